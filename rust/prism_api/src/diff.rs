@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::repository::RevisionRange;
+use crate::repository::{Revision, RevisionRange};
 
 /// A full diff produced for a given revision range.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -12,6 +12,338 @@ pub struct Diff {
     pub files: Vec<DiffFile>,
 }
 
+/// Cheap file-level overview of a diff, without hunks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffSummary {
+    /// The revisions that were compared to produce this summary.
+    pub range: RevisionRange,
+    /// Whether the head side is the working directory rather than `range.head`.
+    #[serde(default)]
+    pub workspace: bool,
+    /// Per-file summaries contained in the diff.
+    #[serde(default)]
+    pub files: Vec<DiffFileSummary>,
+}
+
+/// File-level metadata for a diff entry, without its hunks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffFileSummary {
+    /// Path of the file relative to the repository root.
+    pub path: String,
+    /// Previous path when the file was renamed or copied.
+    #[serde(default)]
+    pub old_path: Option<String>,
+    /// Status of the file change in the diff.
+    pub status: FileStatus,
+    /// High-level summary of insertions/deletions.
+    #[serde(default)]
+    pub stats: DiffStats,
+    /// Indicates whether the diff content is binary.
+    #[serde(default)]
+    pub is_binary: bool,
+    /// File mode on the base side, when the file exists there.
+    #[serde(default)]
+    pub old_mode: Option<FileMode>,
+    /// File mode on the head side, when the file exists there.
+    #[serde(default)]
+    pub new_mode: Option<FileMode>,
+    /// Blob on the base side, when the file exists there. Summaries carry
+    /// the oid and size libgit2 reports for the delta; image dimensions and
+    /// symlink targets are only resolved on [`DiffFile`].
+    #[serde(default)]
+    pub old_blob: Option<BlobInfo>,
+    /// Blob on the head side, when the file exists there. Working directory
+    /// files may report a zero oid until they are loaded as a [`DiffFile`].
+    #[serde(default)]
+    pub new_blob: Option<BlobInfo>,
+    /// Pointer change for submodule entries.
+    #[serde(default)]
+    pub submodule: Option<SubmoduleChange>,
+    /// Kind of content the file holds.
+    #[serde(default)]
+    pub classification: FileClassification,
+    /// Where the file's uncommitted changes sit relative to the index, for
+    /// workspace, index, and unstaged diffs. `None` for other diffs and for
+    /// files whose changes are all committed.
+    #[serde(default)]
+    pub stage: Option<StageState>,
+}
+
+impl From<&DiffFile> for DiffFileSummary {
+    fn from(file: &DiffFile) -> Self {
+        Self {
+            path: file.path.clone(),
+            old_path: file.old_path.clone(),
+            status: file.status,
+            stats: file.stats,
+            is_binary: file.is_binary,
+            old_mode: file.old_mode,
+            new_mode: file.new_mode,
+            old_blob: file.old_blob.clone(),
+            new_blob: file.new_blob.clone(),
+            submodule: file.submodule.clone(),
+            classification: file.classification,
+            stage: file.stage,
+        }
+    }
+}
+
+/// Identity and size of one side of a file diff.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlobInfo {
+    /// Blob oid. Working directory files are hashed on the fly.
+    pub oid: String,
+    /// Size of the blob in bytes.
+    pub size: u64,
+    /// Pixel dimensions, for binary files in a recognized image format.
+    #[serde(default)]
+    pub image: Option<ImageInfo>,
+    /// Link target, for symbolic links.
+    #[serde(default)]
+    pub symlink_target: Option<String>,
+}
+
+/// Kind of content a file holds, so reviews can de-emphasize noise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileClassification {
+    /// Hand-written source code.
+    #[default]
+    Source,
+    /// Test code or fixtures.
+    Test,
+    /// Documentation or other prose.
+    Documentation,
+    /// Package manager lockfile.
+    Lockfile,
+    /// Output of a code generator or minifier.
+    Generated,
+    /// Third-party code checked into the repository.
+    Vendored,
+}
+
+impl FileClassification {
+    /// Whether the file is machine-written, so its hunks rarely need review.
+    #[must_use]
+    pub const fn is_generated(self) -> bool {
+        matches!(self, Self::Lockfile | Self::Generated | Self::Vendored)
+    }
+}
+
+/// Glob assigning a classification to matching paths, ahead of
+/// `.gitattributes` and built-in naming conventions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClassificationRule {
+    /// Git pathspec glob, e.g. `proto/gen/**` or `*.snap`.
+    pub pattern: String,
+    /// Classification given to matching paths.
+    pub classification: FileClassification,
+}
+
+/// Kind of tree entry a file is stored as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileMode {
+    /// Regular, non-executable file (`100644`).
+    Regular,
+    /// Executable file (`100755`).
+    Executable,
+    /// Symbolic link (`120000`).
+    Symlink,
+    /// Submodule commit pointer (`160000`).
+    Gitlink,
+}
+
+/// Format and pixel dimensions of an image blob.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageInfo {
+    /// Detected image format.
+    pub format: ImageFormat,
+    /// Width in pixels.
+    pub width: u32,
+    /// Height in pixels.
+    pub height: u32,
+}
+
+/// Image formats recognized in binary diffs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageFormat {
+    /// Portable Network Graphics.
+    Png,
+    /// JPEG/JFIF.
+    Jpeg,
+    /// Graphics Interchange Format.
+    Gif,
+    /// `WebP`, lossy or lossless.
+    Webp,
+}
+
+/// Commit pointers of a submodule entry on both sides of a diff.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmoduleChange {
+    /// Commit recorded on the base side, or `None` when the submodule was added.
+    #[serde(default)]
+    pub old_oid: Option<String>,
+    /// Commit recorded on the head side, or `None` when the submodule was removed.
+    #[serde(default)]
+    pub new_oid: Option<String>,
+    /// Whether the submodule's working directory has uncommitted changes.
+    /// Only reported for working directory diffs.
+    #[serde(default)]
+    pub dirty: bool,
+    /// Submodule commits reachable from `new_oid` but not `old_oid`, newest
+    /// first. Populated when requested via [`DiffRequest::submodule_log`] and
+    /// the submodule is checked out.
+    #[serde(default)]
+    pub commits: Vec<Revision>,
+}
+
+/// Raw bytes of both sides of a file diff, typically used to render binary
+/// and image changes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileBlobs {
+    /// Path of the file relative to the repository root.
+    pub path: String,
+    /// Base side contents, when the file exists there.
+    #[serde(default)]
+    pub old: Option<Vec<u8>>,
+    /// Head side contents, when the file exists there.
+    #[serde(default)]
+    pub new: Option<Vec<u8>>,
+}
+
+/// Hunks loaded on demand for a single file of a [`DiffSummary`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileHunks {
+    /// Path of the file relative to the repository root.
+    pub path: String,
+    /// The loaded hunks, possibly cut short by a line cap.
+    #[serde(default)]
+    pub hunks: Vec<DiffHunk>,
+    /// Whether hunks or lines were omitted because of a line cap.
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+/// A page of per-commit diffs taken from a revision range.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitDiffPage {
+    /// One diff per commit, oldest first. Each diff's range spans the commit
+    /// and its first parent.
+    #[serde(default)]
+    pub diffs: Vec<Diff>,
+    /// Total number of commits in the requested range.
+    pub total_commits: u32,
+    /// Offset of the next page, or `None` when this is the last page or the
+    /// page is empty.
+    #[serde(default)]
+    pub next_offset: Option<u32>,
+}
+
+/// How a commit from the old range corresponds to the new range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommitPairStatus {
+    /// Both commits introduce the same patch and message.
+    Unchanged,
+    /// The commits were paired but their patches or messages differ.
+    Modified,
+    /// The commit only exists in the new range.
+    Added,
+    /// The commit only exists in the old range.
+    Dropped,
+}
+
+/// A commit from the old range matched with its counterpart in the new range.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitPair {
+    /// Relationship between the two commits.
+    pub status: CommitPairStatus,
+    /// Commit from the old range, or `None` when the commit was added.
+    #[serde(default)]
+    pub old: Option<Revision>,
+    /// Commit from the new range, or `None` when the commit was dropped.
+    #[serde(default)]
+    pub new: Option<Revision>,
+    /// Diff of the two commits' patches. Each line's text keeps the inner
+    /// patch prefix (`+`, `-`, or a space). Empty unless the pair is modified.
+    #[serde(default)]
+    pub interdiff: Vec<DiffHunk>,
+}
+
+/// A `git range-diff` style comparison between two versions of a branch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RangeDiff {
+    /// Range holding the previous version of the branch.
+    pub old_range: RevisionRange,
+    /// Range holding the rewritten version of the branch.
+    pub new_range: RevisionRange,
+    /// Commit pairs in new-range order, with dropped commits placed where
+    /// they appeared in the old range.
+    #[serde(default)]
+    pub pairs: Vec<CommitPair>,
+}
+
+/// Which version of a file to read from a revision range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileVersion {
+    /// The file at `RevisionRange.base`.
+    Base,
+    /// The file at `RevisionRange.head`.
+    Head,
+    /// The file as it currently exists in the working directory.
+    Workspace,
+}
+
+/// Full contents of a file at a single version.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileContents {
+    /// Path of the file relative to the repository root.
+    pub path: String,
+    /// Version the contents were read from.
+    pub version: FileVersion,
+    /// Blob oid of the contents. Hashed on the fly for working directory files.
+    pub oid: String,
+    /// Size of the contents in bytes.
+    pub size: u64,
+    /// Whether the contents look binary. Binary files carry no `text`.
+    #[serde(default)]
+    pub is_binary: bool,
+    /// Decoded text, when the file is not binary.
+    #[serde(default)]
+    pub text: Option<String>,
+    /// Encoding `text` was decoded with, when the file is not binary.
+    #[serde(default)]
+    pub encoding: Option<TextEncoding>,
+}
+
+/// Encoding used to decode file text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TextEncoding {
+    /// Valid UTF-8.
+    Utf8,
+    /// Mostly UTF-8; invalid sequences were replaced with U+FFFD.
+    Utf8Lossy,
+    /// UTF-8 preceded by a byte order mark.
+    Utf8Bom,
+    /// Little-endian UTF-16 without a byte order mark.
+    Utf16Le,
+    /// Little-endian UTF-16 preceded by a byte order mark.
+    Utf16LeBom,
+    /// Big-endian UTF-16 without a byte order mark.
+    Utf16Be,
+    /// Big-endian UTF-16 preceded by a byte order mark.
+    Utf16BeBom,
+    /// A legacy single- or multi-byte encoding.
+    Legacy {
+        /// WHATWG name of the encoding, such as `windows-1252` or `Shift_JIS`.
+        name: String,
+    },
+}
+
 /// Representation of the diff for a single file.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DiffFile {
@@ -28,11 +360,129 @@ pub struct DiffFile {
     /// Indicates whether the diff content is binary.
     #[serde(default)]
     pub is_binary: bool,
+    /// File mode on the base side, when the file exists there.
+    #[serde(default)]
+    pub old_mode: Option<FileMode>,
+    /// File mode on the head side, when the file exists there.
+    #[serde(default)]
+    pub new_mode: Option<FileMode>,
+    /// Blob on the base side, when the file exists there.
+    #[serde(default)]
+    pub old_blob: Option<BlobInfo>,
+    /// Blob on the head side, when the file exists there.
+    #[serde(default)]
+    pub new_blob: Option<BlobInfo>,
+    /// Pointer change for submodule entries.
+    #[serde(default)]
+    pub submodule: Option<SubmoduleChange>,
+    /// Kind of content the file holds.
+    #[serde(default)]
+    pub classification: FileClassification,
+    /// Where the file's uncommitted changes sit relative to the index, for
+    /// workspace, index, and unstaged diffs. `None` for other diffs and for
+    /// files whose changes are all committed.
+    #[serde(default)]
+    pub stage: Option<StageState>,
+    /// Whether hunk lines were left out to respect the request's size limits.
+    /// Stats still count every line; reload the file without limits to see
+    /// the rest.
+    #[serde(default)]
+    pub truncated: bool,
+    /// Encoding the file text was decoded with, taken from the head side or,
+    /// for deleted files, the base side. `None` for binary files and
+    /// submodules.
+    #[serde(default)]
+    pub encoding: Option<TextEncoding>,
+    /// Whether every hunk of the file is whitespace-only. Unset for files
+    /// without hunks and for truncated files.
+    #[serde(default)]
+    pub whitespace_only: bool,
+    /// Whether every hunk of the file only changes line endings, as when a
+    /// file is converted between CRLF and LF. Implies `whitespace_only`.
+    #[serde(default)]
+    pub line_endings_only: bool,
+    /// Functions, types, and impl blocks the change adds, removes, renames,
+    /// or edits. Populated when requested via
+    /// [`DiffRequest::structural_summary`] for files in a recognized language.
+    #[serde(default)]
+    pub structure: Option<StructuralSummary>,
     /// The hunks that make up this file diff.
     #[serde(default)]
     pub hunks: Vec<DiffHunk>,
 }
 
+/// Outline of the declarations a file diff touches, parsed from both sides
+/// of the file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct StructuralSummary {
+    /// Changed declarations in file order.
+    #[serde(default)]
+    pub changes: Vec<SymbolChange>,
+    /// Functions and methods among `changes`.
+    #[serde(default)]
+    pub functions_changed: u32,
+    /// Types and impl blocks among `changes`.
+    #[serde(default)]
+    pub types_changed: u32,
+    /// Public declarations among `changes` whose signature changed, or that
+    /// were removed or renamed.
+    #[serde(default)]
+    pub public_signatures_changed: u32,
+}
+
+/// One declaration a file diff touches.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolChange {
+    /// What kind of declaration changed.
+    pub kind: SymbolKind,
+    /// Name qualified by its enclosing types, e.g. `Parser::parse`. Taken
+    /// from the head side, or from the base side for removed declarations.
+    pub name: String,
+    /// Previous qualified name, for renamed declarations.
+    #[serde(default)]
+    pub old_name: Option<String>,
+    /// How the declaration changed.
+    pub change: SymbolChangeKind,
+    /// Whether the declaration is visible outside its module on either side.
+    #[serde(default)]
+    pub is_public: bool,
+    /// One-based line the declaration starts on in the base version.
+    #[serde(default)]
+    pub base_line: Option<u32>,
+    /// One-based line the declaration starts on in the head version.
+    #[serde(default)]
+    pub head_line: Option<u32>,
+}
+
+/// Kind of declaration tracked by a [`StructuralSummary`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolKind {
+    /// Function, method, or initializer.
+    Function,
+    /// Struct, enum, class, interface, protocol, trait, or type alias.
+    Type,
+    /// Rust `impl` block or Swift extension.
+    Impl,
+}
+
+/// How a declaration changed between the base and head versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolChangeKind {
+    /// Only present in the head version.
+    Added,
+    /// Only present in the base version.
+    Removed,
+    /// Present under a different name with the same body.
+    Renamed,
+    /// Declaration header changed: parameters, return type, generics,
+    /// supertypes, or visibility.
+    SignatureChanged,
+    /// Only the body changed.
+    BodyChanged,
+}
+
 /// Summary information about the changes within a file diff.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub struct DiffStats {
@@ -73,12 +523,51 @@ impl DiffStats {
 pub struct DiffHunk {
     /// The range header describing the hunk offsets.
     pub header: DiffRange,
-    /// Optional section header (e.g., function signature) extracted from the diff.
+    /// Optional section header (e.g., function signature). With
+    /// [`DiffRequest::enclosing_symbols`] this is the signature of the
+    /// innermost function or type around the hunk's first change, falling
+    /// back to the header git extracted from the diff.
     #[serde(default)]
     pub section: Option<String>,
     /// Line-level changes inside the hunk.
     #[serde(default)]
     pub lines: Vec<DiffLine>,
+    /// Whether the hunk would be empty if whitespace, including line breaks,
+    /// were ignored.
+    #[serde(default)]
+    pub whitespace_only: bool,
+    /// Whether the hunk only changes line endings (CRLF versus LF, or the
+    /// newline at end of file). Implies `whitespace_only`.
+    #[serde(default)]
+    pub line_endings_only: bool,
+}
+
+/// A hunk laid out for side-by-side display.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplitHunk {
+    /// The range header describing the hunk offsets.
+    pub header: DiffRange,
+    /// Optional section header (e.g., function signature) extracted from the diff.
+    #[serde(default)]
+    pub section: Option<String>,
+    /// Aligned rows, top to bottom.
+    #[serde(default)]
+    pub rows: Vec<SplitRow>,
+}
+
+/// One aligned row of a side-by-side diff.
+///
+/// Context rows carry the same line on both sides. Changed rows pair a
+/// deletion with the addition that replaced it; when one side runs out of
+/// lines, the other side is `None` and should be rendered as filler.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplitRow {
+    /// Line shown in the base column.
+    #[serde(default)]
+    pub base: Option<DiffLine>,
+    /// Line shown in the head column.
+    #[serde(default)]
+    pub head: Option<DiffLine>,
 }
 
 /// The line number ranges referenced by a hunk header.
@@ -110,6 +599,42 @@ pub struct DiffLine {
     /// Optional inline highlights (e.g., intraline differences).
     #[serde(default)]
     pub highlights: Vec<LineHighlight>,
+    /// Syntax token spans, populated when requested via
+    /// [`DiffRequest::syntax_highlighting`].
+    #[serde(default)]
+    pub tokens: Vec<SyntaxToken>,
+    /// Set when the line belongs to a block moved elsewhere in the diff,
+    /// populated when requested via [`DiffRequest::detect_moves`].
+    #[serde(default)]
+    pub moved: Option<LineMove>,
+    /// Whether `text` was cut at [`DiffRequest::max_line_chars`].
+    #[serde(default)]
+    pub truncated: bool,
+    /// Terminator stripped from the end of `text`.
+    #[serde(default)]
+    pub line_ending: LineEnding,
+    /// Qualified name of the innermost function or type containing the line
+    /// on its own side, populated when requested via
+    /// [`DiffRequest::enclosing_symbols`].
+    #[serde(default)]
+    pub enclosing_symbol: Option<String>,
+}
+
+/// Where a moved line's block came from or went to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineMove {
+    /// Identifier shared by the deleted and added sides of one moved block.
+    /// Adjacent blocks always have different ids, so alternating colors by id
+    /// separates them the way git's `--color-moved=zebra` does.
+    pub block: u32,
+    /// Path of the file holding the other side of the block.
+    pub path: String,
+    /// 1-based line where the other side of the block starts: the head line
+    /// for deleted lines and the base line for added lines.
+    pub line: u32,
+    /// Whether the block was also edited while it moved, including changes
+    /// to indentation.
+    pub edited: bool,
 }
 
 /// Highlights to indicate intraline modifications.
@@ -121,6 +646,43 @@ pub struct LineHighlight {
     pub end_column: u32,
 }
 
+/// A syntax-highlighted span within a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyntaxToken {
+    /// Zero-based column where the token begins (inclusive).
+    pub start_column: u32,
+    /// Zero-based column where the token ends (exclusive).
+    pub end_column: u32,
+    /// Highlight class of the token.
+    pub kind: SyntaxKind,
+}
+
+/// Highlight class assigned to a syntax token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyntaxKind {
+    /// Language keyword.
+    Keyword,
+    /// Type name.
+    Type,
+    /// Function or macro name at a definition or call site.
+    Function,
+    /// String or character literal.
+    String,
+    /// Numeric literal.
+    Number,
+    /// Built-in constant such as `true` or `null`.
+    Constant,
+    /// Comment, including doc comments.
+    Comment,
+    /// Attribute or decorator.
+    Attribute,
+    /// Operator symbol.
+    Operator,
+    /// Brackets, delimiters, and separators.
+    Punctuation,
+}
+
 /// Type of a line contained in a diff.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -133,6 +695,29 @@ pub enum DiffLineKind {
     Deletion,
 }
 
+/// Terminator that ended a line in the file it was read from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineEnding {
+    /// `\n`.
+    #[default]
+    Lf,
+    /// `\r\n`.
+    CrLf,
+    /// The last line of a file that does not end with a newline.
+    Missing,
+}
+
+/// Files, and optionally hunks, to include when exporting a patch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PatchSelection {
+    /// Path of the file as reported in [`DiffFile::path`].
+    pub path: String,
+    /// Indices into [`DiffFile::hunks`] to include, or `None` for every hunk.
+    #[serde(default)]
+    pub hunks: Option<Vec<u32>>,
+}
+
 /// File status from the diff's perspective.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -149,12 +734,134 @@ pub enum FileStatus {
     Copied,
     /// File type changed (e.g., text -> binary).
     TypeChange,
+    /// A submodule was added, removed, or moved to another commit. Details
+    /// are in [`DiffFile::submodule`].
+    Submodule,
+}
+
+/// Staging state of a file with uncommitted changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StageState {
+    /// Every uncommitted change is staged in the index.
+    Staged,
+    /// The changes exist only in the working tree.
+    Unstaged,
+    /// Some changes are staged and later ones are not.
+    PartiallyStaged,
+    /// The file is new and not yet in the index.
+    Untracked,
+}
+
+/// Options controlling how a diff is generated.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DiffRequest {
+    /// Number of unchanged lines shown around each change.
+    pub context_lines: u32,
+    /// Which whitespace differences are ignored when comparing lines.
+    pub whitespace: WhitespaceMode,
+    /// Similarity percentage (0-100) required to report a rename.
+    pub rename_threshold: u16,
+    /// Similarity percentage (0-100) required to report a copy.
+    pub copy_threshold: u16,
+    /// Whether copy detection runs at all.
+    pub detect_copies: bool,
+    /// Whether unmodified files are considered as copy sources. This is the
+    /// most expensive part of copy detection on large repositories.
+    pub copies_from_unmodified: bool,
+    /// Whether diff lines carry syntax tokens for recognized languages.
+    pub syntax_highlighting: bool,
+    /// Whether submodule entries list the commits between their old and new
+    /// pointers.
+    pub submodule_log: bool,
+    /// Whether files in recognized languages carry a [`StructuralSummary`]
+    /// of the declarations they change.
+    pub structural_summary: bool,
+    /// Whether hunk sections and lines are labeled with the function or
+    /// type enclosing them, parsed from the file itself rather than taken
+    /// from git's section header.
+    pub enclosing_symbols: bool,
+    /// Whether deleted and added blocks that match each other are marked as
+    /// moved. Moves across files are found only when the whole diff is
+    /// generated at once; hunks loaded for one file see moves within it.
+    pub detect_moves: bool,
+    /// Whether hunks are left out for generated, vendored, and lockfile
+    /// files. Their stats are still reported.
+    pub skip_generated_hunks: bool,
+    /// Git pathspecs limiting the diff to matching paths, e.g. `src/ui` or
+    /// `*.swift`. Empty means every path.
+    pub include_paths: Vec<String>,
+    /// Git pathspecs whose matches are left out of the diff, e.g. `docs/`.
+    pub exclude_paths: Vec<String>,
+    /// Total bytes of line text materialized across the whole diff. Files
+    /// past the budget keep their stats but are marked truncated. `None`, the
+    /// default, materializes everything.
+    pub max_total_bytes: Option<u64>,
+    /// Lines materialized per file before the file is marked truncated. The
+    /// file's stats still count every line, including the ones left out.
+    /// `None`, the default, keeps every line.
+    pub max_file_lines: Option<u32>,
+    /// Characters kept per line before the line is marked truncated. `None`,
+    /// the default, keeps whole lines.
+    pub max_line_chars: Option<u32>,
+}
+
+impl DiffRequest {
+    /// Context lines used when none are requested explicitly.
+    pub const DEFAULT_CONTEXT_LINES: u32 = 3;
+    /// Suggested cap on line text materialized for one diff (32 MiB), for
+    /// callers opting in to [`DiffRequest::max_total_bytes`].
+    pub const RECOMMENDED_MAX_TOTAL_BYTES: u64 = 32 << 20;
+    /// Suggested cap on lines materialized per file.
+    pub const RECOMMENDED_MAX_FILE_LINES: u32 = 20_000;
+    /// Suggested cap on characters kept per line.
+    pub const RECOMMENDED_MAX_LINE_CHARS: u32 = 4_000;
+}
+
+impl Default for DiffRequest {
+    fn default() -> Self {
+        Self {
+            context_lines: Self::DEFAULT_CONTEXT_LINES,
+            whitespace: WhitespaceMode::default(),
+            rename_threshold: 50,
+            copy_threshold: 100,
+            detect_copies: true,
+            copies_from_unmodified: true,
+            syntax_highlighting: false,
+            submodule_log: false,
+            structural_summary: false,
+            enclosing_symbols: false,
+            detect_moves: false,
+            skip_generated_hunks: false,
+            include_paths: Vec::new(),
+            exclude_paths: Vec::new(),
+            max_total_bytes: None,
+            max_file_lines: None,
+            max_line_chars: None,
+        }
+    }
+}
+
+/// Whitespace handling applied while comparing lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WhitespaceMode {
+    /// Whitespace differences are significant.
+    #[default]
+    Include,
+    /// Ignore all whitespace.
+    IgnoreAll,
+    /// Ignore whitespace at the end of lines.
+    IgnoreAtEol,
+    /// Ignore changes in the amount of whitespace.
+    IgnoreChange,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::repository::{Revision, RevisionRange, Signature};
+    use crate::repository::{Revision, RevisionKind, RevisionRange, Signature};
 
     #[test]
     fn diff_round_trip() {
@@ -170,6 +877,7 @@ mod tests {
                     }),
                     committer: None,
                     timestamp: Some(1_700_000_000),
+                    kind: RevisionKind::Commit,
                 }),
                 head: Revision {
                     oid: "2222222222222222222222222222222222222222".into(),
@@ -181,6 +889,7 @@ mod tests {
                     }),
                     committer: None,
                     timestamp: Some(1_700_000_100),
+                    kind: RevisionKind::Commit,
                 },
             },
             files: vec![DiffFile {
@@ -189,6 +898,23 @@ mod tests {
                 status: FileStatus::Modified,
                 stats: DiffStats::new(2, 1),
                 is_binary: false,
+                old_mode: None,
+                new_mode: Some(FileMode::Regular),
+                old_blob: None,
+                new_blob: Some(BlobInfo {
+                    oid: "3333333333333333333333333333333333333333".into(),
+                    size: 128,
+                    image: None,
+                    symlink_target: None,
+                }),
+                submodule: None,
+                classification: FileClassification::Source,
+                stage: None,
+                truncated: false,
+                encoding: None,
+                whitespace_only: false,
+                line_endings_only: false,
+                structure: None,
                 hunks: vec![DiffHunk {
                     header: DiffRange {
                         base_start: 10,
@@ -204,6 +930,11 @@ mod tests {
                             base_line: Some(10),
                             head_line: Some(10),
                             highlights: vec![],
+                            tokens: vec![],
+                            moved: None,
+                            truncated: false,
+                            line_ending: LineEnding::Lf,
+                            enclosing_symbol: None,
                         },
                         DiffLine {
                             kind: DiffLineKind::Deletion,
@@ -211,6 +942,11 @@ mod tests {
                             base_line: Some(11),
                             head_line: None,
                             highlights: vec![],
+                            tokens: vec![],
+                            moved: None,
+                            truncated: false,
+                            line_ending: LineEnding::Lf,
+                            enclosing_symbol: None,
                         },
                         DiffLine {
                             kind: DiffLineKind::Addition,
@@ -221,8 +957,19 @@ mod tests {
                                 start_column: 15,
                                 end_column: 18,
                             }],
+                            tokens: vec![SyntaxToken {
+                                start_column: 13,
+                                end_column: 18,
+                                kind: SyntaxKind::String,
+                            }],
+                            moved: None,
+                            truncated: false,
+                            line_ending: LineEnding::Lf,
+                            enclosing_symbol: None,
                         },
                     ],
+                    whitespace_only: false,
+                    line_endings_only: false,
                 }],
             }],
         };
@@ -285,6 +1032,20 @@ mod tests {
         assert_eq!(json, "\"addition\"");
     }
 
+    #[test]
+    fn diff_request_defaults_fill_missing_fields() {
+        let request: DiffRequest =
+            serde_json::from_str(r#"{"context_lines": 10, "whitespace": "ignore_at_eol"}"#)
+                .expect("deserialize request");
+        assert_eq!(request.context_lines, 10);
+        assert_eq!(request.whitespace, WhitespaceMode::IgnoreAtEol);
+        assert_eq!(
+            request.rename_threshold,
+            DiffRequest::default().rename_threshold
+        );
+        assert!(request.detect_copies);
+    }
+
     #[test]
     fn diff_range_is_copy() {
         let range = DiffRange {
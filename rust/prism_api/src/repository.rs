@@ -30,6 +30,28 @@ pub struct Revision {
     /// Unix timestamp (seconds) associated with the revision.
     #[serde(default)]
     pub timestamp: Option<i64>,
+    /// What `oid` names, which decides how files are read at this revision.
+    #[serde(default)]
+    pub kind: RevisionKind,
+}
+
+/// Kind of object a [`Revision`] stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RevisionKind {
+    /// `oid` is a commit.
+    #[default]
+    Commit,
+    /// `oid` is a tree that no commit points to, such as a recorded review
+    /// iteration.
+    Tree,
+    /// The repository index, with `oid` naming the tree it was written as
+    /// when the diff was generated.
+    Index,
+    /// The working directory, with `oid` naming the tree it was written as
+    /// when the diff was generated. Files are read from that tree, so they
+    /// match the diff even if the working directory changed since.
+    Workdir,
 }
 
 /// Structured author/committer identity.
@@ -78,6 +100,7 @@ mod tests {
             }),
             committer: None,
             timestamp: Some(1_690_000_000),
+            kind: RevisionKind::Commit,
         };
 
         let json = serde_json::to_string(&revision).expect("serialize revision");
@@ -85,6 +108,17 @@ mod tests {
         assert_eq!(revision, decoded);
     }
 
+    #[test]
+    fn revision_kind_defaults_to_commit() {
+        let json = r#"{ "oid": "0123456789abcdef0123456789abcdef01234567" }"#;
+        let revision: Revision = serde_json::from_str(json).expect("deserialize revision");
+        assert_eq!(revision.kind, RevisionKind::Commit);
+
+        let json = r#"{ "oid": "0123456789abcdef0123456789abcdef01234567", "kind": "workdir" }"#;
+        let revision: Revision = serde_json::from_str(json).expect("deserialize revision");
+        assert_eq!(revision.kind, RevisionKind::Workdir);
+    }
+
     #[test]
     fn repository_info_defaults() {
         let json = r#"{
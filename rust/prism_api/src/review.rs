@@ -219,6 +219,16 @@ pub struct ReviewThread {
     pub resolved: bool,
 }
 
+/// A snapshot of the reviewed tree, recorded each time the reviewer finishes a
+/// pass so later diffs can show only what changed since.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewIteration {
+    /// One-based iteration number, increasing with each recorded snapshot.
+    pub number: u32,
+    /// Oid of the tree object captured for this iteration.
+    pub tree_oid: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -12,11 +12,17 @@ pub mod repository;
 pub mod review;
 
 pub use diff::{
-    Diff, DiffFile, DiffHunk, DiffLine, DiffLineKind, DiffRange, DiffStats, FileStatus,
-    LineHighlight,
+    BlobInfo, ClassificationRule, CommitDiffPage, CommitPair, CommitPairStatus, Diff, DiffFile,
+    DiffFileSummary, DiffHunk, DiffLine, DiffLineKind, DiffRange, DiffRequest, DiffStats,
+    DiffSummary, FileBlobs, FileClassification, FileContents, FileHunks, FileMode, FileStatus,
+    FileVersion, ImageFormat, ImageInfo, LineEnding, LineHighlight, LineMove, PatchSelection,
+    RangeDiff, SplitHunk, SplitRow, StageState, StructuralSummary, SubmoduleChange, SymbolChange,
+    SymbolChangeKind, SymbolKind, SyntaxKind, SyntaxToken, TextEncoding, WhitespaceMode,
+};
+pub use repository::{
+    RepositoryInfo, Revision, RevisionKind, RevisionRange, Signature, WorkspaceStatus,
 };
-pub use repository::{RepositoryInfo, Revision, RevisionRange, Signature, WorkspaceStatus};
 pub use review::{
-    CommentDraft, Diagnostic, DiffSide, FileRange, Position, Range, ReviewComment, ReviewThread,
-    Severity, Suggestion, TextEdit,
+    CommentDraft, Diagnostic, DiffSide, FileRange, Position, Range, ReviewComment, ReviewIteration,
+    ReviewThread, Severity, Suggestion, TextEdit,
 };
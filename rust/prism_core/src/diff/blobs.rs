@@ -0,0 +1,316 @@
+//! Blob identity, modes, sizes, and image dimensions for file diffs.
+//!
+//! libgit2 reports blob oids for both sides of a delta, but leaves sizes unset
+//! unless it loaded the content, and leaves working directory oids zeroed
+//! unless it had to hash the file. This module fills both in after the diff
+//! has been built.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use git2::{ObjectType, Oid};
+
+use crate::api::diff::{BlobInfo, DiffFile, FileMode, ImageFormat, ImageInfo};
+
+/// Describe one side of a delta, or `None` when the file does not exist on
+/// that side.
+pub fn blob_info(file: &git2::DiffFile<'_>) -> Option<BlobInfo> {
+    (is_present(file) && !super::submodules::is_gitlink(file)).then(|| BlobInfo {
+        oid: file.id().to_string(),
+        size: file.size(),
+        image: None,
+        symlink_target: None,
+    })
+}
+
+/// Classify the mode of one side of a delta, or `None` when the file does not
+/// exist on that side.
+pub fn file_mode(file: &git2::DiffFile<'_>) -> Option<FileMode> {
+    if !is_present(file) {
+        return None;
+    }
+    match file.mode() {
+        git2::FileMode::Blob | git2::FileMode::BlobGroupWritable => Some(FileMode::Regular),
+        git2::FileMode::BlobExecutable => Some(FileMode::Executable),
+        git2::FileMode::Link => Some(FileMode::Symlink),
+        git2::FileMode::Commit => Some(FileMode::Gitlink),
+        git2::FileMode::Tree | git2::FileMode::Unreadable => None,
+    }
+}
+
+/// Whether one side of a delta exists. Diffs parsed from patch text do not
+/// flag existing sides, so a blob id counts as well.
+fn is_present(file: &git2::DiffFile<'_>) -> bool {
+    file.exists() || !file.id().is_zero()
+}
+
+/// Resolve sizes, working directory oids, symlink targets, and image
+/// dimensions for `file`.
+pub fn describe(repo: &git2::Repository, file: &mut DiffFile) {
+    let is_binary = file.is_binary;
+    let old_path = file.old_path.clone().unwrap_or_else(|| file.path.clone());
+    if let Some(blob) = file.old_blob.as_mut() {
+        let is_symlink = file.old_mode == Some(FileMode::Symlink);
+        resolve(repo, &old_path, is_binary, is_symlink, blob);
+    }
+    if let Some(blob) = file.new_blob.as_mut() {
+        let is_symlink = file.new_mode == Some(FileMode::Symlink);
+        resolve(repo, &file.path, is_binary, is_symlink, blob);
+    }
+}
+
+/// Read the bytes behind `blob`, from the object database or, for working
+/// directory files, from disk.
+pub fn read(repo: &git2::Repository, path: &str, blob: &BlobInfo) -> Option<Vec<u8>> {
+    let oid = Oid::from_str(&blob.oid).ok()?;
+    if let Ok(object) = repo.find_blob(oid) {
+        return Some(object.content().to_vec());
+    }
+    let is_symlink = blob.symlink_target.is_some();
+    read_workdir_entry(&repo.workdir()?.join(Path::new(path)), is_symlink)
+}
+
+/// Read at most the first `len` bytes behind `blob`, like [`read`]. Loose
+/// objects and working directory files are streamed; packed objects are
+/// loaded whole, as libgit2 cannot stream them.
+pub fn read_prefix(
+    repo: &git2::Repository,
+    path: &str,
+    blob: &BlobInfo,
+    len: usize,
+) -> Option<Vec<u8>> {
+    let oid = Oid::from_str(&blob.oid).ok()?;
+    let limit = u64::try_from(len).unwrap_or(u64::MAX);
+    let mut prefix = Vec::new();
+    if let Ok(odb) = repo.odb() {
+        if let Ok((reader, _, ObjectType::Blob)) = odb.reader(oid) {
+            reader.take(limit).read_to_end(&mut prefix).ok()?;
+            return Some(prefix);
+        }
+    }
+    if let Ok(object) = repo.find_blob(oid) {
+        let content = object.content();
+        return Some(content[..content.len().min(len)].to_vec());
+    }
+    let path = repo.workdir()?.join(Path::new(path));
+    if blob.symlink_target.is_some() {
+        return read_workdir_entry(&path, true);
+    }
+    File::open(path)
+        .ok()?
+        .take(limit)
+        .read_to_end(&mut prefix)
+        .ok()?;
+    Some(prefix)
+}
+
+fn resolve(
+    repo: &git2::Repository,
+    path: &str,
+    is_binary: bool,
+    is_symlink: bool,
+    blob: &mut BlobInfo,
+) {
+    let Ok(oid) = Oid::from_str(&blob.oid) else {
+        return;
+    };
+
+    let in_odb = !oid.is_zero() && repo.odb().is_ok_and(|odb| odb.exists(oid));
+    if in_odb {
+        if blob.size == 0 {
+            if let Ok((size, _)) = repo.odb().and_then(|odb| odb.read_header(oid)) {
+                blob.size = size as u64;
+            }
+        }
+        if is_binary || is_symlink {
+            if let Ok(object) = repo.find_blob(oid) {
+                if is_symlink {
+                    blob.symlink_target =
+                        Some(String::from_utf8_lossy(object.content()).into_owned());
+                } else {
+                    blob.image = image_info(object.content());
+                }
+            }
+        }
+        return;
+    }
+
+    // Untracked or modified working directory file.
+    let Some(bytes) = repo
+        .workdir()
+        .and_then(|root| read_workdir_entry(&root.join(Path::new(path)), is_symlink))
+    else {
+        return;
+    };
+    if let Ok(hashed) = Oid::hash_object(ObjectType::Blob, &bytes) {
+        blob.oid = hashed.to_string();
+    }
+    blob.size = bytes.len() as u64;
+    if is_symlink {
+        blob.symlink_target = Some(String::from_utf8_lossy(&bytes).into_owned());
+        return;
+    }
+    // Untracked files are not content-sniffed by libgit2, so rely on the
+    // image signature alone.
+    blob.image = image_info(&bytes);
+}
+
+/// Read a working directory file the way git stores it: symbolic links are
+/// represented by their target path rather than the file they point to.
+fn read_workdir_entry(path: &Path, is_symlink: bool) -> Option<Vec<u8>> {
+    if is_symlink {
+        let target = std::fs::read_link(path).ok()?;
+        return Some(target.to_string_lossy().into_owned().into_bytes());
+    }
+    std::fs::read(path).ok()
+}
+
+/// Detect PNG, JPEG, GIF, and `WebP` headers and read their dimensions.
+fn image_info(bytes: &[u8]) -> Option<ImageInfo> {
+    let (format, width, height) = if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        (ImageFormat::Png, be_u32(bytes, 16)?, be_u32(bytes, 20)?)
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        (
+            ImageFormat::Gif,
+            u32::from(le_u16(bytes, 6)?),
+            u32::from(le_u16(bytes, 8)?),
+        )
+    } else if bytes.starts_with(&[0xFF, 0xD8]) {
+        let (width, height) = jpeg_dimensions(bytes)?;
+        (ImageFormat::Jpeg, width, height)
+    } else if bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP") {
+        let (width, height) = webp_dimensions(bytes)?;
+        (ImageFormat::Webp, width, height)
+    } else {
+        return None;
+    };
+
+    Some(ImageInfo {
+        format,
+        width,
+        height,
+    })
+}
+
+/// Walk JPEG segments until a start-of-frame marker.
+fn jpeg_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    let mut offset = 2;
+    loop {
+        if *bytes.get(offset)? != 0xFF {
+            return None;
+        }
+        let marker = *bytes.get(offset + 1)?;
+        if marker == 0xFF {
+            offset += 1;
+            continue;
+        }
+        let is_frame = matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC);
+        if is_frame {
+            let height = be_u16(bytes, offset + 5)?;
+            let width = be_u16(bytes, offset + 7)?;
+            return Some((u32::from(width), u32::from(height)));
+        }
+        let length = usize::from(be_u16(bytes, offset + 2)?);
+        offset += 2 + length;
+    }
+}
+
+/// Read dimensions from the first `WebP` chunk.
+fn webp_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    match bytes.get(12..16)? {
+        b"VP8X" => Some((le_u24(bytes, 24)? + 1, le_u24(bytes, 27)? + 1)),
+        b"VP8L" => {
+            let bits = u32::from_le_bytes(bytes.get(21..25)?.try_into().ok()?);
+            Some(((bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1))
+        }
+        b"VP8 " => Some((
+            u32::from(le_u16(bytes, 26)? & 0x3FFF),
+            u32::from(le_u16(bytes, 28)? & 0x3FFF),
+        )),
+        _ => None,
+    }
+}
+
+fn be_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        bytes.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn le_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        bytes.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn be_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        bytes.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn le_u24(bytes: &[u8], offset: usize) -> Option<u32> {
+    let raw = bytes.get(offset..offset + 3)?;
+    Some(u32::from(raw[0]) | u32::from(raw[1]) << 8 | u32::from(raw[2]) << 16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec();
+        bytes.extend_from_slice(&width.to_be_bytes());
+        bytes.extend_from_slice(&height.to_be_bytes());
+        bytes.extend_from_slice(&[8, 6, 0, 0, 0]);
+        bytes
+    }
+
+    #[test]
+    fn reads_png_and_gif_dimensions() {
+        assert_eq!(
+            image_info(&png(640, 480)),
+            Some(ImageInfo {
+                format: ImageFormat::Png,
+                width: 640,
+                height: 480,
+            })
+        );
+
+        let gif = [b"GIF89a".as_slice(), &[0x20, 0x01, 0x10, 0x00]].concat();
+        assert_eq!(
+            image_info(&gif).map(|info| (info.format, info.width, info.height)),
+            Some((ImageFormat::Gif, 288, 16))
+        );
+    }
+
+    #[test]
+    fn walks_jpeg_segments_to_frame_header() {
+        let jpeg = [
+            0xFF, 0xD8, // SOI
+            0xFF, 0xE0, 0x00, 0x04, 0x00, 0x00, // APP0 with two payload bytes
+            0xFF, 0xC0, 0x00, 0x11, 0x08, 0x00, 0x20, 0x01, 0x40, // SOF0: 32 x 320
+        ];
+        assert_eq!(
+            image_info(&jpeg).map(|info| (info.format, info.width, info.height)),
+            Some((ImageFormat::Jpeg, 320, 32))
+        );
+    }
+
+    #[test]
+    fn reads_extended_webp_canvas() {
+        let mut webp = b"RIFF\0\0\0\0WEBPVP8X\x0a\0\0\0\0\0\0\0".to_vec();
+        webp.extend_from_slice(&[0x63, 0x00, 0x00, 0xC7, 0x00, 0x00]);
+        assert_eq!(
+            image_info(&webp).map(|info| (info.format, info.width, info.height)),
+            Some((ImageFormat::Webp, 100, 200))
+        );
+    }
+
+    #[test]
+    fn ignores_unknown_formats() {
+        assert_eq!(image_info(b"\0\x01binary"), None);
+        assert_eq!(image_info(b"\x89PNG"), None);
+    }
+}
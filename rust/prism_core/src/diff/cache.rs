@@ -0,0 +1,187 @@
+//! Per-session cache backing lazily loaded diffs.
+//!
+//! Entries are keyed on the tree oids being compared plus the request options,
+//! so committed diffs stay valid for as long as they are cached. The working
+//! directory is keyed on a [`workspace_fingerprint`] instead, so its entries
+//! stop matching once a file changes without any of its contents being read
+//! or written to the object database.
+
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::time::UNIX_EPOCH;
+
+use git2::{ObjectType, Oid, StatusOptions};
+
+use crate::api::diff::{DiffFile, DiffRequest, DiffSummary};
+use crate::Result;
+
+/// Number of distinct diffs retained before the oldest entry is evicted.
+const MAX_ENTRIES: usize = 8;
+
+/// Identity of a cached diff.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    /// Tree on the base side, if any.
+    pub old_tree: Option<Oid>,
+    /// Tree on the head side, or the [`workspace_fingerprint`] for
+    /// workspace diffs.
+    pub new_tree: Oid,
+    /// Options the diff was generated with.
+    pub request: DiffRequest,
+}
+
+/// Summary and lazily loaded files for a single diff.
+#[derive(Debug)]
+pub struct CachedDiff {
+    pub summary: DiffSummary,
+    pub files: HashMap<String, DiffFile>,
+}
+
+/// Bounded cache of recently summarized diffs, oldest first.
+#[derive(Debug, Default)]
+pub struct DiffCache {
+    entries: VecDeque<(CacheKey, CachedDiff)>,
+}
+
+impl DiffCache {
+    pub fn get(&self, key: &CacheKey) -> Option<&CachedDiff> {
+        self.entries
+            .iter()
+            .find(|(candidate, _)| candidate == key)
+            .map(|(_, entry)| entry)
+    }
+
+    pub fn get_mut(&mut self, key: &CacheKey) -> Option<&mut CachedDiff> {
+        self.entries
+            .iter_mut()
+            .find(|(candidate, _)| candidate == key)
+            .map(|(_, entry)| entry)
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn insert(&mut self, key: CacheKey, summary: DiffSummary) {
+        self.entries.retain(|(candidate, _)| *candidate != key);
+        if self.entries.len() == MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((
+            key,
+            CachedDiff {
+                summary,
+                files: HashMap::new(),
+            },
+        ));
+    }
+}
+
+/// Fingerprint the index and working directory from cheap metadata: the
+/// index checksum, plus the status, size, and modification time of every
+/// changed or untracked file. Like git's own stat checks, a rewrite that keeps
+/// both the size and the timestamp of a file goes unnoticed.
+///
+/// # Errors
+///
+/// Returns an error if the repository status cannot be read.
+pub fn workspace_fingerprint(repo: &git2::Repository) -> Result<Oid> {
+    let mut state = index_checksum(repo).unwrap_or_default();
+
+    let mut options = StatusOptions::new();
+    options
+        .include_untracked(true)
+        .recurse_untracked_dirs(true)
+        .include_ignored(false);
+    let statuses = repo.statuses(Some(&mut options))?;
+    let root = repo.workdir();
+    for entry in statuses.iter() {
+        state.extend_from_slice(entry.path_bytes());
+        state.push(0);
+        state.extend_from_slice(&entry.status().bits().to_le_bytes());
+        let metadata = entry
+            .path()
+            .zip(root)
+            .and_then(|(path, root)| std::fs::symlink_metadata(root.join(path)).ok());
+        if let Some(metadata) = metadata {
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .unwrap_or_default();
+            state.extend_from_slice(&metadata.len().to_le_bytes());
+            state.extend_from_slice(&modified.as_nanos().to_le_bytes());
+        }
+    }
+    Ok(Oid::hash_object(ObjectType::Blob, &state)?)
+}
+
+/// The trailing checksum git writes over the index file, which changes
+/// whenever the index does.
+fn index_checksum(repo: &git2::Repository) -> Option<Vec<u8>> {
+    let mut file = File::open(repo.path().join("index")).ok()?;
+    let mut checksum = vec![0; 20];
+    file.seek(SeekFrom::End(-20)).ok()?;
+    file.read_exact(&mut checksum).ok()?;
+    Some(checksum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::repository::{Revision, RevisionKind, RevisionRange};
+    use crate::diff::fixtures::file;
+
+    fn summary() -> DiffSummary {
+        DiffSummary {
+            range: RevisionRange {
+                base: None,
+                head: Revision {
+                    oid: "0".repeat(40),
+                    reference: None,
+                    summary: None,
+                    author: None,
+                    committer: None,
+                    timestamp: None,
+                    kind: RevisionKind::Commit,
+                },
+            },
+            workspace: false,
+            files: Vec::new(),
+        }
+    }
+
+    fn key(seed: u8) -> CacheKey {
+        CacheKey {
+            old_tree: None,
+            new_tree: Oid::from_bytes(&[seed; 20]).expect("oid"),
+            request: DiffRequest::default(),
+        }
+    }
+
+    #[test]
+    fn evicts_oldest_entry_when_full() {
+        let mut cache = DiffCache::default();
+        for seed in 0..=u8::try_from(MAX_ENTRIES).expect("small") {
+            cache.insert(key(seed), summary());
+        }
+
+        assert!(cache.get(&key(0)).is_none());
+        assert!(cache.get(&key(1)).is_some());
+    }
+
+    #[test]
+    fn reinserting_replaces_cached_files() {
+        let mut cache = DiffCache::default();
+        cache.insert(key(1), summary());
+        cache
+            .get_mut(&key(1))
+            .expect("entry")
+            .files
+            .insert("a.txt".into(), file("a.txt", Vec::new()));
+
+        cache.insert(key(1), summary());
+        assert!(cache.get(&key(1)).expect("entry").files.is_empty());
+    }
+}
@@ -0,0 +1,236 @@
+//! Classification of diff files as source, tests, documentation, lockfiles,
+//! generated, or vendored content.
+//!
+//! Configured globs win, followed by the `.gitattributes` keys GitHub's
+//! linguist understands (`linguist-generated`, `linguist-vendored`,
+//! `linguist-documentation`) and `-diff`. Well-known file names and
+//! directories fill in the rest. An attribute explicitly set to false turns
+//! off the matching heuristic, as it does on GitHub.
+
+use std::path::Path;
+
+use git2::{AttrCheckFlags, AttrValue, Pathspec, PathspecFlags};
+
+use crate::api::diff::{ClassificationRule, FileClassification};
+
+/// Lockfiles written by package managers.
+const LOCKFILE_NAMES: &[&str] = &[
+    "Cargo.lock",
+    "package-lock.json",
+    "npm-shrinkwrap.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "bun.lockb",
+    "bun.lock",
+    "Gemfile.lock",
+    "Podfile.lock",
+    "Package.resolved",
+    "composer.lock",
+    "poetry.lock",
+    "Pipfile.lock",
+    "uv.lock",
+    "go.sum",
+    "flake.lock",
+    "mix.lock",
+    "pubspec.lock",
+    "packages.lock.json",
+];
+
+/// Directories holding third-party code.
+const VENDORED_DIRECTORIES: &[&str] = &[
+    "vendor",
+    "vendored",
+    "third_party",
+    "third-party",
+    "node_modules",
+    "Pods",
+    "Carthage",
+];
+
+/// File name suffixes produced by code generators and minifiers.
+const GENERATED_SUFFIXES: &[&str] = &[
+    ".min.js",
+    ".min.css",
+    ".js.map",
+    ".css.map",
+    ".pb.go",
+    ".pb.swift",
+    "_pb2.py",
+    "_pb2.pyi",
+    ".g.dart",
+    ".freezed.dart",
+    ".designer.cs",
+];
+
+/// Directories holding documentation.
+const DOCUMENTATION_DIRECTORIES: &[&str] = &["doc", "docs", "documentation", "man"];
+
+/// File names documenting a project regardless of their location.
+const DOCUMENTATION_STEMS: &[&str] = &[
+    "readme",
+    "changelog",
+    "changes",
+    "contributing",
+    "license",
+    "licence",
+    "copying",
+    "authors",
+    "notice",
+];
+
+/// Directories holding tests.
+const TEST_DIRECTORIES: &[&str] = &["test", "tests", "__tests__", "spec", "specs", "testdata"];
+
+/// Resolves the classification of paths for one diff.
+pub struct Classifier {
+    rules: Vec<(Pathspec, FileClassification)>,
+}
+
+impl Classifier {
+    /// Compile `rules`; patterns git cannot parse are ignored.
+    pub fn new(rules: &[ClassificationRule]) -> Self {
+        Self {
+            rules: rules
+                .iter()
+                .filter_map(|rule| {
+                    Pathspec::new([rule.pattern.as_str()])
+                        .ok()
+                        .map(|spec| (spec, rule.classification))
+                })
+                .collect(),
+        }
+    }
+
+    /// Classify `path`, consulting `repo` for attributes.
+    pub fn classify(&self, repo: &git2::Repository, path: &str) -> FileClassification {
+        let relative = Path::new(path);
+        if let Some((_, classification)) = self
+            .rules
+            .iter()
+            .find(|(spec, _)| spec.matches_path(relative, PathspecFlags::DEFAULT))
+        {
+            return *classification;
+        }
+
+        let generated = attribute(repo, relative, "linguist-generated");
+        let vendored = attribute(repo, relative, "linguist-vendored");
+        let documentation = attribute(repo, relative, "linguist-documentation");
+        if generated == Some(true) || attribute(repo, relative, "diff") == Some(false) {
+            return FileClassification::Generated;
+        }
+        if vendored == Some(true) {
+            return FileClassification::Vendored;
+        }
+        if documentation == Some(true) {
+            return FileClassification::Documentation;
+        }
+
+        let heuristic = classify_path(path);
+        let overridden = match heuristic {
+            FileClassification::Generated | FileClassification::Lockfile => generated,
+            FileClassification::Vendored => vendored,
+            FileClassification::Documentation => documentation,
+            FileClassification::Test | FileClassification::Source => None,
+        };
+        if overridden == Some(false) {
+            FileClassification::Source
+        } else {
+            heuristic
+        }
+    }
+}
+
+/// Read a boolean attribute, or `None` when it is unspecified.
+fn attribute(repo: &git2::Repository, path: &Path, name: &str) -> Option<bool> {
+    let value = repo
+        .get_attr_bytes(path, name, AttrCheckFlags::default())
+        .ok()
+        .flatten();
+    match AttrValue::from_bytes(value) {
+        AttrValue::True | AttrValue::Bytes(_) => Some(true),
+        AttrValue::False => Some(false),
+        AttrValue::String(value) => Some(!matches!(value, "false" | "0")),
+        AttrValue::Unspecified => None,
+    }
+}
+
+/// Classify `path` from its name and directories alone.
+fn classify_path(path: &str) -> FileClassification {
+    let mut components: Vec<&str> = path.split('/').collect();
+    let name = components.pop().unwrap_or_default();
+    let has_directory = |candidates: &[&str]| {
+        components
+            .iter()
+            .any(|component| candidates.contains(component))
+    };
+
+    if LOCKFILE_NAMES.contains(&name) {
+        return FileClassification::Lockfile;
+    }
+    if has_directory(VENDORED_DIRECTORIES) {
+        return FileClassification::Vendored;
+    }
+    if GENERATED_SUFFIXES
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+        || name.contains(".generated.")
+    {
+        return FileClassification::Generated;
+    }
+    if is_test(name) || has_directory(TEST_DIRECTORIES) {
+        return FileClassification::Test;
+    }
+
+    let lowercase = name.to_ascii_lowercase();
+    let stem = lowercase.split('.').next().unwrap_or_default();
+    let is_prose = [".md", ".markdown", ".rst", ".adoc", ".txt"]
+        .iter()
+        .any(|extension| lowercase.ends_with(extension));
+    if DOCUMENTATION_STEMS.contains(&stem) || is_prose || has_directory(DOCUMENTATION_DIRECTORIES) {
+        return FileClassification::Documentation;
+    }
+
+    FileClassification::Source
+}
+
+/// Test file naming conventions of the common ecosystems.
+fn is_test(name: &str) -> bool {
+    let stem = name.split('.').next().unwrap_or_default();
+    stem.ends_with("_test")
+        || stem.starts_with("test_")
+        || stem.ends_with("Tests")
+        || stem.ends_with("Test")
+        || stem.ends_with("Spec")
+        || name.contains(".test.")
+        || name.contains(".spec.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_well_known_paths() {
+        for (path, expected) in [
+            ("Cargo.lock", FileClassification::Lockfile),
+            ("web/package-lock.json", FileClassification::Lockfile),
+            (
+                "vendor/github.com/pkg/errors/errors.go",
+                FileClassification::Vendored,
+            ),
+            ("static/app.min.js", FileClassification::Generated),
+            ("api/service.pb.go", FileClassification::Generated),
+            ("src/parser_test.go", FileClassification::Test),
+            ("tests/diff_integration.rs", FileClassification::Test),
+            ("App/AppTests/ReviewTests.swift", FileClassification::Test),
+            ("web/src/button.test.tsx", FileClassification::Test),
+            ("README.md", FileClassification::Documentation),
+            ("docs/architecture.svg", FileClassification::Documentation),
+            ("LICENSE", FileClassification::Documentation),
+            ("src/lib.rs", FileClassification::Source),
+            ("src/contest.rs", FileClassification::Source),
+        ] {
+            assert_eq!(classify_path(path), expected, "{path}");
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Context expansion around diff hunks.
+//!
+//! Lines outside of any hunk are identical on both sides of a diff, shifted by
+//! the net number of lines added or removed by the hunks above them. This
+//! module maps such lines between the two sides so expanded context can carry
+//! both `base_line` and `head_line`.
+
+use crate::api::diff::{DiffHunk, DiffLine, DiffLineKind};
+use crate::api::review::DiffSide;
+
+/// Build context lines for `start_line..start_line + line_count` on `side`.
+///
+/// `contents` is the file text on `side`. A `start_line` of 0 is treated as
+/// 1, still covering `line_count` lines. Lines that fall inside a hunk, or
+/// past the end of the file, are omitted.
+pub fn expand(
+    hunks: &[DiffHunk],
+    contents: &[u8],
+    side: DiffSide,
+    start_line: u32,
+    line_count: u32,
+) -> Vec<DiffLine> {
+    let first = start_line.max(1);
+    let last = first.saturating_add(line_count);
+
+    split_lines(contents)
+        .enumerate()
+        .filter_map(|(index, text)| {
+            let line = u32::try_from(index + 1).ok()?;
+            (first..last).contains(&line).then_some((line, text))
+        })
+        .filter_map(|(line, text)| {
+            let other = map_line(hunks, side, line)?;
+            let (base_line, head_line) = match side {
+                DiffSide::Base => (line, other),
+                DiffSide::Head => (other, line),
+            };
+            Some(DiffLine {
+                kind: DiffLineKind::Context,
+                text: super::sanitize_line(text),
+                base_line: Some(base_line),
+                head_line: Some(head_line),
+                highlights: Vec::new(),
+                tokens: Vec::new(),
+                moved: None,
+                truncated: false,
+                line_ending: super::line_ending(text),
+                enclosing_symbol: None,
+            })
+        })
+        .collect()
+}
+
+/// Map an unchanged `line` on `side` to the opposite side, or `None` when the
+/// line is covered by a hunk.
+fn map_line(hunks: &[DiffHunk], side: DiffSide, line: u32) -> Option<u32> {
+    let mut offset = 0_i64;
+    for hunk in hunks {
+        let header = hunk.header;
+        let (start, len, other_len) = match side {
+            DiffSide::Base => (header.base_start, header.base_lines, header.head_lines),
+            DiffSide::Head => (header.head_start, header.head_lines, header.base_lines),
+        };
+
+        // A hunk with no lines on this side sits just after `start`.
+        let first_covered = if len == 0 { start + 1 } else { start };
+        let end = first_covered + len;
+
+        if line < first_covered {
+            break;
+        }
+        if line < end {
+            return None;
+        }
+        offset += i64::from(other_len) - i64::from(len);
+    }
+
+    u32::try_from(i64::from(line) + offset).ok()
+}
+
+fn split_lines(contents: &[u8]) -> impl Iterator<Item = &[u8]> {
+    contents
+        .split_inclusive(|byte| *byte == b'\n')
+        .filter(|line| !line.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::diff::DiffRange;
+    use crate::diff::fixtures;
+
+    fn hunk(base_start: u32, base_lines: u32, head_start: u32, head_lines: u32) -> DiffHunk {
+        let mut hunk = fixtures::hunk(Vec::new());
+        hunk.header = DiffRange {
+            base_start,
+            base_lines,
+            head_start,
+            head_lines,
+        };
+        hunk
+    }
+
+    #[test]
+    fn maps_lines_around_hunks() {
+        // Two lines inserted after base line 5, one line removed at base line 20.
+        let hunks = vec![hunk(5, 0, 6, 2), hunk(20, 1, 21, 0)];
+
+        assert_eq!(map_line(&hunks, DiffSide::Base, 5), Some(5));
+        assert_eq!(map_line(&hunks, DiffSide::Base, 6), Some(8));
+        assert_eq!(map_line(&hunks, DiffSide::Base, 20), None);
+        assert_eq!(map_line(&hunks, DiffSide::Base, 21), Some(22));
+        assert_eq!(map_line(&hunks, DiffSide::Head, 7), None);
+        assert_eq!(map_line(&hunks, DiffSide::Head, 8), Some(6));
+        assert_eq!(map_line(&hunks, DiffSide::Head, 22), Some(21));
+    }
+
+    #[test]
+    fn expands_requested_span_with_both_line_numbers() {
+        let hunks = vec![hunk(2, 1, 2, 2)];
+        let contents = b"a\nb\nb2\nc\nd\n";
+
+        let lines = expand(&hunks, contents, DiffSide::Head, 1, 5);
+        let numbered: Vec<_> = lines
+            .iter()
+            .map(|line| (line.text.as_str(), line.base_line, line.head_line))
+            .collect();
+        assert_eq!(
+            numbered,
+            vec![
+                ("a", Some(1), Some(1)),
+                ("c", Some(3), Some(4)),
+                ("d", Some(4), Some(5))
+            ]
+        );
+        assert!(lines.iter().all(|line| line.kind == DiffLineKind::Context));
+    }
+
+    #[test]
+    fn stops_at_end_of_file() {
+        let lines = expand(&[], b"only\n", DiffSide::Base, 1, 10);
+        assert_eq!(lines.len(), 1);
+        assert!(expand(&[], b"only\n", DiffSide::Base, 5, 3).is_empty());
+    }
+
+    #[test]
+    fn clamps_a_zero_start_without_losing_lines() {
+        let lines = expand(&[], b"a\nb\nc\n", DiffSide::Head, 0, 2);
+        let texts: Vec<_> = lines.iter().map(|line| line.text.as_str()).collect();
+        assert_eq!(texts, vec!["a", "b"]);
+    }
+}
@@ -0,0 +1,301 @@
+//! Intraline highlighting for paired deletion/addition lines.
+//!
+//! Each run of changed lines inside a hunk is split into its deletions and
+//! additions, which are paired up in order. Every pair is tokenized into
+//! words, whitespace runs, and punctuation, and the tokens that differ are
+//! reported as [`LineHighlight`] column ranges on both sides.
+
+use crate::api::diff::{DiffHunk, DiffLine, DiffLineKind, LineHighlight};
+
+/// Upper bound on the token comparison matrix before a pair is skipped.
+const MAX_TOKEN_CELLS: usize = 250_000;
+
+/// Populate `highlights` for every paired deletion/addition in the hunk.
+pub fn annotate_hunk(hunk: &mut DiffHunk) {
+    let mut index = 0;
+    while index < hunk.lines.len() {
+        if hunk.lines[index].kind == DiffLineKind::Context {
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        while index < hunk.lines.len() && hunk.lines[index].kind != DiffLineKind::Context {
+            index += 1;
+        }
+        annotate_block(&mut hunk.lines[start..index]);
+    }
+}
+
+fn annotate_block(lines: &mut [DiffLine]) {
+    let deletions: Vec<usize> = positions(lines, DiffLineKind::Deletion);
+    let additions: Vec<usize> = positions(lines, DiffLineKind::Addition);
+
+    for (&deletion, &addition) in deletions.iter().zip(&additions) {
+        let (base, head) = highlight_pair(&lines[deletion].text, &lines[addition].text);
+        lines[deletion].highlights = base;
+        lines[addition].highlights = head;
+    }
+}
+
+fn positions(lines: &[DiffLine], kind: DiffLineKind) -> Vec<usize> {
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.kind == kind)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Compute highlight ranges for a base/head line pair.
+///
+/// Columns are zero-based character (Unicode scalar) offsets. Both sides are
+/// left empty when the lines share no non-whitespace token, since the whole
+/// line changed and highlighting it adds nothing.
+fn highlight_pair(base: &str, head: &str) -> (Vec<LineHighlight>, Vec<LineHighlight>) {
+    let base_tokens = tokenize(base);
+    let head_tokens = tokenize(head);
+
+    let Some((base_changed, head_changed)) = changed_tokens(&base_tokens, &head_tokens) else {
+        return (Vec::new(), Vec::new());
+    };
+
+    let shares_content = base_tokens
+        .iter()
+        .zip(&base_changed)
+        .any(|(token, changed)| !changed && !token.is_whitespace());
+    if !shares_content {
+        return (Vec::new(), Vec::new());
+    }
+
+    (
+        collect_ranges(&base_tokens, &base_changed),
+        collect_ranges(&head_tokens, &head_changed),
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Token<'a> {
+    text: &'a str,
+    start: u32,
+    end: u32,
+}
+
+impl Token<'_> {
+    fn is_whitespace(&self) -> bool {
+        self.text.chars().all(char::is_whitespace)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenClass {
+    Word,
+    Whitespace,
+    Punctuation,
+}
+
+fn classify(ch: char) -> TokenClass {
+    if ch.is_alphanumeric() || ch == '_' {
+        TokenClass::Word
+    } else if ch.is_whitespace() {
+        TokenClass::Whitespace
+    } else {
+        TokenClass::Punctuation
+    }
+}
+
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut current: Option<(usize, u32, TokenClass)> = None;
+    let mut column = 0_u32;
+
+    for (offset, ch) in text.char_indices() {
+        let class = classify(ch);
+        match current {
+            Some((_, _, active)) if active == class && class != TokenClass::Punctuation => {}
+            Some((start_offset, start_column, _)) => {
+                tokens.push(Token {
+                    text: &text[start_offset..offset],
+                    start: start_column,
+                    end: column,
+                });
+                current = Some((offset, column, class));
+            }
+            None => current = Some((offset, column, class)),
+        }
+        column += 1;
+    }
+
+    if let Some((start_offset, start_column, _)) = current {
+        tokens.push(Token {
+            text: &text[start_offset..],
+            start: start_column,
+            end: column,
+        });
+    }
+
+    tokens
+}
+
+/// Flag tokens on each side that are not part of the longest common token
+/// subsequence. Returns `None` when the lines are too long to compare.
+fn changed_tokens(base: &[Token<'_>], head: &[Token<'_>]) -> Option<(Vec<bool>, Vec<bool>)> {
+    let prefix = base
+        .iter()
+        .zip(head)
+        .take_while(|(left, right)| left.text == right.text)
+        .count();
+    let suffix = base[prefix..]
+        .iter()
+        .rev()
+        .zip(head[prefix..].iter().rev())
+        .take_while(|(left, right)| left.text == right.text)
+        .count();
+
+    let base_middle = &base[prefix..base.len() - suffix];
+    let head_middle = &head[prefix..head.len() - suffix];
+    if base_middle.len().saturating_mul(head_middle.len()) > MAX_TOKEN_CELLS {
+        return None;
+    }
+
+    let mut base_changed = vec![false; base.len()];
+    let mut head_changed = vec![false; head.len()];
+    let (base_matched, head_matched) = longest_common_subsequence(base_middle, head_middle);
+    for (offset, matched) in base_matched.into_iter().enumerate() {
+        base_changed[prefix + offset] = !matched;
+    }
+    for (offset, matched) in head_matched.into_iter().enumerate() {
+        head_changed[prefix + offset] = !matched;
+    }
+
+    Some((base_changed, head_changed))
+}
+
+fn longest_common_subsequence(base: &[Token<'_>], head: &[Token<'_>]) -> (Vec<bool>, Vec<bool>) {
+    let width = head.len() + 1;
+    let mut table = vec![0_u32; (base.len() + 1) * width];
+
+    for i in (0..base.len()).rev() {
+        for j in (0..head.len()).rev() {
+            table[i * width + j] = if base[i].text == head[j].text {
+                table[(i + 1) * width + j + 1] + 1
+            } else {
+                table[(i + 1) * width + j].max(table[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut base_matched = vec![false; base.len()];
+    let mut head_matched = vec![false; head.len()];
+    let (mut i, mut j) = (0, 0);
+    while i < base.len() && j < head.len() {
+        if base[i].text == head[j].text {
+            base_matched[i] = true;
+            head_matched[j] = true;
+            i += 1;
+            j += 1;
+        } else if table[(i + 1) * width + j] >= table[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    (base_matched, head_matched)
+}
+
+/// Merge consecutive changed tokens into column ranges, absorbing unchanged
+/// whitespace that sits between two changed tokens.
+fn collect_ranges(tokens: &[Token<'_>], changed: &[bool]) -> Vec<LineHighlight> {
+    let mut ranges: Vec<LineHighlight> = Vec::new();
+    let mut bridging_whitespace = false;
+
+    for (token, &is_changed) in tokens.iter().zip(changed) {
+        if is_changed {
+            match ranges.last_mut() {
+                Some(last) if last.end_column == token.start || bridging_whitespace => {
+                    last.end_column = token.end;
+                }
+                _ => ranges.push(LineHighlight {
+                    start_column: token.start,
+                    end_column: token.end,
+                }),
+            }
+            bridging_whitespace = false;
+        } else {
+            bridging_whitespace = token.is_whitespace()
+                && ranges.last().map(|last| last.end_column) == Some(token.start);
+        }
+    }
+
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diff::fixtures::{hunk, line};
+
+    fn highlight(start_column: u32, end_column: u32) -> LineHighlight {
+        LineHighlight {
+            start_column,
+            end_column,
+        }
+    }
+
+    #[test]
+    fn highlights_renamed_identifier() {
+        let (base, head) = highlight_pair(
+            "    let total = compute_sum(values);",
+            "    let total = compute_total(values);",
+        );
+        assert_eq!(base, vec![highlight(16, 27)]);
+        assert_eq!(head, vec![highlight(16, 29)]);
+    }
+
+    #[test]
+    fn highlights_inserted_argument_on_head_only() {
+        let (base, head) = highlight_pair("call(a, b)", "call(a, extra, b)");
+        assert!(base.is_empty());
+        assert_eq!(head, vec![highlight(8, 15)]);
+    }
+
+    #[test]
+    fn merges_changes_separated_by_whitespace() {
+        let (base, head) = highlight_pair("let a = old value;", "let a = new thing;");
+        assert_eq!(base, vec![highlight(8, 17)]);
+        assert_eq!(head, vec![highlight(8, 17)]);
+    }
+
+    #[test]
+    fn skips_completely_rewritten_lines() {
+        let (base, head) = highlight_pair("alpha beta", "gamma delta");
+        assert!(base.is_empty());
+        assert!(head.is_empty());
+    }
+
+    #[test]
+    fn columns_count_characters_not_bytes() {
+        let (base, head) = highlight_pair("let é = 1;", "let é = 2;");
+        assert_eq!(base, vec![highlight(8, 9)]);
+        assert_eq!(head, vec![highlight(8, 9)]);
+    }
+
+    #[test]
+    fn pairs_lines_in_order_within_change_blocks() {
+        let mut hunk = hunk(vec![
+            line(DiffLineKind::Context, "fn main() {"),
+            line(DiffLineKind::Deletion, "    let x = 1;"),
+            line(DiffLineKind::Deletion, "    let y = 2;"),
+            line(DiffLineKind::Addition, "    let x = 10;"),
+            line(DiffLineKind::Context, "}"),
+        ]);
+
+        annotate_hunk(&mut hunk);
+
+        assert_eq!(hunk.lines[1].highlights, vec![highlight(12, 13)]);
+        assert_eq!(hunk.lines[3].highlights, vec![highlight(12, 14)]);
+        assert!(hunk.lines[2].highlights.is_empty());
+        assert!(hunk.lines[0].highlights.is_empty());
+    }
+}
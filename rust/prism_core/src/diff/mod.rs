@@ -0,0 +1,3341 @@
+//! Diff generation and patch application primitives.
+
+mod blobs;
+mod cache;
+mod classify;
+mod context;
+mod intraline;
+mod moves;
+mod parse;
+mod patch;
+mod range_diff;
+mod sections;
+mod split;
+mod stage;
+mod structure;
+mod submodules;
+mod tokens;
+mod whitespace;
+
+use std::cell::RefCell;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use git2::{DiffFindOptions, DiffLineType, DiffOptions, Oid};
+
+use crate::{
+    api::diff::{
+        BlobInfo, ClassificationRule, CommitDiffPage, Diff, DiffFile, DiffFileSummary, DiffHunk,
+        DiffLine, DiffLineKind, DiffRange, DiffRequest, DiffStats, DiffSummary, FileBlobs,
+        FileClassification, FileHunks, FileStatus, LineEnding, RangeDiff, SplitHunk, TextEncoding,
+        WhitespaceMode,
+    },
+    api::repository::{Revision, RevisionKind, RevisionRange},
+    api::review::{DiffSide, ReviewIteration},
+    encoding,
+    repository::{revision_tree, Repository},
+    Error, Result,
+};
+
+use self::cache::{CacheKey, DiffCache};
+use self::classify::Classifier;
+pub use self::patch::render_patch;
+use self::range_diff::CommitPatch;
+pub use self::split::split_hunks;
+
+/// Entry point for diff generation.
+///
+/// Each engine owns a small cache of summarized diffs so that hunks can be
+/// loaded one file at a time after a [`DiffSummary`] has been returned.
+#[derive(Debug, Default)]
+pub struct DiffEngine {
+    cache: Mutex<DiffCache>,
+    iterations: Mutex<Vec<ReviewIteration>>,
+    classification_rules: Mutex<Vec<ClassificationRule>>,
+}
+
+impl DiffEngine {
+    /// Construct a new diff engine instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the globs that classify diff files ahead of `.gitattributes`
+    /// and built-in naming conventions. The first matching rule wins.
+    ///
+    /// Cached diffs are dropped so later summaries pick up the new rules.
+    pub fn set_classification_rules(&self, rules: Vec<ClassificationRule>) {
+        *self
+            .classification_rules
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = rules;
+        self.cache().clear();
+    }
+
+    /// Generate a unified diff between the repository head and its base.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the repository has no head revision or if any
+    /// underlying git operation fails.
+    pub fn diff(&self, repository: &Repository, request: &DiffRequest) -> Result<Diff> {
+        let range = repository
+            .revision_range()?
+            .ok_or(Error::MissingHeadRevision)?;
+
+        self.diff_for_range(repository, range, request)
+    }
+
+    /// Generate a unified diff representing the workspace changes (index and
+    /// working tree) against the current head revision. Each file carries its
+    /// [`DiffFile::stage`]; [`DiffEngine::diff_index`] and
+    /// [`DiffEngine::diff_unstaged`] show the two halves separately.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the repository has no head revision or if any
+    /// underlying git operation fails.
+    pub fn diff_workspace(&self, repository: &Repository, request: &DiffRequest) -> Result<Diff> {
+        let range = repository
+            .revision_range()?
+            .ok_or(Error::MissingHeadRevision)?;
+
+        let git_repo = repository.git_repo();
+        let head_tree = commit_tree(git_repo, &range.head.oid)?;
+        let files = generate_workspace_diff(git_repo, &self.classifier(), &head_tree, request)?;
+
+        Ok(Diff { range, files })
+    }
+
+    /// Generate a unified diff of the changes staged in the index against the
+    /// current head revision, as `git diff --cached` shows them.
+    ///
+    /// The head of the returned range is a [`RevisionKind::Index`] revision
+    /// whose oid is the index written as a tree, so the range can be passed
+    /// to the follow-up APIs with `workspace` unset. Files carry their [`DiffFile::stage`] so
+    /// ones with further unstaged changes stand out.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the repository has no head revision, the index
+    /// holds unresolved conflicts, or any git operation fails.
+    pub fn diff_index(&self, repository: &Repository, request: &DiffRequest) -> Result<Diff> {
+        let head = repository
+            .head_revision()?
+            .ok_or(Error::MissingHeadRevision)?;
+
+        let git_repo = repository.git_repo();
+        let head_tree = commit_tree(git_repo, &head.oid)?;
+        let index = index_revision(repository.write_index_tree()?);
+        let index_tree = git_repo.find_tree(Oid::from_str(&index.oid)?)?;
+        let mut files = generate_diff(
+            git_repo,
+            &self.classifier(),
+            Some(&head_tree),
+            &index_tree,
+            request,
+        )?;
+        annotate_stages(git_repo, &mut files, request)?;
+
+        Ok(Diff {
+            range: RevisionRange {
+                base: Some(head),
+                head: index,
+            },
+            files,
+        })
+    }
+
+    /// Generate a unified diff of the working tree changes not yet staged,
+    /// including untracked files, as `git diff` shows them.
+    ///
+    /// The base of the returned range is the index as in
+    /// [`DiffEngine::diff_index`], and its head is a
+    /// [`RevisionKind::Workdir`] revision naming the working directory
+    /// written as a tree. The diff is taken against that tree, so follow-up
+    /// calls read the same snapshot the diff shows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the repository has no head revision, the index
+    /// holds unresolved conflicts, or any git operation fails.
+    pub fn diff_unstaged(&self, repository: &Repository, request: &DiffRequest) -> Result<Diff> {
+        let git_repo = repository.git_repo();
+        let index = index_revision(repository.write_index_tree()?);
+        let head = workdir_revision(repository)?;
+        let index_tree = revision_tree(git_repo, &index)?;
+        let workdir_tree = revision_tree(git_repo, &head)?;
+        let mut files = generate_diff(
+            git_repo,
+            &self.classifier(),
+            Some(&index_tree),
+            &workdir_tree,
+            request,
+        )?;
+        annotate_stages(git_repo, &mut files, request)?;
+
+        Ok(Diff {
+            range: RevisionRange {
+                base: Some(index),
+                head,
+            },
+            files,
+        })
+    }
+
+    /// Generate a unified diff against the merge-base of HEAD and `target`.
+    ///
+    /// When `target` is `None` the repository default branch is used. With
+    /// `include_workspace` set, uncommitted index and working tree changes are
+    /// included on top of HEAD. The returned range's base is the merge-base.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the repository has no head revision, the target
+    /// or merge-base cannot be resolved, or if any git operation fails.
+    pub fn diff_merge_base(
+        &self,
+        repository: &Repository,
+        target: Option<&str>,
+        include_workspace: bool,
+        request: &DiffRequest,
+    ) -> Result<Diff> {
+        let range = repository
+            .merge_base_range(target)?
+            .ok_or(Error::MissingHeadRevision)?;
+
+        if !include_workspace {
+            return self.diff_for_range(repository, range, request);
+        }
+
+        let git_repo = repository.git_repo();
+        let base_tree = match range.base.as_ref() {
+            Some(base) => commit_tree(git_repo, &base.oid)?,
+            None => commit_tree(git_repo, &range.head.oid)?,
+        };
+        let files = generate_workspace_diff(git_repo, &self.classifier(), &base_tree, request)?;
+
+        Ok(Diff { range, files })
+    }
+
+    /// Generate one diff per commit in `range`, oldest first.
+    ///
+    /// Only the commits in the `[offset, offset + limit)` window are diffed so
+    /// large ranges can be paged through incrementally. An empty page, as
+    /// returned for a zero `limit` or an `offset` past the end, has no next
+    /// offset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the range cannot be walked or any commit diff fails.
+    pub fn diff_commits(
+        &self,
+        repository: &Repository,
+        range: &RevisionRange,
+        offset: u32,
+        limit: u32,
+        request: &DiffRequest,
+    ) -> Result<CommitDiffPage> {
+        let (commits, total) =
+            repository.commit_ranges_page(range, offset as usize, limit as usize)?;
+        let total_commits = u32::try_from(total).unwrap_or(u32::MAX);
+        let end = offset.saturating_add(u32::try_from(commits.len()).unwrap_or(u32::MAX));
+
+        let diffs = commits
+            .into_iter()
+            .map(|commit| self.diff_for_range(repository, commit, request))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(CommitDiffPage {
+            next_offset: (!diffs.is_empty() && end < total_commits).then_some(end),
+            diffs,
+            total_commits,
+        })
+    }
+
+    /// Compare two versions of a rewritten branch, `git range-diff` style.
+    ///
+    /// The commits of `old_range` and `new_range` are diffed one by one with
+    /// [`DiffEngine::diff_for_range`] and paired by the similarity of their
+    /// patches and messages. Only the request's context, whitespace, rename,
+    /// copy, and path options apply to those diffs. Each pair is reported as unchanged, modified
+    /// (with a diff of the two patches), added, or dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either range cannot be walked or any commit diff
+    /// fails.
+    pub fn range_diff(
+        &self,
+        repository: &Repository,
+        old_range: RevisionRange,
+        new_range: RevisionRange,
+        request: &DiffRequest,
+    ) -> Result<RangeDiff> {
+        let old = self.commit_patches(repository, &old_range, request)?;
+        let new = self.commit_patches(repository, &new_range, request)?;
+
+        Ok(RangeDiff {
+            pairs: range_diff::pair(&old, &new)?,
+            old_range,
+            new_range,
+        })
+    }
+
+    /// Build a diff from patch text: `git diff` or `diff -u` output, or the
+    /// mbox messages written by `git format-patch`.
+    ///
+    /// The base of the returned range is the repository head, which the
+    /// patch is reviewed against. The head revision carries the commit id,
+    /// author, date, and subject from the first message's headers when the
+    /// patch has them, and a zero oid otherwise. Blob ids the repository
+    /// knows are expanded from the patch's abbreviations. Syntax tokens are
+    /// not attached, since the patched file contents are not available.
+    ///
+    /// With `check_applies` set the patch is applied to the head tree in
+    /// memory first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PatchDoesNotApply`] when the check is requested and
+    /// fails, and a git error when the patch cannot be parsed.
+    pub fn diff_patch(
+        &self,
+        repository: &Repository,
+        patch: &[u8],
+        check_applies: bool,
+        request: &DiffRequest,
+    ) -> Result<Diff> {
+        let git_repo = repository.git_repo();
+        let parsed = git2::Diff::from_buffer(&parse::normalize(patch))?;
+        let base = repository.head_revision()?;
+        if check_applies {
+            let base_tree = match base.as_ref() {
+                Some(base) => commit_tree(git_repo, &base.oid)?,
+                None => git_repo.find_tree(git_repo.treebuilder(None)?.write()?)?,
+            };
+            git_repo
+                .apply_to_tree(&base_tree, &parsed, None)
+                .map_err(|source| Error::PatchDoesNotApply {
+                    message: source.message().to_owned(),
+                })?;
+        }
+
+        let classifier = self.classifier();
+        let mut files = build_files(&parsed, request, None)?;
+        for file in &mut files {
+            parse::resolve_blobs(git_repo, file);
+            file.classification = classifier.classify(git_repo, &file.path);
+            if request.skip_generated_hunks && file.classification.is_generated() {
+                file.hunks.clear();
+            }
+        }
+        if request.detect_moves {
+            moves::annotate(&mut files);
+        }
+
+        Ok(Diff {
+            range: RevisionRange {
+                base,
+                head: parse::revision(patch),
+            },
+            files,
+        })
+    }
+
+    /// Generate a unified diff for an explicit revision range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either revision cannot be resolved or if git fails.
+    pub fn diff_for_range(
+        &self,
+        repository: &Repository,
+        range: RevisionRange,
+        request: &DiffRequest,
+    ) -> Result<Diff> {
+        let git_repo = repository.git_repo();
+        let head_tree = revision_tree(git_repo, &range.head)?;
+        let base_tree = match range.base.as_ref() {
+            Some(base) => Some(revision_tree(git_repo, base)?),
+            None => None,
+        };
+
+        let files = generate_diff(
+            git_repo,
+            &self.classifier(),
+            base_tree.as_ref(),
+            &head_tree,
+            request,
+        )?;
+
+        Ok(Diff { range, files })
+    }
+
+    /// Summarize the diff between the repository head and its base without
+    /// materializing hunks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the repository has no head revision or if any
+    /// underlying git operation fails.
+    pub fn diff_summary(
+        &self,
+        repository: &Repository,
+        request: &DiffRequest,
+    ) -> Result<DiffSummary> {
+        let range = repository
+            .revision_range()?
+            .ok_or(Error::MissingHeadRevision)?;
+
+        self.summarize(repository, range, false, request)
+    }
+
+    /// Summarize workspace changes (index and working tree) against the
+    /// current head revision without materializing hunks.
+    ///
+    /// Every call recomputes the summary and discards hunks cached for the
+    /// previous workspace state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the repository has no head revision or if any
+    /// underlying git operation fails.
+    pub fn diff_summary_workspace(
+        &self,
+        repository: &Repository,
+        request: &DiffRequest,
+    ) -> Result<DiffSummary> {
+        let range = repository
+            .revision_range()?
+            .ok_or(Error::MissingHeadRevision)?;
+
+        self.summarize(repository, range, true, request)
+    }
+
+    /// Summarize the diff for an explicit revision range without
+    /// materializing hunks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either revision cannot be resolved or if git fails.
+    pub fn diff_summary_for_range(
+        &self,
+        repository: &Repository,
+        range: RevisionRange,
+        request: &DiffRequest,
+    ) -> Result<DiffSummary> {
+        self.summarize(repository, range, false, request)
+    }
+
+    /// Load the hunks of a single file from a summarized diff.
+    ///
+    /// `range` and `workspace` identify the diff and should be taken from the
+    /// [`DiffSummary`]. When `max_lines` is set, lines past the cap are
+    /// dropped and the result is marked as truncated. Workspace files always
+    /// reflect the working directory as it is now, even if it changed since
+    /// the summary was taken.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::FileNotInDiff`] when `path` is not part of the diff, or
+    /// any error produced while resolving revisions or running git.
+    pub fn load_file_hunks(
+        &self,
+        repository: &Repository,
+        range: &RevisionRange,
+        workspace: bool,
+        path: &str,
+        max_lines: Option<u32>,
+        request: &DiffRequest,
+    ) -> Result<FileHunks> {
+        let file = self.file_diff(repository, range, workspace, path, request)?;
+        Ok(cap_hunks(file, max_lines))
+    }
+
+    /// Load the hunks of `path` laid out for side-by-side display.
+    ///
+    /// `range` and `workspace` identify the diff as in
+    /// [`DiffEngine::load_file_hunks`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::FileNotInDiff`] when `path` is not part of the diff, or
+    /// any error produced while resolving revisions or running git.
+    pub fn load_split_hunks(
+        &self,
+        repository: &Repository,
+        range: &RevisionRange,
+        workspace: bool,
+        path: &str,
+        request: &DiffRequest,
+    ) -> Result<Vec<SplitHunk>> {
+        let file = self.file_diff(repository, range, workspace, path, request)?;
+        Ok(split_hunks(&file.hunks))
+    }
+
+    /// Read the raw bytes of both sides of `path`, for rendering binary and
+    /// image changes.
+    ///
+    /// `range` and `workspace` identify the diff as in
+    /// [`DiffEngine::load_file_hunks`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::FileNotInDiff`] when `path` is not part of the diff, or
+    /// any error produced while resolving revisions or running git.
+    pub fn load_file_blobs(
+        &self,
+        repository: &Repository,
+        range: &RevisionRange,
+        workspace: bool,
+        path: &str,
+        request: &DiffRequest,
+    ) -> Result<FileBlobs> {
+        let file = self.file_diff(repository, range, workspace, path, request)?;
+        let git_repo = repository.git_repo();
+        let old_path = file.old_path.as_deref().unwrap_or(&file.path);
+
+        Ok(FileBlobs {
+            old: file
+                .old_blob
+                .as_ref()
+                .and_then(|blob| blobs::read(git_repo, old_path, blob)),
+            new: file
+                .new_blob
+                .as_ref()
+                .and_then(|blob| blobs::read(git_repo, &file.path, blob)),
+            path: file.path,
+        })
+    }
+
+    /// Return unchanged lines around the hunks of `path`, numbered on both
+    /// sides of the diff.
+    ///
+    /// The span starts at the 1-based `start_line` on `side` and covers
+    /// `line_count` lines. Lines that belong to a hunk or lie past the end of
+    /// the file are omitted, including hunks left out of the loaded diff by
+    /// the size caps or [`DiffRequest::skip_generated_hunks`]. `range` and
+    /// `workspace` identify the diff as in [`DiffEngine::load_file_hunks`].
+    /// Lines carry their enclosing symbol
+    /// when [`DiffRequest::enclosing_symbols`] is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::FileNotInDiff`] when `path` is not part of the diff, or
+    /// any error produced while reading file contents or running git.
+    #[allow(clippy::too_many_arguments)]
+    pub fn expand_context(
+        &self,
+        repository: &Repository,
+        range: &RevisionRange,
+        workspace: bool,
+        path: &str,
+        side: DiffSide,
+        start_line: u32,
+        line_count: u32,
+        request: &DiffRequest,
+    ) -> Result<Vec<DiffLine>> {
+        // Hunks dropped by the size caps or for generated files would make
+        // their changed lines look unchanged, so map through the full diff.
+        let file = self.file_diff(
+            repository,
+            range,
+            workspace,
+            path,
+            &comparison_request(request),
+        )?;
+        let decode_blob = |bytes: Vec<u8>| encoding::decode(&bytes, &encoding::detect(&bytes));
+        let contents = match side {
+            DiffSide::Base => match range.base.as_ref() {
+                Some(base) => {
+                    let base_path = file.old_path.as_deref().unwrap_or(&file.path);
+                    repository.blob_at(base, base_path)?.map(decode_blob)
+                }
+                None => None,
+            },
+            DiffSide::Head if workspace => repository.workdir_file(&file.path)?.map(|bytes| {
+                let detected =
+                    encoding::detect_in_workdir(repository.git_repo(), &file.path, &bytes);
+                encoding::decode(&bytes, &detected)
+            }),
+            DiffSide::Head => repository
+                .blob_at(&range.head, &file.path)?
+                .map(decode_blob),
+        };
+
+        Ok(contents.map_or_else(Vec::new, |contents| {
+            let mut lines = context::expand(
+                &file.hunks,
+                contents.as_bytes(),
+                side,
+                start_line,
+                line_count,
+            );
+            if request.enclosing_symbols {
+                sections::label_lines(
+                    repository.git_repo(),
+                    &file.path,
+                    &contents,
+                    side,
+                    &mut lines,
+                );
+            }
+            lines
+        }))
+    }
+
+    /// Record the tree the reviewer has just seen as a new review iteration.
+    ///
+    /// `revision` may name a commit or tree (any revspec git understands).
+    /// When it is `None`, the current workspace, including untracked files, is
+    /// written as a tree object and recorded instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `revision` cannot be resolved to a tree, or when
+    /// the workspace tree cannot be written.
+    pub fn record_iteration(
+        &self,
+        repository: &Repository,
+        revision: Option<&str>,
+    ) -> Result<ReviewIteration> {
+        let tree_oid = match revision {
+            Some(spec) => repository
+                .git_repo()
+                .revparse_single(spec)?
+                .peel_to_tree()?
+                .id()
+                .to_string(),
+            None => repository.write_workspace_tree()?,
+        };
+
+        let mut iterations = self.iterations();
+        let number = u32::try_from(iterations.len() + 1).unwrap_or(u32::MAX);
+        let iteration = ReviewIteration { number, tree_oid };
+        iterations.push(iteration.clone());
+        drop(iterations);
+        Ok(iteration)
+    }
+
+    /// Review iterations recorded so far, oldest first.
+    #[must_use]
+    pub fn review_iterations(&self) -> Vec<ReviewIteration> {
+        self.iterations().clone()
+    }
+
+    /// Generate a diff from a recorded review iteration to the current
+    /// workspace, showing only what changed since that pass.
+    ///
+    /// `number` selects the iteration; `None` uses the most recent one. The
+    /// returned range's base is the iteration's tree, with an
+    /// `iteration-<n>` reference, and its head is the working directory,
+    /// written as a tree. Both are [`RevisionKind::Tree`] and
+    /// [`RevisionKind::Workdir`] revisions, so the range can be passed to the
+    /// per-file APIs with `workspace` unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IterationNotFound`] when iteration `number` has not
+    /// been recorded, [`Error::NoIterationsRecorded`] when `number` is `None`
+    /// and none has, [`Error::MissingHeadRevision`] when HEAD is unborn, or
+    /// any error produced while running git.
+    pub fn diff_since_iteration(
+        &self,
+        repository: &Repository,
+        number: Option<u32>,
+        request: &DiffRequest,
+    ) -> Result<Diff> {
+        let recorded = {
+            let iterations = self.iterations();
+            number.map_or_else(
+                || iterations.last().cloned(),
+                |number| {
+                    iterations
+                        .iter()
+                        .find(|iteration| iteration.number == number)
+                        .cloned()
+                },
+            )
+        };
+        let iteration = recorded.ok_or_else(|| {
+            number.map_or(Error::NoIterationsRecorded, |number| {
+                Error::IterationNotFound { number }
+            })
+        })?;
+
+        // Snapshot the workspace as a tree too, so untracked files present in
+        // both snapshots are compared directly rather than through the index.
+        let git_repo = repository.git_repo();
+        let old_tree = git_repo.find_tree(Oid::from_str(&iteration.tree_oid)?)?;
+        let head = workdir_revision(repository)?;
+        let new_tree = revision_tree(git_repo, &head)?;
+        let files = generate_diff(
+            git_repo,
+            &self.classifier(),
+            Some(&old_tree),
+            &new_tree,
+            request,
+        )?;
+
+        let range = RevisionRange {
+            base: Some(tree_revision(
+                iteration.tree_oid,
+                RevisionKind::Tree,
+                format!("Review iteration {}", iteration.number),
+                Some(format!("iteration-{}", iteration.number)),
+            )),
+            head,
+        };
+        Ok(Diff { range, files })
+    }
+
+    /// Render every commit of `range` for pairing by [`range_diff::pair`].
+    fn commit_patches(
+        &self,
+        repository: &Repository,
+        range: &RevisionRange,
+        request: &DiffRequest,
+    ) -> Result<Vec<CommitPatch>> {
+        let patch_request = comparison_request(request);
+        let git_repo = repository.git_repo();
+        repository
+            .commit_ranges(range)?
+            .into_iter()
+            .map(|commit| {
+                let message = git_repo
+                    .find_commit(Oid::from_str(&commit.head.oid)?)?
+                    .message_bytes()
+                    .to_vec();
+                let diff = self.diff_for_range(repository, commit, &patch_request)?;
+                Ok(CommitPatch::new(&diff, &String::from_utf8_lossy(&message)))
+            })
+            .collect()
+    }
+
+    /// Full diff for a single file, served from the cache when available.
+    fn file_diff(
+        &self,
+        repository: &Repository,
+        range: &RevisionRange,
+        workspace: bool,
+        path: &str,
+        request: &DiffRequest,
+    ) -> Result<DiffFile> {
+        let git_repo = repository.git_repo();
+        let (old_tree, new_tree) = diff_trees(git_repo, range, workspace)?;
+        let key = cache_key(repository, old_tree.as_ref(), new_tree.as_ref(), request)?;
+
+        let cached = self
+            .cache()
+            .get(&key)
+            .and_then(|entry| entry.files.get(path).cloned());
+        if let Some(file) = cached {
+            return Ok(file);
+        }
+
+        let raw = raw_diff(git_repo, old_tree.as_ref(), new_tree.as_ref(), request)?;
+        let mut file = build_file_at_path(
+            &raw,
+            path,
+            request,
+            Decoder::new(git_repo, workspace, request),
+        )?
+        .ok_or_else(|| Error::FileNotInDiff {
+            path: path.to_owned(),
+        })?;
+        annotate_files(
+            git_repo,
+            &self.classifier(),
+            new_tree.is_none(),
+            std::slice::from_mut(&mut file),
+            request,
+        );
+        if let Some(entry) = self.cache().get_mut(&key) {
+            entry.files.insert(path.to_owned(), file.clone());
+        }
+        Ok(file)
+    }
+
+    fn summarize(
+        &self,
+        repository: &Repository,
+        range: RevisionRange,
+        workspace: bool,
+        request: &DiffRequest,
+    ) -> Result<DiffSummary> {
+        let git_repo = repository.git_repo();
+        let (old_tree, new_tree) = diff_trees(git_repo, &range, workspace)?;
+        let key = cache_key(repository, old_tree.as_ref(), new_tree.as_ref(), request)?;
+
+        if !workspace {
+            if let Some(entry) = self.cache().get(&key) {
+                return Ok(DiffSummary {
+                    range,
+                    ..entry.summary.clone()
+                });
+            }
+        }
+
+        let raw = raw_diff(git_repo, old_tree.as_ref(), new_tree.as_ref(), request)?;
+        let summary = DiffSummary {
+            range,
+            workspace,
+            files: summarize_files(git_repo, &self.classifier(), &raw, workspace, request)?,
+        };
+        self.cache().insert(key, summary.clone());
+
+        Ok(summary)
+    }
+
+    fn cache(&self) -> MutexGuard<'_, DiffCache> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn classifier(&self) -> Classifier {
+        Classifier::new(
+            &self
+                .classification_rules
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        )
+    }
+
+    fn iterations(&self) -> MutexGuard<'_, Vec<ReviewIteration>> {
+        self.iterations
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Resolve the trees compared for `range`. A missing new tree stands for the
+/// working directory, diffed against the head tree.
+fn diff_trees<'repo>(
+    repo: &'repo git2::Repository,
+    range: &RevisionRange,
+    workspace: bool,
+) -> Result<(Option<git2::Tree<'repo>>, Option<git2::Tree<'repo>>)> {
+    let head_tree = revision_tree(repo, &range.head)?;
+    if workspace {
+        return Ok((Some(head_tree), None));
+    }
+
+    let base_tree = match range.base.as_ref() {
+        Some(base) => Some(revision_tree(repo, base)?),
+        None => None,
+    };
+    Ok((base_tree, Some(head_tree)))
+}
+
+/// Cache key for the diff between `old_tree` and `new_tree`. A missing new
+/// tree stands for the working directory, which is keyed on its
+/// [`cache::workspace_fingerprint`] so entries stop matching as soon as a file
+/// in it changes.
+fn cache_key(
+    repository: &Repository,
+    old_tree: Option<&git2::Tree<'_>>,
+    new_tree: Option<&git2::Tree<'_>>,
+    request: &DiffRequest,
+) -> Result<CacheKey> {
+    let new_tree = match new_tree {
+        Some(tree) => tree.id(),
+        None => cache::workspace_fingerprint(repository.git_repo())?,
+    };
+    Ok(CacheKey {
+        old_tree: old_tree.map(git2::Tree::id),
+        new_tree,
+        request: request.clone(),
+    })
+}
+
+fn commit_tree<'repo>(repo: &'repo git2::Repository, oid: &str) -> Result<git2::Tree<'repo>> {
+    let oid = Oid::from_str(oid)?;
+    let commit = repo.find_commit(oid)?;
+    Ok(commit.tree()?)
+}
+
+/// Build every file of `diff`, decoding non-UTF-8 text with `decoder` when
+/// given.
+fn build_files(
+    diff: &git2::Diff<'_>,
+    request: &DiffRequest,
+    decoder: Option<Decoder<'_>>,
+) -> Result<Vec<DiffFile>> {
+    let mut builder = DiffBuilder::new(request)?;
+    builder.decoder = decoder;
+    let builder = RefCell::new(builder);
+
+    {
+        let mut file_cb = |delta: git2::DiffDelta<'_>, _progress: f32| {
+            builder.borrow_mut().start_file(&delta);
+            true
+        };
+
+        let mut binary_cb = |_: git2::DiffDelta<'_>, _: git2::DiffBinary<'_>| {
+            builder.borrow_mut().mark_binary();
+            true
+        };
+
+        let mut hunk_cb = |_: git2::DiffDelta<'_>, hunk: git2::DiffHunk<'_>| {
+            builder.borrow_mut().start_hunk(&hunk);
+            true
+        };
+
+        let mut line_cb =
+            |_: git2::DiffDelta<'_>, _: Option<git2::DiffHunk<'_>>, line: git2::DiffLine<'_>| {
+                builder.borrow_mut().push_line(&line);
+                true
+            };
+
+        diff.foreach(
+            &mut file_cb,
+            Some(&mut binary_cb),
+            Some(&mut hunk_cb),
+            Some(&mut line_cb),
+        )?;
+    }
+
+    Ok(builder.into_inner().finish())
+}
+
+/// Summarize every file in `diff` without building hunk lines. Only what the
+/// delta and its line stats carry is reported, plus the classification,
+/// submodule state, and staging state; blobs are not read.
+fn summarize_files(
+    repo: &git2::Repository,
+    classifier: &Classifier,
+    diff: &git2::Diff<'_>,
+    workspace: bool,
+    request: &DiffRequest,
+) -> Result<Vec<DiffFileSummary>> {
+    let stages = if workspace {
+        Some(stage::Stages::read(repo, request)?)
+    } else {
+        None
+    };
+    let mut summaries = Vec::with_capacity(diff.deltas().len());
+    for index in 0..diff.deltas().len() {
+        if let Some(mut file) = build_file(diff, index, false, request, None)? {
+            submodules::describe(repo, workspace, request, &mut file);
+            file.classification = classifier.classify(repo, &file.path);
+            if let Some(stages) = &stages {
+                stages.annotate(&mut file);
+            }
+            summaries.push(DiffFileSummary::from(&file));
+        }
+    }
+    Ok(summaries)
+}
+
+/// Resolve metadata that libgit2 leaves out of a delta.
+fn describe_file(
+    repo: &git2::Repository,
+    classifier: &Classifier,
+    workspace: bool,
+    request: &DiffRequest,
+    file: &mut DiffFile,
+) {
+    blobs::describe(repo, file);
+    submodules::describe(repo, workspace, request, file);
+    file.classification = classifier.classify(repo, &file.path);
+    if request.structural_summary {
+        structure::annotate(repo, workspace, file);
+    }
+}
+
+fn build_file_at_path(
+    diff: &git2::Diff<'_>,
+    path: &str,
+    request: &DiffRequest,
+    decoder: Decoder<'_>,
+) -> Result<Option<DiffFile>> {
+    for (index, delta) in diff.deltas().enumerate() {
+        if delta.status() != git2::Delta::Unmodified && delta_path(&delta) == path {
+            return build_file(diff, index, true, request, Some(decoder));
+        }
+    }
+    Ok(None)
+}
+
+/// Build the file at `index` of `diff`. Without `include_hunks` only the file
+/// metadata and line stats are produced. Excluded files yield `None`.
+/// `decoder`, when given, decodes non-UTF-8 text.
+fn build_file(
+    diff: &git2::Diff<'_>,
+    index: usize,
+    include_hunks: bool,
+    request: &DiffRequest,
+    decoder: Option<Decoder<'_>>,
+) -> Result<Option<DiffFile>> {
+    let mut builder = DiffBuilder::new(request)?;
+    builder.decoder = decoder;
+    let Some(patch) = git2::Patch::from_diff(diff, index)? else {
+        let Some(delta) = diff.get_delta(index) else {
+            return Ok(None);
+        };
+        builder.start_file(&delta);
+        return Ok(builder.finish().pop());
+    };
+
+    if include_hunks {
+        return build_patch(&patch, builder);
+    }
+
+    let delta = patch.delta();
+    builder.start_file(&delta);
+    if delta.flags().is_binary() {
+        builder.mark_binary();
+    }
+
+    let (_, additions, deletions) = patch.line_stats()?;
+    let mut file = builder.finish().pop();
+    if let Some(file) = file.as_mut() {
+        if !file.is_binary && file.submodule.is_none() {
+            file.stats = DiffStats::new(
+                u32::try_from(additions).unwrap_or(u32::MAX),
+                u32::try_from(deletions).unwrap_or(u32::MAX),
+            );
+        }
+    }
+    Ok(file)
+}
+
+/// Build a file, including its hunks, from a single-file patch.
+fn build_patch(patch: &git2::Patch<'_>, mut builder: DiffBuilder<'_>) -> Result<Option<DiffFile>> {
+    let delta = patch.delta();
+    builder.start_file(&delta);
+    if delta.flags().is_binary() {
+        builder.mark_binary();
+    }
+    builder.push_hunks(patch)?;
+    Ok(builder.finish().pop())
+}
+
+/// Keep at most `max_lines` hunk lines of `file`.
+fn cap_hunks(file: DiffFile, max_lines: Option<u32>) -> FileHunks {
+    let mut remaining = max_lines.map_or(usize::MAX, |max| max as usize);
+    let mut truncated = false;
+    let mut hunks = Vec::with_capacity(file.hunks.len());
+
+    for mut hunk in file.hunks {
+        if remaining == 0 {
+            truncated = true;
+            break;
+        }
+        if hunk.lines.len() > remaining {
+            hunk.lines.truncate(remaining);
+            truncated = true;
+        }
+        remaining -= hunk.lines.len();
+        hunks.push(hunk);
+        if truncated {
+            break;
+        }
+    }
+
+    FileHunks {
+        path: file.path,
+        hunks,
+        truncated,
+    }
+}
+
+fn generate_diff(
+    repo: &git2::Repository,
+    classifier: &Classifier,
+    base_tree: Option<&git2::Tree<'_>>,
+    head_tree: &git2::Tree<'_>,
+    request: &DiffRequest,
+) -> Result<Vec<DiffFile>> {
+    let mut files = build_files(
+        &raw_diff(repo, base_tree, Some(head_tree), request)?,
+        request,
+        Some(Decoder::new(repo, false, request)),
+    )?;
+    annotate_files(repo, classifier, false, &mut files, request);
+    Ok(files)
+}
+
+fn generate_workspace_diff(
+    repo: &git2::Repository,
+    classifier: &Classifier,
+    head_tree: &git2::Tree<'_>,
+    request: &DiffRequest,
+) -> Result<Vec<DiffFile>> {
+    let mut files = build_files(
+        &raw_diff(repo, Some(head_tree), None, request)?,
+        request,
+        Some(Decoder::new(repo, true, request)),
+    )?;
+    annotate_files(repo, classifier, true, &mut files, request);
+    annotate_stages(repo, &mut files, request)?;
+    Ok(files)
+}
+
+/// Set the staging state of every file from the repository status.
+fn annotate_stages(
+    repo: &git2::Repository,
+    files: &mut [DiffFile],
+    request: &DiffRequest,
+) -> Result<()> {
+    let stages = stage::Stages::read(repo, request)?;
+    for file in files {
+        stages.annotate(file);
+    }
+    Ok(())
+}
+
+/// Revision standing for the index, written as the tree `oid`.
+fn index_revision(oid: String) -> Revision {
+    tree_revision(
+        oid,
+        RevisionKind::Index,
+        "Staged changes".to_owned(),
+        Some("index".to_owned()),
+    )
+}
+
+/// Revision standing for the working directory, written out as a tree so the
+/// diff and any follow-up reads share one snapshot.
+fn workdir_revision(repository: &Repository) -> Result<Revision> {
+    Ok(tree_revision(
+        repository.write_workspace_tree()?,
+        RevisionKind::Workdir,
+        "Working directory".to_owned(),
+        None,
+    ))
+}
+
+/// Revision for the tree `oid`, which no commit points to.
+const fn tree_revision(
+    oid: String,
+    kind: RevisionKind,
+    summary: String,
+    reference: Option<String>,
+) -> Revision {
+    Revision {
+        oid,
+        reference,
+        summary: Some(summary),
+        author: None,
+        committer: None,
+        timestamp: None,
+        kind,
+    }
+}
+
+/// Resolve blob, submodule, and classification metadata for `files`, and
+/// attach syntax tokens and moved-block markers when the request asks for
+/// them. Hunks of generated files are dropped first when requested.
+/// `workspace` marks the head side as the working directory.
+fn annotate_files(
+    repo: &git2::Repository,
+    classifier: &Classifier,
+    workspace: bool,
+    files: &mut [DiffFile],
+    request: &DiffRequest,
+) {
+    for file in files.iter_mut() {
+        describe_file(repo, classifier, workspace, request, file);
+        if request.skip_generated_hunks && file.classification.is_generated() {
+            file.hunks.clear();
+        }
+        if request.syntax_highlighting {
+            tokens::annotate_file(repo, workspace, file);
+        }
+        if request.enclosing_symbols {
+            sections::annotate_file(repo, workspace, file);
+        }
+    }
+    if request.detect_moves {
+        moves::annotate(files);
+    }
+}
+
+/// Run libgit2's diff between two trees, or between `old_tree` and the
+/// working directory (including the index) when `new_tree` is `None`.
+fn raw_diff<'repo>(
+    repo: &'repo git2::Repository,
+    old_tree: Option<&git2::Tree<'_>>,
+    new_tree: Option<&git2::Tree<'_>>,
+    request: &DiffRequest,
+) -> Result<git2::Diff<'repo>> {
+    let mut raw_diff = if let Some(new_tree) = new_tree {
+        let mut options = tree_diff_options(request);
+        repo.diff_tree_to_tree(old_tree, Some(new_tree), Some(&mut options))?
+    } else {
+        let mut options = workspace_diff_options(request);
+        repo.diff_tree_to_workdir_with_index(old_tree, Some(&mut options))?
+    };
+    configure_similarity(&mut raw_diff, request)?;
+    Ok(raw_diff)
+}
+
+fn tree_diff_options(request: &DiffRequest) -> DiffOptions {
+    let mut options = DiffOptions::new();
+    options
+        .context_lines(request.context_lines)
+        .interhunk_lines(0)
+        .ignore_submodules(false)
+        .indent_heuristic(true)
+        .ignore_whitespace(request.whitespace == WhitespaceMode::IgnoreAll)
+        .ignore_whitespace_change(request.whitespace == WhitespaceMode::IgnoreChange)
+        .ignore_whitespace_eol(request.whitespace == WhitespaceMode::IgnoreAtEol)
+        .include_unmodified(request.detect_copies && request.copies_from_unmodified)
+        .include_typechange(true);
+    for path in &request.include_paths {
+        options.pathspec(path);
+    }
+    options
+}
+
+/// Matcher for the request's excluded paths, or `None` when nothing is
+/// excluded. libgit2 only applies pathspecs as includes, so excluded files
+/// are skipped while the diff is built.
+fn exclusions(request: &DiffRequest) -> Result<Option<git2::Pathspec>> {
+    if request.exclude_paths.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(git2::Pathspec::new(
+        request.exclude_paths.iter().map(String::as_str),
+    )?))
+}
+
+fn is_excluded(exclusions: Option<&git2::Pathspec>, path: &str) -> bool {
+    exclusions.is_some_and(|spec| {
+        spec.matches_path(std::path::Path::new(path), git2::PathspecFlags::DEFAULT)
+    })
+}
+
+fn workspace_diff_options(request: &DiffRequest) -> DiffOptions {
+    let mut options = tree_diff_options(request);
+    options
+        .include_untracked(true)
+        .recurse_untracked_dirs(true)
+        .include_ignored(false)
+        .include_unmodified(false)
+        .include_typechange(true);
+    options
+}
+
+fn configure_similarity(diff: &mut git2::Diff<'_>, request: &DiffRequest) -> Result<()> {
+    let mut find_options = DiffFindOptions::new();
+    find_options
+        .renames(true)
+        .renames_from_rewrites(true)
+        .rename_threshold(request.rename_threshold)
+        .copies(request.detect_copies)
+        .copies_from_unmodified(request.detect_copies && request.copies_from_unmodified)
+        .copy_threshold(request.copy_threshold)
+        .break_rewrites_for_renames_only(true)
+        .remove_unmodified(true);
+
+    diff.find_similar(Some(&mut find_options))?;
+    Ok(())
+}
+
+/// Reads the text of both sides of a file to decode it from its detected
+/// encoding.
+#[derive(Clone, Copy)]
+struct Decoder<'a> {
+    repo: &'a git2::Repository,
+    /// Whether the new side of each file is in the working directory.
+    workspace: bool,
+    request: &'a DiffRequest,
+}
+
+impl<'a> Decoder<'a> {
+    const fn new(repo: &'a git2::Repository, workspace: bool, request: &'a DiffRequest) -> Self {
+        Self {
+            repo,
+            workspace,
+            request,
+        }
+    }
+
+    /// Set the encoding of `file`, detected from the first bytes of each
+    /// side, and return the decoded text of both sides unless libgit2's
+    /// view of them as UTF-8 can be used as is.
+    fn decode(self, file: &mut DiffFile) -> Option<(String, String)> {
+        if file.submodule.is_some() {
+            return None;
+        }
+        let old_path = file.old_path.clone().unwrap_or_else(|| file.path.clone());
+        let old = file
+            .old_blob
+            .as_ref()
+            .and_then(|blob| self.detect(&old_path, blob, false));
+        let new = file
+            .new_blob
+            .as_ref()
+            .and_then(|blob| self.detect(&file.path, blob, self.workspace));
+
+        let detected = new.as_ref().or(old.as_ref())?;
+        if file.is_binary && !encoding::is_utf16(detected) {
+            return None;
+        }
+        file.encoding = Some(detected.clone());
+        if old.as_ref().map_or(true, encoding::is_plain_utf8)
+            && new.as_ref().map_or(true, encoding::is_plain_utf8)
+        {
+            return None;
+        }
+
+        let read = |path: &str, blob: Option<&BlobInfo>, detected: Option<TextEncoding>| {
+            blob.zip(detected)
+                .and_then(|(blob, detected)| {
+                    let bytes = blobs::read(self.repo, path, blob)?;
+                    Some(encoding::decode(&bytes, &detected))
+                })
+                .unwrap_or_default()
+        };
+        Some((
+            read(&old_path, file.old_blob.as_ref(), old),
+            read(&file.path, file.new_blob.as_ref(), new),
+        ))
+    }
+
+    /// Detect the encoding of one side from its first bytes.
+    fn detect(self, path: &str, blob: &BlobInfo, workdir: bool) -> Option<TextEncoding> {
+        let prefix = blobs::read_prefix(self.repo, path, blob, encoding::PREFIX_LEN)?;
+        Some(if workdir {
+            encoding::detect_prefix_in_workdir(self.repo, path, &prefix)
+        } else {
+            encoding::detect_prefix(&prefix)
+        })
+    }
+}
+
+/// Collects libgit2 diff callbacks into [`DiffFile`]s, applying the request's
+/// exclusions and size limits along the way. The default builder keeps every
+/// file and line.
+#[derive(Default)]
+struct DiffBuilder<'a> {
+    files: Vec<DiffFile>,
+    exclusions: Option<git2::Pathspec>,
+    /// Decodes files whose text is not plain UTF-8 as they are started.
+    decoder: Option<Decoder<'a>>,
+    max_total_bytes: Option<u64>,
+    max_file_lines: Option<u32>,
+    max_line_chars: Option<u32>,
+    /// Whether callbacks currently belong to a delta that is not reported,
+    /// or whose hunks were already built from decoded text.
+    skipping: bool,
+    /// Bytes of line text kept so far across all files.
+    bytes: u64,
+    /// Whether `max_total_bytes` has been reached.
+    budget_spent: bool,
+    /// Lines kept so far in the current file.
+    file_lines: u32,
+}
+
+impl DiffBuilder<'_> {
+    fn new(request: &DiffRequest) -> Result<Self> {
+        Ok(Self {
+            exclusions: exclusions(request)?,
+            max_total_bytes: request.max_total_bytes,
+            max_file_lines: request.max_file_lines,
+            max_line_chars: request.max_line_chars,
+            ..Self::default()
+        })
+    }
+
+    fn start_file(&mut self, delta: &git2::DiffDelta<'_>) {
+        // Unmodified entries are only included as copy-detection sources and
+        // never carry hunks; libgit2 leaves them behind when nothing matched.
+        self.skipping = delta.status() == git2::Delta::Unmodified
+            || is_excluded(self.exclusions.as_ref(), &delta_path(delta));
+        self.file_lines = 0;
+        if self.skipping {
+            return;
+        }
+
+        let submodule = submodules::submodule_change(delta);
+        let status = if submodule.is_some() {
+            FileStatus::Submodule
+        } else {
+            convert_status(delta.status())
+        };
+        let (old_path, new_path) = delta_paths(delta);
+
+        let diff_file = DiffFile {
+            path: delta_path(delta),
+            old_path: if matches!(status, FileStatus::Renamed | FileStatus::Copied)
+                && old_path != new_path
+            {
+                old_path
+            } else {
+                None
+            },
+            status,
+            stats: DiffStats::ZERO,
+            is_binary: delta.new_file().is_binary() || delta.old_file().is_binary(),
+            old_mode: blobs::file_mode(&delta.old_file()),
+            new_mode: blobs::file_mode(&delta.new_file()),
+            old_blob: blobs::blob_info(&delta.old_file()),
+            new_blob: blobs::blob_info(&delta.new_file()),
+            submodule,
+            classification: FileClassification::Source,
+            stage: None,
+            truncated: false,
+            encoding: None,
+            whitespace_only: false,
+            line_endings_only: false,
+            structure: None,
+            hunks: Vec::new(),
+        };
+
+        self.files.push(diff_file);
+        if let Some(decoder) = self.decoder {
+            self.decode_file(decoder);
+        }
+    }
+
+    /// Detect the encoding of the file just started and, unless both sides
+    /// are plain UTF-8, build its hunks from the decoded text so lines no
+    /// longer show replacement characters. UTF-16 files, which libgit2
+    /// reports as binary, gain hunks this way. The request's size limits
+    /// apply to the decoded lines as to any other.
+    fn decode_file(&mut self, decoder: Decoder<'_>) {
+        let Some(file) = self.files.last_mut() else {
+            return;
+        };
+        let Some((old_text, new_text)) = decoder.decode(file) else {
+            return;
+        };
+        let old_path = file.old_path.clone().unwrap_or_else(|| file.path.clone());
+        let mut options = tree_diff_options(decoder.request);
+        let Ok(patch) = git2::Patch::from_buffers(
+            old_text.as_bytes(),
+            Some(std::path::Path::new(&old_path)),
+            new_text.as_bytes(),
+            Some(std::path::Path::new(&file.path)),
+            Some(&mut options),
+        ) else {
+            return;
+        };
+
+        file.is_binary = false;
+        let rebuilt = self.push_hunks(&patch);
+        // libgit2's own hunks for the delta would repeat the decoded ones.
+        self.skipping = true;
+        if rebuilt.is_err() {
+            if let Some(file) = self.files.last_mut() {
+                file.truncated = true;
+            }
+        }
+    }
+
+    /// Add every hunk and line of `patch` to the current file.
+    fn push_hunks(&mut self, patch: &git2::Patch<'_>) -> Result<()> {
+        for hunk_index in 0..patch.num_hunks() {
+            let (hunk, line_count) = patch.hunk(hunk_index)?;
+            self.start_hunk(&hunk);
+            for line_index in 0..line_count {
+                self.push_line(&patch.line_in_hunk(hunk_index, line_index)?);
+            }
+        }
+        Ok(())
+    }
+
+    fn mark_binary(&mut self) {
+        if self.skipping {
+            return;
+        }
+        if let Some(current) = self.files.last_mut() {
+            current.is_binary = true;
+            current.hunks.clear();
+        }
+    }
+
+    fn start_hunk(&mut self, hunk: &git2::DiffHunk<'_>) {
+        if self.skipping {
+            return;
+        }
+        if let Some(file) = self.files.last_mut() {
+            if file.is_binary || file.submodule.is_some() || file.truncated {
+                return;
+            }
+
+            let header = DiffRange {
+                base_start: hunk.old_start(),
+                base_lines: hunk.old_lines(),
+                head_start: hunk.new_start(),
+                head_lines: hunk.new_lines(),
+            };
+
+            file.hunks.push(DiffHunk {
+                header,
+                section: parse_section(hunk.header()),
+                lines: Vec::new(),
+                whitespace_only: false,
+                line_endings_only: false,
+            });
+        }
+    }
+
+    fn push_line(&mut self, line: &git2::DiffLine<'_>) {
+        if self.skipping {
+            return;
+        }
+        let Some(file) = self.files.last_mut() else {
+            return;
+        };
+
+        if file.is_binary || file.submodule.is_some() {
+            return;
+        }
+
+        let Some(hunk) = file.hunks.last_mut() else {
+            return;
+        };
+
+        let line_type = line.origin_value();
+        let Some(kind) = convert_line_kind(line_type) else {
+            return;
+        };
+
+        if matches!(kind, DiffLineKind::Addition) {
+            file.stats.additions += 1;
+        } else if matches!(kind, DiffLineKind::Deletion) {
+            file.stats.deletions += 1;
+        }
+
+        if file.truncated
+            || self
+                .max_file_lines
+                .is_some_and(|max| self.file_lines >= max)
+        {
+            file.truncated = true;
+            return;
+        }
+
+        let mut text = sanitize_line(line.content());
+        let cut = self.max_line_chars.and_then(|max| {
+            text.char_indices()
+                .nth(usize::try_from(max).unwrap_or(usize::MAX))
+                .map(|(offset, _)| offset)
+        });
+        if let Some(offset) = cut {
+            text.truncate(offset);
+        }
+
+        // Once one line does not fit, later files are truncated as well so
+        // the diff never resumes past a gap.
+        let bytes = self.bytes.saturating_add(text.len() as u64);
+        if self.budget_spent || self.max_total_bytes.is_some_and(|max| bytes > max) {
+            self.budget_spent = true;
+            file.truncated = true;
+            return;
+        }
+        self.bytes = bytes;
+        self.file_lines += 1;
+
+        hunk.lines.push(DiffLine {
+            kind,
+            text,
+            base_line: line.old_lineno(),
+            head_line: line.new_lineno(),
+            highlights: Vec::new(),
+            tokens: Vec::new(),
+            moved: None,
+            truncated: cut.is_some(),
+            line_ending: line_ending(line.content()),
+            enclosing_symbol: None,
+        });
+    }
+
+    fn finish(mut self) -> Vec<DiffFile> {
+        for file in &mut self.files {
+            if file.truncated {
+                file.hunks.retain(|hunk| !hunk.lines.is_empty());
+            }
+            for hunk in &mut file.hunks {
+                intraline::annotate_hunk(hunk);
+                whitespace::annotate_hunk(hunk);
+            }
+            whitespace::annotate_file(file);
+        }
+        self.files
+    }
+}
+
+fn delta_paths(delta: &git2::DiffDelta<'_>) -> (Option<String>, Option<String>) {
+    let old_path = delta
+        .old_file()
+        .path()
+        .map(|path| path.to_string_lossy().into_owned());
+    let new_path = delta
+        .new_file()
+        .path()
+        .map(|path| path.to_string_lossy().into_owned());
+    (old_path, new_path)
+}
+
+/// Path a delta is reported under: the new path, or the old path for deletions.
+fn delta_path(delta: &git2::DiffDelta<'_>) -> String {
+    let (old_path, new_path) = delta_paths(delta);
+    match (convert_status(delta.status()), new_path, old_path) {
+        (FileStatus::Deleted, _, Some(old)) | (_, None, Some(old)) => old,
+        (_, Some(newer), _) => newer,
+        _ => String::new(),
+    }
+}
+
+const fn convert_status(status: git2::Delta) -> FileStatus {
+    match status {
+        git2::Delta::Added | git2::Delta::Untracked => FileStatus::Added,
+        git2::Delta::Deleted => FileStatus::Deleted,
+        git2::Delta::Modified
+        | git2::Delta::Ignored
+        | git2::Delta::Unreadable
+        | git2::Delta::Conflicted
+        | git2::Delta::Unmodified => FileStatus::Modified,
+        git2::Delta::Renamed => FileStatus::Renamed,
+        git2::Delta::Copied => FileStatus::Copied,
+        git2::Delta::Typechange => FileStatus::TypeChange,
+    }
+}
+
+fn parse_section(header: &[u8]) -> Option<String> {
+    let header_str = std::str::from_utf8(header).ok()?;
+    let trimmed = header_str.trim_end_matches(['\r', '\n']);
+    let closing = trimmed.rfind("@@")?;
+    let section = trimmed[closing + 2..].trim();
+    if section.is_empty() {
+        None
+    } else {
+        Some(section.to_owned())
+    }
+}
+
+/// Map a libgit2 line origin to a line kind. The "no newline at end of file"
+/// markers are not lines of their own; the line before them already carries
+/// [`LineEnding::Missing`].
+const fn convert_line_kind(origin: DiffLineType) -> Option<DiffLineKind> {
+    match origin {
+        DiffLineType::Context => Some(DiffLineKind::Context),
+        DiffLineType::Addition => Some(DiffLineKind::Addition),
+        DiffLineType::Deletion => Some(DiffLineKind::Deletion),
+        DiffLineType::ContextEOFNL
+        | DiffLineType::AddEOFNL
+        | DiffLineType::DeleteEOFNL
+        | DiffLineType::Binary
+        | DiffLineType::FileHeader
+        | DiffLineType::HunkHeader => None,
+    }
+}
+
+fn sanitize_line(bytes: &[u8]) -> String {
+    std::str::from_utf8(bytes).map_or_else(
+        |_| {
+            let text = String::from_utf8_lossy(bytes);
+            trim_line_endings(text.to_string())
+        },
+        |text| trim_line_endings(text.to_owned()),
+    )
+}
+
+/// Terminator at the end of raw line `bytes`.
+fn line_ending(bytes: &[u8]) -> LineEnding {
+    if bytes.ends_with(b"\r\n") {
+        LineEnding::CrLf
+    } else if bytes.ends_with(b"\n") {
+        LineEnding::Lf
+    } else {
+        LineEnding::Missing
+    }
+}
+
+fn trim_line_endings(mut line: String) -> String {
+    if line.ends_with('\n') {
+        line.pop();
+        if line.ends_with('\r') {
+            line.pop();
+        }
+    }
+    line
+}
+
+/// `request` reduced to the options that decide which lines differ, with
+/// presentation and size caps turned off.
+fn comparison_request(request: &DiffRequest) -> DiffRequest {
+    DiffRequest {
+        syntax_highlighting: false,
+        submodule_log: false,
+        structural_summary: false,
+        enclosing_symbols: false,
+        detect_moves: false,
+        skip_generated_hunks: false,
+        max_total_bytes: None,
+        max_file_lines: None,
+        max_line_chars: None,
+        ..request.clone()
+    }
+}
+
+/// Diff models for unit tests of the submodules. Fields a test does not set
+/// take their serde defaults, so new fields need no changes here.
+#[cfg(test)]
+mod fixtures {
+    use crate::api::diff::{DiffFile, DiffHunk, DiffLine, DiffLineKind};
+
+    pub(super) fn line(kind: DiffLineKind, text: &str) -> DiffLine {
+        serde_json::from_value(serde_json::json!({ "kind": kind, "text": text }))
+            .expect("diff line")
+    }
+
+    /// A hunk of `lines` starting at line 1 on both sides.
+    pub(super) fn hunk(lines: Vec<DiffLine>) -> DiffHunk {
+        let count = |kind: DiffLineKind| {
+            lines
+                .iter()
+                .filter(|line| line.kind == DiffLineKind::Context || line.kind == kind)
+                .count()
+        };
+        let mut hunk: DiffHunk = serde_json::from_value(serde_json::json!({
+            "header": {
+                "base_start": 1,
+                "base_lines": count(DiffLineKind::Deletion),
+                "head_start": 1,
+                "head_lines": count(DiffLineKind::Addition),
+            },
+        }))
+        .expect("diff hunk");
+        hunk.lines = lines;
+        hunk
+    }
+
+    /// A modified file at `path` with a single hunk of `lines`.
+    pub(super) fn file(path: &str, lines: Vec<DiffLine>) -> DiffFile {
+        let mut file: DiffFile =
+            serde_json::from_value(serde_json::json!({ "path": path, "status": "modified" }))
+                .expect("diff file");
+        file.hunks = vec![hunk(lines)];
+        file
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::diff::{FileVersion, ImageFormat, StageState, SymbolChangeKind, SyntaxKind},
+        repository::Repository,
+        Error,
+    };
+    use git2::{IndexAddOption, Repository as GitRepository};
+    use tempfile::TempDir;
+
+    #[test]
+    fn generates_diff_for_modified_file() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("file.txt"), "hello\nworld\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        write_file(temp.path().join("file.txt"), "hello\nprism\nworld\n");
+        stage_and_commit(&git_repo, "Update greeting")?;
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        let diff = engine.diff(&repository, &DiffRequest::default())?;
+
+        assert_eq!(diff.files.len(), 1);
+        let file = &diff.files[0];
+        assert_eq!(file.path, "file.txt");
+        assert_eq!(file.status, FileStatus::Modified);
+        assert_eq!(file.stats.additions, 1);
+        assert_eq!(file.stats.deletions, 0);
+        assert_eq!(file.hunks.len(), 1);
+        assert!(!file.hunks[0].lines.is_empty());
+
+        let addition = file.hunks[0]
+            .lines
+            .iter()
+            .find(|line| line.kind == DiffLineKind::Addition)
+            .expect("addition line");
+        assert_eq!(addition.head_line, Some(2));
+        assert_eq!(addition.text, "prism");
+
+        Ok(())
+    }
+
+    #[test]
+    fn populates_intraline_highlights_for_modified_lines() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("lib.rs"), "let value = old_name(input);\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        write_file(temp.path().join("lib.rs"), "let value = new_name(input);\n");
+        stage_and_commit(&git_repo, "Rename call")?;
+
+        let repository = Repository::open(temp.path())?;
+        let diff = DiffEngine::new().diff(&repository, &DiffRequest::default())?;
+
+        let lines = &diff.files[0].hunks[0].lines;
+        let deletion = lines
+            .iter()
+            .find(|line| line.kind == DiffLineKind::Deletion)
+            .expect("deletion line");
+        let addition = lines
+            .iter()
+            .find(|line| line.kind == DiffLineKind::Addition)
+            .expect("addition line");
+
+        let expected = vec![crate::api::diff::LineHighlight {
+            start_column: 12,
+            end_column: 20,
+        }];
+        assert_eq!(deletion.highlights, expected);
+        assert_eq!(addition.highlights, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn respects_requested_context_lines() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("file.txt"), "a\nb\nc\nd\ne\nf\ng\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        write_file(temp.path().join("file.txt"), "a\nb\nc\nD\ne\nf\ng\n");
+        stage_and_commit(&git_repo, "Change middle line")?;
+
+        let repository = Repository::open(temp.path())?;
+        let request = DiffRequest {
+            context_lines: 1,
+            ..DiffRequest::default()
+        };
+        let diff = DiffEngine::new().diff(&repository, &request)?;
+
+        let hunk = &diff.files[0].hunks[0];
+        assert_eq!(hunk.header.base_start, 3);
+        assert_eq!(hunk.header.base_lines, 3);
+        let context = hunk
+            .lines
+            .iter()
+            .filter(|line| line.kind == DiffLineKind::Context)
+            .count();
+        assert_eq!(context, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ignores_whitespace_when_requested() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("file.txt"), "fn main() {\n    run();\n}\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        write_file(temp.path().join("file.txt"), "fn main() {\n\trun();  \n}\n");
+        stage_and_commit(&git_repo, "Reindent")?;
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+
+        let default_diff = engine.diff(&repository, &DiffRequest::default())?;
+        assert_eq!(default_diff.files[0].stats.additions, 1);
+
+        let at_eol = DiffRequest {
+            whitespace: WhitespaceMode::IgnoreAtEol,
+            ..DiffRequest::default()
+        };
+        let eol_diff = engine.diff(&repository, &at_eol)?;
+        assert_eq!(eol_diff.files[0].stats.additions, 1);
+
+        let ignore_all = DiffRequest {
+            whitespace: WhitespaceMode::IgnoreAll,
+            ..DiffRequest::default()
+        };
+        let all_diff = engine.diff(&repository, &ignore_all)?;
+        assert!(all_diff
+            .files
+            .iter()
+            .all(|file| file.stats == DiffStats::ZERO && file.hunks.is_empty()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn detects_renamed_file() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("old.txt"), "one\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        let old_path = temp.path().join("old.txt");
+        let new_path = temp.path().join("new.txt");
+        std::fs::rename(&old_path, &new_path).map_err(|source| Error::Io {
+            path: old_path.to_string_lossy().into_owned(),
+            source,
+        })?;
+        write_file(new_path, "one\ntwo\n");
+        stage_and_commit(&git_repo, "Rename and edit")?;
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        let diff = engine.diff(&repository, &DiffRequest::default())?;
+
+        assert_eq!(diff.files.len(), 1);
+        let file = &diff.files[0];
+        assert_eq!(file.path, "new.txt");
+        assert_eq!(file.old_path.as_deref(), Some("old.txt"));
+        assert_eq!(file.status, FileStatus::Renamed);
+        assert_eq!(file.stats.additions, 1);
+        assert_eq!(file.stats.deletions, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn handles_initial_commit_as_addition() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("hello.txt"), "hi prism\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        let diff = engine.diff(&repository, &DiffRequest::default())?;
+
+        assert_eq!(diff.range.base, None);
+        assert_eq!(diff.files.len(), 1);
+        let file = &diff.files[0];
+        assert_eq!(file.status, FileStatus::Added);
+        assert_eq!(file.stats.additions, 1);
+        assert_eq!(file.stats.deletions, 0);
+        assert!(file.hunks.iter().all(|h| h.header.base_lines == 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn marks_deleted_file_with_deletions() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("gone.txt"), "remove me\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        std::fs::remove_file(temp.path().join("gone.txt")).expect("remove file");
+        stage_and_commit(&git_repo, "Delete file")?;
+
+        let repository = Repository::open(temp.path())?;
+        let diff = DiffEngine::new().diff(&repository, &DiffRequest::default())?;
+
+        assert_eq!(diff.files.len(), 1);
+        let file = &diff.files[0];
+        assert_eq!(file.path, "gone.txt");
+        assert_eq!(file.status, FileStatus::Deleted);
+        assert_eq!(file.stats.additions, 0);
+        assert!(file.stats.deletions > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn marks_binary_file_and_drops_hunks() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_bytes(temp.path().join("asset.bin"), &[0_u8, 159, 255, 0]);
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        write_bytes(temp.path().join("asset.bin"), &[5_u8, 6, 7, 8]);
+        stage_and_commit(&git_repo, "Update binary")?;
+
+        let repository = Repository::open(temp.path())?;
+        let diff = DiffEngine::new().diff(&repository, &DiffRequest::default())?;
+
+        assert_eq!(diff.files.len(), 1);
+        let file = &diff.files[0];
+        assert!(file.is_binary);
+        assert!(file.hunks.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn diff_workspace_surfaces_staged_and_unstaged_changes() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("tracked.txt"), "one\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        write_file(temp.path().join("tracked.txt"), "one\ntwo\n");
+        stage_file(&git_repo, "tracked.txt")?;
+        write_file(temp.path().join("tracked.txt"), "one\ntwo\nthree\n");
+
+        write_file(temp.path().join("notes.md"), "draft\n");
+
+        let repository = Repository::open(temp.path())?;
+        let diff = DiffEngine::new().diff_workspace(&repository, &DiffRequest::default())?;
+
+        let paths: Vec<_> = diff.files.iter().map(|file| file.path.as_str()).collect();
+        assert!(paths.contains(&"tracked.txt"));
+        assert!(paths.contains(&"notes.md"));
+
+        let tracked = diff
+            .files
+            .iter()
+            .find(|file| file.path == "tracked.txt")
+            .expect("tracked file present");
+        assert_eq!(tracked.status, FileStatus::Modified);
+        assert!(tracked.stats.additions > 0);
+
+        let notes = diff
+            .files
+            .iter()
+            .find(|file| file.path == "notes.md")
+            .expect("notes file present");
+        assert_eq!(notes.status, FileStatus::Added);
+
+        Ok(())
+    }
+
+    #[test]
+    fn diff_workspace_returns_empty_for_clean_worktree() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("README.md"), "hello\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        let repository = Repository::open(temp.path())?;
+        let diff = DiffEngine::new().diff_workspace(&repository, &DiffRequest::default())?;
+
+        assert!(diff.files.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn diff_workspace_marks_binary_file() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_bytes(temp.path().join("asset.bin"), &[0_u8, 159, 255, 0]);
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        write_bytes(temp.path().join("asset.bin"), &[5_u8, 6, 7, 8]);
+
+        let repository = Repository::open(temp.path())?;
+        let diff = DiffEngine::new().diff_workspace(&repository, &DiffRequest::default())?;
+
+        assert_eq!(diff.files.len(), 1);
+        let file = &diff.files[0];
+        assert!(file.is_binary);
+        assert!(file.hunks.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn diff_merge_base_spans_all_branch_commits() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("file.txt"), "one\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+        let fork_point = git_repo.head()?.peel_to_commit()?;
+        git_repo.branch("trunk", &fork_point, false)?;
+
+        write_file(temp.path().join("file.txt"), "one\ntwo\n");
+        stage_and_commit(&git_repo, "First agent commit")?;
+        write_file(temp.path().join("extra.txt"), "extra\n");
+        stage_and_commit(&git_repo, "Second agent commit")?;
+        write_file(temp.path().join("draft.txt"), "draft\n");
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        let request = DiffRequest::default();
+
+        let committed = engine.diff_merge_base(&repository, Some("trunk"), false, &request)?;
+        assert_eq!(
+            committed.range.base.as_ref().map(|base| base.oid.clone()),
+            Some(fork_point.id().to_string())
+        );
+        let mut paths: Vec<_> = committed.files.iter().map(|f| f.path.as_str()).collect();
+        paths.sort_unstable();
+        assert_eq!(paths, vec!["extra.txt", "file.txt"]);
+
+        let with_workspace = engine.diff_merge_base(&repository, Some("trunk"), true, &request)?;
+        assert!(with_workspace
+            .files
+            .iter()
+            .any(|file| file.path == "draft.txt"));
+        assert_eq!(with_workspace.files.len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn diff_commits_pages_through_range() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("base.txt"), "base\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+        let base = Repository::open(temp.path())?
+            .head_revision()?
+            .expect("base revision");
+
+        for name in ["one.txt", "two.txt", "three.txt"] {
+            write_file(temp.path().join(name), "content\n");
+            stage_and_commit(&git_repo, name)?;
+        }
+
+        let repository = Repository::open(temp.path())?;
+        let range = RevisionRange {
+            base: Some(base),
+            head: repository.head_revision()?.expect("head revision"),
+        };
+        let engine = DiffEngine::new();
+        let request = DiffRequest::default();
+
+        let first = engine.diff_commits(&repository, &range, 0, 2, &request)?;
+        assert_eq!(first.total_commits, 3);
+        assert_eq!(first.next_offset, Some(2));
+        let paths: Vec<_> = first
+            .diffs
+            .iter()
+            .map(|diff| diff.files[0].path.as_str())
+            .collect();
+        assert_eq!(paths, vec!["one.txt", "two.txt"]);
+
+        let second = engine.diff_commits(&repository, &range, 2, 2, &request)?;
+        assert_eq!(second.diffs.len(), 1);
+        assert_eq!(second.diffs[0].files[0].path, "three.txt");
+        assert_eq!(second.next_offset, None);
+        assert_eq!(
+            second.diffs[0].range.base.as_ref().map(|b| b.oid.as_str()),
+            Some(first.diffs[1].range.head.oid.as_str())
+        );
+
+        let empty = engine.diff_commits(&repository, &range, 1, 0, &request)?;
+        assert!(empty.diffs.is_empty());
+        assert_eq!(empty.total_commits, 3);
+        assert_eq!(empty.next_offset, None);
+        let past_end = engine.diff_commits(&repository, &range, 5, 2, &request)?;
+        assert!(past_end.diffs.is_empty());
+        assert_eq!(past_end.next_offset, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn summary_matches_full_diff_and_loads_hunks_lazily() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("a.txt"), "one\ntwo\nthree\n");
+        write_file(temp.path().join("b.txt"), "keep\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        write_file(temp.path().join("a.txt"), "one\n2\nthree\nfour\n");
+        write_file(temp.path().join("c.txt"), "new\n");
+        stage_and_commit(&git_repo, "Edit and add")?;
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        let request = DiffRequest::default();
+
+        let full = engine.diff(&repository, &request)?;
+        let summary = engine.diff_summary(&repository, &request)?;
+        let expected: Vec<_> = full.files.iter().map(DiffFileSummary::from).collect();
+        assert_eq!(summary.files, expected);
+        assert!(!summary.workspace);
+
+        let loaded = engine.load_file_hunks(
+            &repository,
+            &summary.range,
+            summary.workspace,
+            "a.txt",
+            None,
+            &request,
+        )?;
+        assert!(!loaded.truncated);
+        assert_eq!(loaded.hunks, full.files[0].hunks);
+
+        let capped = engine.load_file_hunks(
+            &repository,
+            &summary.range,
+            summary.workspace,
+            "a.txt",
+            Some(2),
+            &request,
+        )?;
+        assert!(capped.truncated);
+        assert_eq!(capped.hunks.len(), 1);
+        assert_eq!(capped.hunks[0].lines.len(), 2);
+
+        let missing = engine.load_file_hunks(
+            &repository,
+            &summary.range,
+            summary.workspace,
+            "b.txt",
+            None,
+            &request,
+        );
+        assert!(matches!(missing, Err(Error::FileNotInDiff { path }) if path == "b.txt"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn workspace_summary_tracks_latest_changes() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("file.txt"), "one\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        let request = DiffRequest::default();
+
+        write_file(temp.path().join("file.txt"), "one\ntwo\n");
+        let first = engine.diff_summary_workspace(&repository, &request)?;
+        assert!(first.workspace);
+        assert_eq!(first.files[0].stats, DiffStats::new(1, 0));
+        engine.load_file_hunks(&repository, &first.range, true, "file.txt", None, &request)?;
+
+        write_file(temp.path().join("file.txt"), "one\ntwo\nthree\n");
+        let second = engine.diff_summary_workspace(&repository, &request)?;
+        assert_eq!(second.files[0].stats, DiffStats::new(2, 0));
+
+        let hunks =
+            engine.load_file_hunks(&repository, &second.range, true, "file.txt", None, &request)?;
+        let additions = |hunks: &FileHunks| {
+            hunks.hunks[0]
+                .lines
+                .iter()
+                .filter(|line| line.kind == DiffLineKind::Addition)
+                .count()
+        };
+        assert_eq!(additions(&hunks), 2);
+
+        write_file(temp.path().join("file.txt"), "one\ntwo\nthree\nfour\n");
+        let objects = object_count(&git_repo)?;
+        let hunks =
+            engine.load_file_hunks(&repository, &second.range, true, "file.txt", None, &request)?;
+        assert_eq!(additions(&hunks), 3);
+        // Keying the cache does not write the working directory out.
+        engine.load_file_hunks(&repository, &second.range, true, "file.txt", None, &request)?;
+        assert_eq!(object_count(&git_repo)?, objects);
+
+        Ok(())
+    }
+
+    fn object_count(repo: &GitRepository) -> Result<usize> {
+        let mut count = 0;
+        repo.odb()?.foreach(|_| {
+            count += 1;
+            true
+        })?;
+        Ok(count)
+    }
+
+    #[test]
+    fn separates_staged_and_unstaged_changes() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        for name in ["staged.txt", "partial.txt", "unstaged.txt"] {
+            write_file(temp.path().join(name), "one\n");
+        }
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        write_file(temp.path().join("staged.txt"), "one\ntwo\n");
+        write_file(temp.path().join("partial.txt"), "one\ntwo\n");
+        write_file(temp.path().join("added.txt"), "new\n");
+        let mut index = git_repo.index()?;
+        for name in ["staged.txt", "partial.txt", "added.txt"] {
+            index.add_path(std::path::Path::new(name))?;
+        }
+        index.write()?;
+        write_file(temp.path().join("partial.txt"), "one\ntwo\nthree\n");
+        write_file(temp.path().join("unstaged.txt"), "zero\n");
+        write_file(temp.path().join("untracked.txt"), "draft\n");
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        let request = DiffRequest::default();
+        let stages = |files: &[DiffFile]| {
+            files
+                .iter()
+                .map(|file| (file.path.clone(), file.stage))
+                .collect::<Vec<_>>()
+        };
+
+        let workspace = engine.diff_workspace(&repository, &request)?;
+        assert_eq!(
+            stages(&workspace.files),
+            vec![
+                ("added.txt".to_owned(), Some(StageState::Staged)),
+                ("partial.txt".to_owned(), Some(StageState::PartiallyStaged)),
+                ("staged.txt".to_owned(), Some(StageState::Staged)),
+                ("unstaged.txt".to_owned(), Some(StageState::Unstaged)),
+                ("untracked.txt".to_owned(), Some(StageState::Untracked)),
+            ]
+        );
+        let summary = engine.diff_summary_workspace(&repository, &request)?;
+        assert_eq!(
+            summary
+                .files
+                .iter()
+                .map(|file| file.stage)
+                .collect::<Vec<_>>(),
+            workspace
+                .files
+                .iter()
+                .map(|file| file.stage)
+                .collect::<Vec<_>>()
+        );
+
+        let index = engine.diff_index(&repository, &request)?;
+        assert_eq!(index.range.head.reference.as_deref(), Some("index"));
+        assert_eq!(index.range.head.kind, RevisionKind::Index);
+        assert_eq!(
+            stages(&index.files),
+            vec![
+                ("added.txt".to_owned(), Some(StageState::Staged)),
+                ("partial.txt".to_owned(), Some(StageState::PartiallyStaged)),
+                ("staged.txt".to_owned(), Some(StageState::Staged)),
+            ]
+        );
+        assert_eq!(index.files[1].stats, DiffStats::new(1, 0));
+
+        let unstaged = engine.diff_unstaged(&repository, &request)?;
+        assert_eq!(unstaged.range.base.as_ref(), Some(&index.range.head));
+        assert_eq!(unstaged.range.head.kind, RevisionKind::Workdir);
+        assert_eq!(
+            stages(&unstaged.files),
+            vec![
+                ("partial.txt".to_owned(), Some(StageState::PartiallyStaged)),
+                ("unstaged.txt".to_owned(), Some(StageState::Unstaged)),
+                ("untracked.txt".to_owned(), Some(StageState::Untracked)),
+            ]
+        );
+        let added: Vec<_> = unstaged.files[0].hunks[0]
+            .lines
+            .iter()
+            .filter(|line| line.kind == DiffLineKind::Addition)
+            .map(|line| line.text.as_str())
+            .collect();
+        assert_eq!(added, vec!["three"]);
+
+        let committed = engine.diff(&repository, &request)?;
+        assert!(committed.files.iter().all(|file| file.stage.is_none()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn expands_context_between_hunks() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        let lines: Vec<String> = (1..=20).map(|n| format!("line {n}")).collect();
+        let original = lines.join("\n") + "\n";
+        write_file(temp.path().join("file.txt"), &original);
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        let updated = original
+            .replace("line 2\n", "line 2\ninserted\n")
+            .replace("line 18\n", "");
+        write_file(temp.path().join("file.txt"), &updated);
+        stage_and_commit(&git_repo, "Edit both ends")?;
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        let request = DiffRequest {
+            context_lines: 1,
+            ..DiffRequest::default()
+        };
+        let diff = engine.diff(&repository, &request)?;
+        assert_eq!(diff.files[0].hunks.len(), 2);
+
+        let lines = engine.expand_context(
+            &repository,
+            &diff.range,
+            false,
+            "file.txt",
+            DiffSide::Head,
+            5,
+            3,
+            &request,
+        )?;
+        let numbered: Vec<_> = lines
+            .iter()
+            .map(|line| (line.text.as_str(), line.base_line, line.head_line))
+            .collect();
+        assert_eq!(
+            numbered,
+            vec![
+                ("line 4", Some(4), Some(5)),
+                ("line 5", Some(5), Some(6)),
+                ("line 6", Some(6), Some(7)),
+            ]
+        );
+
+        let base_lines = engine.expand_context(
+            &repository,
+            &diff.range,
+            false,
+            "file.txt",
+            DiffSide::Base,
+            19,
+            5,
+            &request,
+        )?;
+        assert_eq!(base_lines.len(), 1);
+        assert_eq!(base_lines[0].text, "line 20");
+        assert_eq!(base_lines[0].head_line, Some(20));
+
+        Ok(())
+    }
+
+    #[test]
+    fn expands_context_past_truncated_hunks() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        let lines: Vec<String> = (1..=20).map(|n| format!("line {n}")).collect();
+        let original = lines.join("\n") + "\n";
+        write_file(temp.path().join("file.txt"), &original);
+        stage_and_commit(&git_repo, "Initial commit")?;
+        let updated = original
+            .replace("line 2\n", "line 2\ninserted\n")
+            .replace("line 18\n", "");
+        write_file(temp.path().join("file.txt"), &updated);
+        stage_and_commit(&git_repo, "Edit both ends")?;
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        let request = DiffRequest {
+            context_lines: 1,
+            max_file_lines: Some(3),
+            ..DiffRequest::default()
+        };
+        let diff = engine.diff(&repository, &request)?;
+        assert!(diff.files[0].truncated);
+        assert_eq!(diff.files[0].hunks.len(), 1);
+
+        let lines = engine.expand_context(
+            &repository,
+            &diff.range,
+            false,
+            "file.txt",
+            DiffSide::Head,
+            15,
+            6,
+            &request,
+        )?;
+        let numbered: Vec<_> = lines
+            .iter()
+            .map(|line| (line.text.as_str(), line.base_line, line.head_line))
+            .collect();
+        assert_eq!(
+            numbered,
+            vec![
+                ("line 14", Some(14), Some(15)),
+                ("line 15", Some(15), Some(16)),
+                ("line 16", Some(16), Some(17)),
+                ("line 20", Some(20), Some(20)),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn summarizes_declaration_changes_per_file() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(
+            temp.path().join("lib.rs"),
+            "pub fn parse(input: &str) -> u8 { 0 }\nfn helper() { step(); }\n",
+        );
+        stage_and_commit(&git_repo, "Initial commit")?;
+        write_file(
+            temp.path().join("lib.rs"),
+            "pub fn parse(input: &[u8]) -> u8 { 0 }\nfn assist() { step(); }\npub struct Parser;\n",
+        );
+        write_file(temp.path().join("notes.txt"), "plain\n");
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        let request = DiffRequest {
+            structural_summary: true,
+            ..DiffRequest::default()
+        };
+        let diff = engine.diff_workspace(&repository, &request)?;
+        let lib = diff
+            .files
+            .iter()
+            .find(|file| file.path == "lib.rs")
+            .expect("lib.rs");
+        let structure = lib.structure.as_ref().expect("structure");
+        assert_eq!(structure.functions_changed, 2);
+        assert_eq!(structure.types_changed, 1);
+        assert_eq!(structure.public_signatures_changed, 1);
+        let changes: Vec<_> = structure
+            .changes
+            .iter()
+            .map(|change| (change.change, change.name.as_str()))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (SymbolChangeKind::SignatureChanged, "parse"),
+                (SymbolChangeKind::Renamed, "assist"),
+                (SymbolChangeKind::Added, "Parser"),
+            ]
+        );
+        let notes = diff
+            .files
+            .iter()
+            .find(|file| file.path == "notes.txt")
+            .expect("notes.txt");
+        assert_eq!(notes.structure, None);
+
+        let plain = engine.diff_workspace(&repository, &DiffRequest::default())?;
+        assert!(plain.files.iter().all(|file| file.structure.is_none()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn labels_hunks_and_lines_with_enclosing_symbols() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        let original = "struct Counter {\n    var count = 0\n\n    func describe() -> String {\n        let prefix = \"Count\"\n        let a = 1\n        let b = 2\n        let c = 3\n        return prefix\n    }\n}\n";
+        write_file(temp.path().join("Counter.swift"), original);
+        stage_and_commit(&git_repo, "Initial commit")?;
+        write_file(
+            temp.path().join("Counter.swift"),
+            &original.replace("return prefix\n", "return prefix + \"!\"\n"),
+        );
+        stage_and_commit(&git_repo, "Shout")?;
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        let request = DiffRequest {
+            context_lines: 1,
+            enclosing_symbols: true,
+            ..DiffRequest::default()
+        };
+        let diff = engine.diff(&repository, &request)?;
+        let hunk = &diff.files[0].hunks[0];
+        assert_eq!(hunk.section.as_deref(), Some("func describe() -> String"));
+        let symbols: Vec<_> = hunk
+            .lines
+            .iter()
+            .map(|line| line.enclosing_symbol.as_deref())
+            .collect();
+        assert_eq!(symbols, vec![Some("Counter.describe"); 4]);
+
+        let lines = engine.expand_context(
+            &repository,
+            &diff.range,
+            false,
+            "Counter.swift",
+            DiffSide::Base,
+            1,
+            2,
+            &request,
+        )?;
+        let labeled: Vec<_> = lines
+            .iter()
+            .map(|line| line.enclosing_symbol.as_deref())
+            .collect();
+        assert_eq!(labeled, vec![Some("Counter"); 2]);
+
+        let plain = engine.diff(
+            &repository,
+            &DiffRequest {
+                enclosing_symbols: false,
+                ..request
+            },
+        )?;
+        let hunk = &plain.files[0].hunks[0];
+        assert_eq!(hunk.section.as_deref(), Some("struct Counter {"));
+        assert!(hunk
+            .lines
+            .iter()
+            .all(|line| line.enclosing_symbol.is_none()));
+        Ok(())
+    }
+
+    #[test]
+    fn attaches_syntax_tokens_using_gitattributes_language() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join(".gitattributes"), "*.tmpl diff=rust\n");
+        write_file(
+            temp.path().join("code.tmpl"),
+            "/* opening\n   comment */\nfn old() {}\n",
+        );
+        stage_and_commit(&git_repo, "Initial commit")?;
+        write_file(
+            temp.path().join("code.tmpl"),
+            "/* opening\n   comment */\nfn new() {}\n",
+        );
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        let request = DiffRequest {
+            syntax_highlighting: true,
+            ..DiffRequest::default()
+        };
+        let diff = engine.diff_workspace(&repository, &request)?;
+        let lines = &diff.files[0].hunks[0].lines;
+
+        let comment = lines
+            .iter()
+            .find(|line| line.text == "   comment */")
+            .expect("context line");
+        assert_eq!(comment.tokens[0].kind, SyntaxKind::Comment);
+
+        let deletion = lines
+            .iter()
+            .find(|line| line.kind == DiffLineKind::Deletion)
+            .expect("deletion");
+        assert_eq!(deletion.tokens[0].kind, SyntaxKind::Keyword);
+        assert!(deletion
+            .tokens
+            .iter()
+            .any(|token| token.kind == SyntaxKind::Function && token.start_column == 3));
+
+        let plain = engine.diff_workspace(&repository, &DiffRequest::default())?;
+        assert!(plain.files[0].hunks[0]
+            .lines
+            .iter()
+            .all(|line| line.tokens.is_empty()));
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn tokenizes_workspace_symlinks_by_their_link_text() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let outside = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("lib.rs"), "fn kept() {}\n");
+        std::os::unix::fs::symlink("lib.rs", temp.path().join("link.rs")).expect("symlink");
+        stage_and_commit(&git_repo, "Initial commit")?;
+        let secret = outside.path().join("secret.rs");
+        write_file(secret.clone(), "fn secret() {}\n");
+        std::fs::remove_file(temp.path().join("link.rs")).expect("remove symlink");
+        std::os::unix::fs::symlink(&secret, temp.path().join("link.rs")).expect("symlink");
+
+        let repository = Repository::open(temp.path())?;
+        let request = DiffRequest {
+            syntax_highlighting: true,
+            ..DiffRequest::default()
+        };
+        let diff = DiffEngine::new().diff_workspace(&repository, &request)?;
+        let addition = diff.files[0].hunks[0]
+            .lines
+            .iter()
+            .find(|line| line.kind == DiffLineKind::Addition)
+            .expect("addition");
+        assert_eq!(addition.text, secret.to_string_lossy());
+        assert!(addition
+            .tokens
+            .iter()
+            .all(|token| token.kind != SyntaxKind::Keyword));
+
+        Ok(())
+    }
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec();
+        bytes.extend_from_slice(&width.to_be_bytes());
+        bytes.extend_from_slice(&height.to_be_bytes());
+        bytes.extend_from_slice(&[8, 6, 0, 0, 0, 0]);
+        bytes
+    }
+
+    #[test]
+    fn reports_blob_sizes_and_image_dimensions() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        std::fs::write(temp.path().join("icon.png"), png(16, 16)).expect("write png");
+        stage_and_commit(&git_repo, "Add icon")?;
+        let mut large = png(512, 256);
+        large.resize(4096, 0);
+        std::fs::write(temp.path().join("icon.png"), &large).expect("write png");
+        stage_and_commit(&git_repo, "Regenerate icon")?;
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        let request = DiffRequest::default();
+        let diff = engine.diff(&repository, &request)?;
+        let file = &diff.files[0];
+        assert!(file.is_binary);
+
+        let old_blob = file.old_blob.as_ref().expect("old blob");
+        let new_blob = file.new_blob.as_ref().expect("new blob");
+        assert_eq!(old_blob.size, 30);
+        assert_eq!(new_blob.size, 4096);
+        assert_eq!(
+            old_blob.image.map(|image| (image.width, image.height)),
+            Some((16, 16))
+        );
+        assert_eq!(
+            new_blob
+                .image
+                .map(|image| (image.format, image.width, image.height)),
+            Some((ImageFormat::Png, 512, 256))
+        );
+
+        let blobs =
+            engine.load_file_blobs(&repository, &diff.range, false, "icon.png", &request)?;
+        assert_eq!(blobs.old.as_deref(), Some(png(16, 16).as_slice()));
+        assert_eq!(blobs.new.as_deref(), Some(large.as_slice()));
+
+        std::fs::write(temp.path().join("new.png"), png(2, 3)).expect("write png");
+        let workspace = engine.diff_workspace(&repository, &request)?;
+        let untracked = workspace
+            .files
+            .iter()
+            .find(|file| file.path == "new.png")
+            .expect("untracked file");
+        assert!(untracked.old_blob.is_none());
+        let new_blob = untracked.new_blob.as_ref().expect("new blob");
+        assert_eq!(
+            new_blob.oid,
+            Oid::hash_object(git2::ObjectType::Blob, &png(2, 3))?.to_string()
+        );
+        assert_eq!(new_blob.image.map(|image| image.width), Some(2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn diffs_workspace_since_last_review_iteration() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("lib.rs"), "one\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        assert!(matches!(
+            engine.diff_since_iteration(&repository, None, &DiffRequest::default()),
+            Err(Error::NoIterationsRecorded)
+        ));
+
+        let lines: Vec<String> = (1..=10).map(|n| format!("line {n}")).collect();
+        let reviewed = lines.join("\n") + "\n";
+        write_file(temp.path().join("lib.rs"), &reviewed);
+        write_file(temp.path().join("notes.md"), "draft\n");
+        let first = engine.record_iteration(&repository, None)?;
+        assert_eq!(first.number, 1);
+        assert!(matches!(
+            engine.diff_since_iteration(&repository, Some(7), &DiffRequest::default()),
+            Err(Error::IterationNotFound { number: 7 })
+        ));
+
+        write_file(temp.path().join("lib.rs"), &format!("{reviewed}line 11\n"));
+        let request = DiffRequest::default();
+        let diff = engine.diff_since_iteration(&repository, None, &request)?;
+
+        let base = diff.range.base.as_ref().expect("iteration base");
+        assert_eq!(base.oid, first.tree_oid);
+        assert_eq!(base.kind, RevisionKind::Tree);
+        assert_eq!(base.reference.as_deref(), Some("iteration-1"));
+        assert_eq!(diff.range.head.kind, RevisionKind::Workdir);
+        assert_eq!(diff.files.len(), 1);
+        let file = &diff.files[0];
+        assert_eq!(file.path, "lib.rs");
+        assert_eq!(file.stats.additions, 1);
+        assert_eq!(file.stats.deletions, 0);
+
+        // The range resolves through the per-file APIs like a commit range.
+        let loaded =
+            engine.load_file_hunks(&repository, &diff.range, false, "lib.rs", None, &request)?;
+        assert_eq!(loaded.hunks, file.hunks);
+        let context = engine.expand_context(
+            &repository,
+            &diff.range,
+            false,
+            "lib.rs",
+            DiffSide::Base,
+            1,
+            2,
+            &request,
+        )?;
+        let numbered: Vec<_> = context
+            .iter()
+            .map(|line| (line.text.as_str(), line.base_line, line.head_line))
+            .collect();
+        assert_eq!(
+            numbered,
+            vec![("line 1", Some(1), Some(1)), ("line 2", Some(2), Some(2))]
+        );
+        let head = repository
+            .file_contents(&diff.range, "lib.rs", FileVersion::Head)?
+            .expect("head contents");
+        assert!(head.text.is_some_and(|text| text.ends_with("line 11\n")));
+
+        let head = engine.record_iteration(&repository, Some("HEAD"))?;
+        assert_eq!(head.number, 2);
+        assert_eq!(engine.review_iterations(), vec![first, head]);
+        let since_head =
+            engine.diff_since_iteration(&repository, Some(2), &DiffRequest::default())?;
+        assert_eq!(since_head.files.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn marks_blocks_moved_across_files() -> Result<()> {
+        // Unrelated headers keep the two files from being paired as a rewrite.
+        const A_HEADER: &str = "use std::fmt;\nuse std::io;\nuse std::path::Path;\n";
+        const B_HEADER: &str = "mod config;\nmod server;\nmod routes;\nmod state;\n";
+
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        let greet = "fn greet(name: &str) -> String {\n    format!(\"hello {name}\")\n}\n";
+        let total =
+            "fn total(values: &[u32]) -> u32 {\n    let sum = values.iter().sum();\n    sum\n}\n";
+        write_file(
+            temp.path().join("a.rs"),
+            &format!("{A_HEADER}\n{greet}\n{total}\nfn keep() {{}}\n"),
+        );
+        write_file(temp.path().join("b.rs"), B_HEADER);
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        let edited_total = total.replace("let sum", "let sum: u32");
+        write_file(
+            temp.path().join("a.rs"),
+            &format!("{A_HEADER}\nfn keep() {{}}\n"),
+        );
+        write_file(
+            temp.path().join("b.rs"),
+            &format!("{B_HEADER}\n{edited_total}\nfn extra() {{}}\n\n{greet}"),
+        );
+        stage_and_commit(&git_repo, "Move functions")?;
+
+        let repository = Repository::open(temp.path())?;
+        let request = DiffRequest {
+            detect_moves: true,
+            ..DiffRequest::default()
+        };
+        let diff = DiffEngine::new().diff(&repository, &request)?;
+
+        let line = |path: &str, kind: DiffLineKind, text: &str| {
+            diff.files
+                .iter()
+                .find(|file| file.path == path)
+                .and_then(|file| {
+                    file.hunks
+                        .iter()
+                        .flat_map(|hunk| &hunk.lines)
+                        .find(|line| line.kind == kind && line.text == text)
+                })
+                .cloned()
+                .expect("line present")
+        };
+
+        let greet_from = line(
+            "a.rs",
+            DiffLineKind::Deletion,
+            "fn greet(name: &str) -> String {",
+        )
+        .moved
+        .expect("greet moved away");
+        let greet_to = line(
+            "b.rs",
+            DiffLineKind::Addition,
+            "fn greet(name: &str) -> String {",
+        )
+        .moved
+        .expect("greet moved here");
+        assert_eq!(greet_from.block, greet_to.block);
+        assert_eq!((greet_from.path.as_str(), greet_from.line), ("b.rs", 13));
+        assert_eq!((greet_to.path.as_str(), greet_to.line), ("a.rs", 5));
+        assert!(!greet_from.edited);
+
+        let total_to = line("b.rs", DiffLineKind::Addition, "    sum")
+            .moved
+            .expect("total moved here");
+        assert_ne!(total_to.block, greet_to.block);
+        assert!(total_to.edited);
+        assert_eq!((total_to.path.as_str(), total_to.line), ("a.rs", 9));
+        let edited_line = line(
+            "b.rs",
+            DiffLineKind::Addition,
+            "    let sum: u32 = values.iter().sum();",
+        );
+        assert_eq!(
+            edited_line.moved.map(|moved| moved.block),
+            Some(total_to.block)
+        );
+
+        assert!(line("b.rs", DiffLineKind::Addition, "fn extra() {}")
+            .moved
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn classifies_files_and_skips_generated_hunks() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(
+            temp.path().join(".gitattributes"),
+            "schema.rs linguist-generated\nfixtures/** -diff\n",
+        );
+        write_file(temp.path().join("README.md"), "docs\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        std::fs::create_dir_all(temp.path().join("fixtures")).expect("create dir");
+        std::fs::create_dir_all(temp.path().join("snapshots")).expect("create dir");
+        write_file(temp.path().join("Cargo.lock"), "version = 3\n");
+        write_file(temp.path().join("schema.rs"), "pub struct Table;\n");
+        write_file(temp.path().join("fixtures/data.json"), "{}\n");
+        write_file(temp.path().join("snapshots/view.snap"), "snapshot\n");
+        write_file(temp.path().join("main.rs"), "fn main() {}\n");
+        write_file(temp.path().join("README.md"), "docs\nmore\n");
+        stage_and_commit(&git_repo, "Add files")?;
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        engine.set_classification_rules(vec![ClassificationRule {
+            pattern: "snapshots/*.snap".into(),
+            classification: FileClassification::Test,
+        }]);
+        let request = DiffRequest {
+            skip_generated_hunks: true,
+            ..DiffRequest::default()
+        };
+        let diff = engine.diff(&repository, &request)?;
+
+        let file = |path: &str| {
+            diff.files
+                .iter()
+                .find(|file| file.path == path)
+                .expect("file present")
+        };
+        for (path, classification) in [
+            ("Cargo.lock", FileClassification::Lockfile),
+            ("schema.rs", FileClassification::Generated),
+            ("fixtures/data.json", FileClassification::Generated),
+            ("snapshots/view.snap", FileClassification::Test),
+            ("main.rs", FileClassification::Source),
+            ("README.md", FileClassification::Documentation),
+        ] {
+            assert_eq!(file(path).classification, classification, "{path}");
+        }
+
+        let lockfile = file("Cargo.lock");
+        assert!(lockfile.hunks.is_empty());
+        assert_eq!(lockfile.stats.additions, 1);
+        assert!(!file("main.rs").hunks.is_empty());
+
+        let summary = engine.diff_summary(&repository, &request)?;
+        let summarized = summary
+            .files
+            .iter()
+            .find(|file| file.path == "schema.rs")
+            .expect("summary present");
+        assert_eq!(summarized.classification, FileClassification::Generated);
+
+        Ok(())
+    }
+
+    #[test]
+    fn filters_files_by_include_and_exclude_pathspecs() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("README.md"), "root\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        for dir in ["app/ui", "app/docs", "server"] {
+            std::fs::create_dir_all(temp.path().join(dir)).expect("create dir");
+        }
+        write_file(temp.path().join("app/ui/view.swift"), "struct View {}\n");
+        write_file(temp.path().join("app/ui/model.rs"), "struct Model;\n");
+        write_file(temp.path().join("app/docs/guide.md"), "guide\n");
+        write_file(temp.path().join("server/main.rs"), "fn main() {}\n");
+        write_file(temp.path().join("README.md"), "root\nmore\n");
+        stage_and_commit(&git_repo, "Add app")?;
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        let paths = |diff: &Diff| -> Vec<String> {
+            let mut paths: Vec<_> = diff.files.iter().map(|file| file.path.clone()).collect();
+            paths.sort();
+            paths
+        };
+
+        let request = DiffRequest {
+            include_paths: vec!["app".into()],
+            exclude_paths: vec!["app/docs".into()],
+            ..DiffRequest::default()
+        };
+        assert_eq!(
+            paths(&engine.diff(&repository, &request)?),
+            vec!["app/ui/model.rs", "app/ui/view.swift"]
+        );
+
+        let globbed = DiffRequest {
+            include_paths: vec!["*.rs".into()],
+            exclude_paths: vec!["server/*".into()],
+            ..DiffRequest::default()
+        };
+        assert_eq!(
+            paths(&engine.diff(&repository, &globbed)?),
+            vec!["app/ui/model.rs"]
+        );
+
+        let summary = engine.diff_summary(&repository, &request)?;
+        assert_eq!(summary.files.len(), 2);
+        assert!(matches!(
+            engine.load_file_hunks(
+                &repository,
+                &summary.range,
+                false,
+                "app/docs/guide.md",
+                None,
+                &request,
+            ),
+            Err(Error::FileNotInDiff { .. })
+        ));
+
+        write_file(temp.path().join("server/main.rs"), "fn main() { run(); }\n");
+        write_file(temp.path().join("app/ui/view.swift"), "struct View { }\n");
+        let workspace = engine.diff_workspace(&repository, &request)?;
+        assert_eq!(paths(&workspace), vec!["app/ui/view.swift"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn decodes_utf16_and_legacy_encoded_files() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+        let utf16 = |text: &str| -> Vec<u8> {
+            std::iter::once(0xFEFF)
+                .chain(text.encode_utf16())
+                .flat_map(u16::to_le_bytes)
+                .collect()
+        };
+
+        write_bytes(temp.path().join("utf16.txt"), &utf16("one\ntwo\n"));
+        write_bytes(temp.path().join("latin1.txt"), b"caf\xe9\n");
+        write_file(
+            temp.path().join(".gitattributes"),
+            "wide.txt working-tree-encoding=UTF-16LE\n",
+        );
+        stage_and_commit(&git_repo, "Initial commit")?;
+        write_bytes(temp.path().join("utf16.txt"), &utf16("one\nTWO\n"));
+        write_bytes(temp.path().join("latin1.txt"), b"caf\xe9\ncr\xe8me\n");
+        stage_and_commit(&git_repo, "Update")?;
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        let changed = |file: &DiffFile| -> Vec<(DiffLineKind, String)> {
+            file.hunks
+                .iter()
+                .flat_map(|hunk| &hunk.lines)
+                .filter(|line| line.kind != DiffLineKind::Context)
+                .map(|line| (line.kind, line.text.clone()))
+                .collect()
+        };
+
+        let diff = engine.diff(&repository, &DiffRequest::default())?;
+        let wide = diff
+            .files
+            .iter()
+            .find(|file| file.path == "utf16.txt")
+            .expect("utf16 file");
+        assert!(!wide.is_binary);
+        assert_eq!(wide.encoding, Some(TextEncoding::Utf16LeBom));
+        assert_eq!(wide.stats, DiffStats::new(1, 1));
+        assert_eq!(
+            changed(wide),
+            vec![
+                (DiffLineKind::Deletion, "two".to_owned()),
+                (DiffLineKind::Addition, "TWO".to_owned()),
+            ]
+        );
+        let legacy = diff
+            .files
+            .iter()
+            .find(|file| file.path == "latin1.txt")
+            .expect("latin-1 file");
+        assert_eq!(
+            legacy.encoding,
+            Some(TextEncoding::Legacy {
+                name: "windows-1252".into()
+            })
+        );
+        assert_eq!(
+            changed(legacy),
+            vec![(DiffLineKind::Addition, "cr\u{e8}me".to_owned())]
+        );
+
+        let loaded = engine.load_file_hunks(
+            &repository,
+            &diff.range,
+            false,
+            "utf16.txt",
+            None,
+            &DiffRequest::default(),
+        )?;
+        assert_eq!(loaded.hunks, wide.hunks);
+
+        // Decoded lines share the byte budget with the files before them.
+        let capped = engine.diff(
+            &repository,
+            &DiffRequest {
+                max_total_bytes: Some(10),
+                ..DiffRequest::default()
+            },
+        )?;
+        assert_eq!(capped.files.len(), 2);
+        assert!(capped.files.iter().all(|file| file.truncated));
+
+        write_bytes(
+            temp.path().join("wide.txt"),
+            &"hi\n"
+                .encode_utf16()
+                .flat_map(u16::to_le_bytes)
+                .collect::<Vec<_>>(),
+        );
+        let workspace = engine.diff_workspace(&repository, &DiffRequest::default())?;
+        let untracked = workspace
+            .files
+            .iter()
+            .find(|file| file.path == "wide.txt")
+            .expect("untracked file");
+        assert_eq!(untracked.encoding, Some(TextEncoding::Utf16Le));
+        assert_eq!(
+            changed(untracked),
+            vec![(DiffLineKind::Addition, "hi".to_owned())]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn flags_whitespace_and_line_ending_only_changes() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        let lines: String =
+            ["fn main() {", "    run(a, b);", "}", "", "fn run() {}", ""].join("\n");
+        write_file(temp.path().join("crlf.rs"), &lines);
+        write_file(temp.path().join("indent.rs"), &lines);
+        write_file(temp.path().join("mixed.rs"), &lines);
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        write_file(temp.path().join("crlf.rs"), &lines.replace('\n', "\r\n"));
+        write_file(
+            temp.path().join("indent.rs"),
+            &lines.replace("    run(a, b);", "\trun(a,b);"),
+        );
+        write_file(
+            temp.path().join("mixed.rs"),
+            &lines
+                .replace("    run(a, b);", "  run(a, b);")
+                .replace("fn run() {}", "fn run() { stop(); }"),
+        );
+        stage_and_commit(&git_repo, "Reformat")?;
+
+        let repository = Repository::open(temp.path())?;
+        let diff = DiffEngine::new().diff(&repository, &DiffRequest::default())?;
+        let flags = |path: &str| {
+            let file = diff
+                .files
+                .iter()
+                .find(|file| file.path == path)
+                .expect("file present");
+            (file.whitespace_only, file.line_endings_only)
+        };
+        assert_eq!(flags("crlf.rs"), (true, true));
+        assert_eq!(flags("indent.rs"), (true, false));
+        assert_eq!(flags("mixed.rs"), (false, false));
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncates_long_lines_large_files_and_oversized_diffs() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("README.md"), "root\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+
+        let many = (0..50)
+            .map(|index| format!("line {index}\n"))
+            .collect::<Vec<_>>()
+            .concat();
+        write_file(
+            temp.path().join("a_long.txt"),
+            &format!("{}\n", "é".repeat(30)),
+        );
+        write_file(temp.path().join("b_many.txt"), &many);
+        write_file(temp.path().join("c_last.txt"), "last\n");
+        stage_and_commit(&git_repo, "Add files")?;
+
+        let repository = Repository::open(temp.path())?;
+        let engine = DiffEngine::new();
+        let file = |diff: &Diff, path: &str| -> DiffFile {
+            diff.files
+                .iter()
+                .find(|file| file.path == path)
+                .cloned()
+                .expect("file present")
+        };
+
+        let limited = DiffRequest {
+            max_file_lines: Some(10),
+            max_line_chars: Some(8),
+            ..DiffRequest::default()
+        };
+        let diff = engine.diff(&repository, &limited)?;
+        let long = file(&diff, "a_long.txt");
+        assert!(!long.truncated);
+        assert_eq!(long.hunks[0].lines[0].text, "é".repeat(8));
+        assert!(long.hunks[0].lines[0].truncated);
+        let many_file = file(&diff, "b_many.txt");
+        assert!(many_file.truncated);
+        assert_eq!(many_file.stats.additions, 50);
+        assert_eq!(many_file.hunks[0].lines.len(), 10);
+        assert!(!file(&diff, "c_last.txt").truncated);
+
+        let budget = DiffRequest {
+            max_total_bytes: Some(100),
+            ..DiffRequest::default()
+        };
+        let diff = engine.diff(&repository, &budget)?;
+        assert!(!file(&diff, "a_long.txt").truncated);
+        assert!(file(&diff, "b_many.txt").truncated);
+        let last = file(&diff, "c_last.txt");
+        assert!(last.truncated);
+        assert!(last.hunks.is_empty());
+        assert_eq!(last.stats.additions, 1);
+
+        let diff = engine.diff(&repository, &DiffRequest::default())?;
+        assert!(diff.files.iter().all(|file| !file.truncated));
+        assert_eq!(file(&diff, "b_many.txt").hunks[0].lines.len(), 50);
+
+        Ok(())
+    }
+
+    #[test]
+    fn errors_when_repository_has_no_head() {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path()).expect("init repo");
+
+        let repository = Repository::open(temp.path()).expect("open repo");
+        let result = DiffEngine::new().diff(&repository, &DiffRequest::default());
+
+        drop(git_repo);
+
+        assert!(matches!(result, Err(Error::MissingHeadRevision)));
+    }
+
+    #[test]
+    fn parse_section_extracts_header_text() {
+        let header = b"@@ -10,5 +10,6 @@ fn greeting";
+        assert_eq!(
+            parse_section(header).as_deref(),
+            Some("fn greeting"),
+            "expected trailing context to be parsed"
+        );
+    }
+
+    #[test]
+    fn parse_section_skips_empty_headers() {
+        assert_eq!(parse_section(b"@@ -1,3 +1,4 @@\n"), None);
+    }
+
+    #[test]
+    fn sanitize_line_handles_invalid_utf8() {
+        let result = sanitize_line(&[b'f', b'o', 0xFF, b'o', b'\n']);
+        assert_eq!(result, "fo\u{FFFD}o");
+    }
+
+    fn write_file(path: std::path::PathBuf, contents: &str) {
+        std::fs::write(path, contents).expect("write file");
+    }
+
+    fn write_bytes(path: std::path::PathBuf, contents: &[u8]) {
+        std::fs::write(path, contents).expect("write bytes");
+    }
+
+    fn stage_file(repo: &GitRepository, path: &str) -> Result<()> {
+        let mut index = repo.index()?;
+        index.add_path(std::path::Path::new(path))?;
+        index.write()?;
+        Ok(())
+    }
+
+    fn stage_and_commit(repo: &GitRepository, message: &str) -> Result<()> {
+        let mut index = repo.index()?;
+        index.add_all(["*"], IndexAddOption::DEFAULT, None)?;
+        index.write()?;
+        let tree_id = index.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        let signature = git2::Signature::now("Test", "test@example.com")?;
+
+        let parents = match repo.head() {
+            Ok(head) => head
+                .peel_to_commit()
+                .map_or_else(|_| Vec::new(), |parent| vec![parent]),
+            Err(err)
+                if matches!(
+                    (err.class(), err.code()),
+                    (
+                        git2::ErrorClass::Reference,
+                        git2::ErrorCode::NotFound | git2::ErrorCode::UnbornBranch
+                    )
+                ) =>
+            {
+                Vec::new()
+            }
+            Err(err) => return Err(Error::from(err)),
+        };
+
+        let parent_refs: Vec<&git2::Commit> = parents.iter().collect();
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            message,
+            &tree,
+            &parent_refs,
+        )?;
+        Ok(())
+    }
+}
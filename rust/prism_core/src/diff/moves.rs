@@ -0,0 +1,307 @@
+//! Detection of code blocks moved within a diff.
+//!
+//! Runs of deleted lines are matched against runs of added lines anywhere in
+//! the diff, comparing lines with surrounding whitespace trimmed so that
+//! re-indented code still counts as moved. Matches separated by a few
+//! unmatched lines on either side are joined into one block marked as edited.
+//! Diffs with too many changed lines to compare are left unmarked.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::api::diff::{DiffFile, DiffLineKind, LineMove};
+
+/// Blocks with fewer alphanumeric characters are ignored, so moved braces and
+/// blank lines are not reported. Matches git's threshold for `--color-moved`.
+const MIN_ALNUM_CHARS: usize = 20;
+
+/// Largest number of unmatched lines, on either side, that may separate two
+/// matches for them to be joined into one edited block.
+const MAX_EDIT_GAP: usize = 3;
+
+/// Upper bound on deleted lines times added lines before move detection is
+/// skipped for the whole diff.
+const MAX_LINE_CELLS: usize = 1_000_000;
+
+/// A deleted or added line taking part in move detection.
+struct Entry {
+    location: (usize, usize, usize),
+    run: usize,
+    path: String,
+    number: u32,
+    text: String,
+}
+
+impl Entry {
+    fn key(&self) -> &str {
+        self.text.trim()
+    }
+}
+
+#[derive(Debug)]
+struct Block {
+    deleted: Range<usize>,
+    added: Range<usize>,
+    edited: bool,
+}
+
+/// Mark lines of `files` that belong to moved blocks.
+pub fn annotate(files: &mut [DiffFile]) {
+    let deleted = collect(files, DiffLineKind::Deletion);
+    let added = collect(files, DiffLineKind::Addition);
+    if deleted.is_empty()
+        || added.is_empty()
+        || deleted.len().saturating_mul(added.len()) > MAX_LINE_CELLS
+    {
+        return;
+    }
+
+    let mut blocks = join_edited(match_blocks(&deleted, &added), &deleted, &added);
+    blocks.retain(|block| {
+        let alnum: usize = deleted[block.deleted.clone()]
+            .iter()
+            .map(|entry| entry.text.chars().filter(|ch| ch.is_alphanumeric()).count())
+            .sum();
+        alnum >= MIN_ALNUM_CHARS
+    });
+
+    for (id, block) in blocks.iter().enumerate() {
+        let block_id = u32::try_from(id).unwrap_or(u32::MAX);
+        let edited = block.edited
+            || block.deleted.len() != block.added.len()
+            || block
+                .deleted
+                .clone()
+                .zip(block.added.clone())
+                .any(|(old, new)| deleted[old].text != added[new].text);
+
+        let (to, from) = (&added[block.added.start], &deleted[block.deleted.start]);
+        mark(files, &deleted[block.deleted.clone()], block_id, to, edited);
+        mark(files, &added[block.added.clone()], block_id, from, edited);
+    }
+}
+
+/// Collect changed lines of `kind` in diff order. Consecutive lines of the
+/// same kind within a hunk share a run; blocks never cross runs.
+fn collect(files: &[DiffFile], kind: DiffLineKind) -> Vec<Entry> {
+    let mut entries = Vec::new();
+    let mut run = 0;
+    for (file_index, file) in files.iter().enumerate() {
+        let path = match kind {
+            DiffLineKind::Deletion => file.old_path.as_ref().unwrap_or(&file.path),
+            _ => &file.path,
+        };
+        for (hunk_index, hunk) in file.hunks.iter().enumerate() {
+            let mut in_run = false;
+            for (line_index, line) in hunk.lines.iter().enumerate() {
+                if line.kind != kind {
+                    in_run = false;
+                    continue;
+                }
+                if !in_run {
+                    run += 1;
+                    in_run = true;
+                }
+                let number = match kind {
+                    DiffLineKind::Deletion => line.base_line,
+                    _ => line.head_line,
+                };
+                entries.push(Entry {
+                    location: (file_index, hunk_index, line_index),
+                    run,
+                    path: path.clone(),
+                    number: number.unwrap_or_default(),
+                    text: line.text.clone(),
+                });
+            }
+        }
+    }
+    entries
+}
+
+/// Greedily match each deleted line to the longest unclaimed run of equal
+/// added lines, in deletion order.
+fn match_blocks(deleted: &[Entry], added: &[Entry]) -> Vec<Block> {
+    let mut by_key: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (index, entry) in added.iter().enumerate() {
+        if !entry.key().is_empty() {
+            by_key.entry(entry.key()).or_default().push(index);
+        }
+    }
+
+    let mut claimed = vec![false; added.len()];
+    let mut blocks = Vec::new();
+    let mut start = 0;
+    while start < deleted.len() {
+        let candidates = by_key
+            .get(deleted[start].key())
+            .map_or(&[][..], Vec::as_slice);
+        let best = candidates
+            .iter()
+            .filter(|&&candidate| !claimed[candidate])
+            .map(|&candidate| {
+                (
+                    match_length(deleted, added, &claimed, start, candidate),
+                    candidate,
+                )
+            })
+            .max_by_key(|&(length, candidate)| (length, std::cmp::Reverse(candidate)));
+
+        let Some((length, candidate)) = best else {
+            start += 1;
+            continue;
+        };
+        claimed[candidate..candidate + length].fill(true);
+        blocks.push(Block {
+            deleted: start..start + length,
+            added: candidate..candidate + length,
+            edited: false,
+        });
+        start += length;
+    }
+    blocks
+}
+
+/// Number of lines from `start` and `candidate` onwards that match, staying
+/// within their runs and stopping at added lines already claimed.
+fn match_length(
+    deleted: &[Entry],
+    added: &[Entry],
+    claimed: &[bool],
+    start: usize,
+    candidate: usize,
+) -> usize {
+    let (old_run, new_run) = (deleted[start].run, added[candidate].run);
+    deleted[start..]
+        .iter()
+        .zip(&added[candidate..])
+        .zip(&claimed[candidate..])
+        .take_while(|((old, new), &taken)| {
+            !taken && old.run == old_run && new.run == new_run && old.key() == new.key()
+        })
+        .count()
+}
+
+/// Join matches that follow each other on both sides of the same runs with
+/// only a few unmatched lines in between.
+fn join_edited(blocks: Vec<Block>, deleted: &[Entry], added: &[Entry]) -> Vec<Block> {
+    let mut claimed = vec![false; added.len()];
+    for block in &blocks {
+        claimed[block.added.clone()].fill(true);
+    }
+
+    let mut joined: Vec<Block> = Vec::with_capacity(blocks.len());
+    for block in blocks {
+        if let Some(previous) = joined.last_mut() {
+            let follows = block.deleted.start >= previous.deleted.end
+                && block.added.start >= previous.added.end
+                && block.deleted.start - previous.deleted.end <= MAX_EDIT_GAP
+                && block.added.start - previous.added.end <= MAX_EDIT_GAP
+                && deleted[block.deleted.start].run == deleted[previous.deleted.start].run
+                && added[block.added.start].run == added[previous.added.start].run
+                && !claimed[previous.added.end..block.added.start].contains(&true);
+            if follows {
+                previous.deleted.end = block.deleted.end;
+                previous.added.end = block.added.end;
+                previous.edited = true;
+                continue;
+            }
+        }
+        joined.push(block);
+    }
+    joined
+}
+
+fn mark(files: &mut [DiffFile], entries: &[Entry], block: u32, other: &Entry, edited: bool) {
+    for entry in entries {
+        let (file, hunk, line) = entry.location;
+        files[file].hunks[hunk].lines[line].moved = Some(LineMove {
+            block,
+            path: other.path.clone(),
+            line: other.number,
+            edited,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::diff::DiffLine;
+    use crate::diff::fixtures::{file, line};
+
+    /// Lines numbered by their position, on the side each belongs to.
+    fn numbered(lines: &[(DiffLineKind, &str)]) -> Vec<DiffLine> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, &(kind, text))| {
+                let number = u32::try_from(index + 1).ok();
+                let mut line = line(kind, text);
+                if kind == DiffLineKind::Deletion {
+                    line.base_line = number;
+                } else {
+                    line.head_line = number;
+                }
+                line
+            })
+            .collect()
+    }
+
+    #[test]
+    fn ignores_blocks_with_little_content() {
+        let mut files = [
+            file(
+                "a.rs",
+                numbered(&[
+                    (DiffLineKind::Deletion, "}"),
+                    (DiffLineKind::Deletion, "x += 1;"),
+                ]),
+            ),
+            file(
+                "b.rs",
+                numbered(&[
+                    (DiffLineKind::Addition, "}"),
+                    (DiffLineKind::Addition, "x += 1;"),
+                ]),
+            ),
+        ];
+        annotate(&mut files);
+        assert!(files
+            .iter()
+            .flat_map(|file| &file.hunks[0].lines)
+            .all(|line| line.moved.is_none()));
+    }
+
+    #[test]
+    fn moves_within_one_file_keep_their_own_path() {
+        let text = "let configuration = load_configuration();";
+        let mut files = [file(
+            "a.rs",
+            numbered(&[
+                (DiffLineKind::Deletion, text),
+                (DiffLineKind::Context, ""),
+                (DiffLineKind::Addition, text),
+            ]),
+        )];
+        annotate(&mut files);
+
+        let lines = &files[0].hunks[0].lines;
+        assert_eq!(lines[0].moved.as_ref().map(|moved| moved.line), Some(3));
+        assert_eq!(lines[2].moved.as_ref().map(|moved| moved.line), Some(1));
+        assert!(lines[1].moved.is_none());
+    }
+
+    #[test]
+    fn skips_diffs_with_too_many_changed_lines() {
+        let text = "let configuration = load_configuration();";
+        let deletions = numbered(&vec![(DiffLineKind::Deletion, text); 1001]);
+        let additions = numbered(&vec![(DiffLineKind::Addition, text); 1001]);
+        let mut files = [file("a.rs", deletions), file("b.rs", additions)];
+        annotate(&mut files);
+        assert!(files
+            .iter()
+            .flat_map(|file| &file.hunks[0].lines)
+            .all(|line| line.moved.is_none()));
+    }
+}
@@ -0,0 +1,455 @@
+//! Parsing of patch files: `git diff` and `diff -u` output, and the mbox
+//! messages written by `git format-patch`.
+//!
+//! libgit2 only parses git-style patches, so file headers of traditional
+//! unified diffs are rewritten into `diff --git` headers first. The headers
+//! of an mbox message (`From <oid>`, `From:`, `Date:`, and `Subject:`) become
+//! the head [`Revision`] of the parsed diff; plain diffs carry no such
+//! metadata.
+//!
+//! Patches abbreviate blob ids on their `index` lines; ids whose objects
+//! exist in the repository are expanded to full ids.
+
+use std::borrow::Cow;
+use std::io::Write as _;
+
+use git2::Oid;
+
+use crate::api::{
+    diff::{BlobInfo, DiffFile},
+    repository::{Revision, RevisionKind, Signature},
+};
+
+/// Rewrite the file headers of traditional unified diffs into the
+/// `diff --git` form libgit2 parses. Paths lose their leading directory, as
+/// with `git apply -p1`, unless they have none. Git patches are returned
+/// unchanged.
+#[must_use]
+pub fn normalize(patch: &[u8]) -> Cow<'_, [u8]> {
+    let lines: Vec<&[u8]> = patch.split_inclusive(|&byte| byte == b'\n').collect();
+    let mut out = Vec::with_capacity(patch.len());
+    let mut in_git_header = false;
+    let mut rewritten = false;
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        index += 1;
+        if line.starts_with(b"diff --git ") {
+            in_git_header = true;
+        } else if let Some((base_lines, head_lines)) = hunk_counts(line) {
+            in_git_header = false;
+            out.extend_from_slice(line);
+            // Copy the hunk body as is, so removed lines that start with
+            // "-- " are not mistaken for file headers.
+            let (mut base_lines, mut head_lines) = (base_lines, head_lines);
+            while (base_lines > 0 || head_lines > 0) && index < lines.len() {
+                let body = lines[index];
+                match body.first() {
+                    Some(b'-') => base_lines = base_lines.saturating_sub(1),
+                    Some(b'+') => head_lines = head_lines.saturating_sub(1),
+                    Some(b'\\') => {}
+                    _ => {
+                        base_lines = base_lines.saturating_sub(1);
+                        head_lines = head_lines.saturating_sub(1);
+                    }
+                }
+                out.extend_from_slice(body);
+                index += 1;
+            }
+            continue;
+        } else if !in_git_header {
+            let next = lines.get(index).copied().unwrap_or_default();
+            if let (Some(old), Some(new)) = (header_path(line, b"--- "), header_path(next, b"+++ "))
+            {
+                write_git_header(&mut out, &old, &new);
+                index += 1;
+                rewritten = true;
+                continue;
+            }
+        }
+        out.extend_from_slice(line);
+    }
+    if rewritten {
+        Cow::Owned(out)
+    } else {
+        Cow::Borrowed(patch)
+    }
+}
+
+/// Parse the line counts of a `@@ -a,b +c,d @@` hunk header.
+fn hunk_counts(line: &[u8]) -> Option<(u32, u32)> {
+    let line = std::str::from_utf8(line.strip_prefix(b"@@ -")?).ok()?;
+    let (ranges, _) = line.split_once(" @@")?;
+    let (base, head) = ranges.split_once(" +")?;
+    let count = |range: &str| {
+        range
+            .split_once(',')
+            .map_or(Some(1), |(_, count)| count.parse().ok())
+    };
+    Some((count(base)?, count(head)?))
+}
+
+/// Path git prints for a side that does not exist.
+const DEV_NULL: &str = "/dev/null";
+
+/// Read the path from a `--- ` or `+++ ` line, dropping the timestamp
+/// `diff -u` appends after a tab. `/dev/null` is kept as is.
+fn header_path(line: &[u8], prefix: &[u8]) -> Option<String> {
+    let line = String::from_utf8_lossy(line.strip_prefix(prefix)?);
+    let path = line.trim_end_matches(['\n', '\r']);
+    let path = path.split_once('\t').map_or(path, |(path, _)| path).trim();
+    if path.is_empty() {
+        return None;
+    }
+    if path == DEV_NULL {
+        return Some(path.to_owned());
+    }
+    let path = path.split_once('/').map_or(path, |(_, rest)| rest);
+    Some(path.to_owned())
+}
+
+fn write_git_header(out: &mut Vec<u8>, old: &str, new: &str) {
+    let path = if new == DEV_NULL { old } else { new };
+    let old_path = if old == DEV_NULL { path } else { old };
+    let _ = writeln!(out, "diff --git a/{old_path} b/{path}");
+    if old == DEV_NULL {
+        let _ = writeln!(out, "new file mode 100644");
+    } else if new == DEV_NULL {
+        let _ = writeln!(out, "deleted file mode 100644");
+    }
+    let side = |prefix: &str, path: &str| {
+        if path == DEV_NULL {
+            DEV_NULL.to_owned()
+        } else {
+            format!("{prefix}/{path}")
+        }
+    };
+    let _ = writeln!(out, "--- {}\n+++ {}", side("a", old), side("b", new));
+}
+
+/// Describe the commit a patch was generated from, using the headers of its
+/// first mbox message when it has any. Without headers the revision carries
+/// a zero oid and no metadata.
+#[must_use]
+pub fn revision(patch: &[u8]) -> Revision {
+    let mut revision = Revision {
+        oid: Oid::zero().to_string(),
+        reference: None,
+        summary: None,
+        author: None,
+        committer: None,
+        timestamp: None,
+        kind: RevisionKind::Commit,
+    };
+    let text = String::from_utf8_lossy(patch);
+    let mut lines = text.lines().peekable();
+    if let Some(separator) = lines.peek().and_then(|line| line.strip_prefix("From ")) {
+        let oid = separator.split_whitespace().next().unwrap_or_default();
+        if let Ok(oid) = Oid::from_str(oid) {
+            revision.oid = oid.to_string();
+        }
+        lines.next();
+    }
+
+    for (name, value) in headers(lines) {
+        match name.to_ascii_lowercase().as_str() {
+            "subject" => revision.summary = Some(strip_subject_tags(&decode_words(&value))),
+            "from" => revision.author = Some(parse_address(&decode_words(&value))),
+            "date" => revision.timestamp = parse_date(&value),
+            _ => {}
+        }
+    }
+    revision
+}
+
+/// Expand the abbreviated blob ids of `file` to full ids, and fill in sizes,
+/// for objects the repository has.
+pub fn resolve_blobs(repo: &git2::Repository, file: &mut DiffFile) {
+    let Ok(odb) = repo.odb() else {
+        return;
+    };
+    for blob in [file.old_blob.as_mut(), file.new_blob.as_mut()]
+        .into_iter()
+        .flatten()
+    {
+        resolve_blob(&odb, blob);
+    }
+}
+
+/// libgit2 pads abbreviated ids with zeros. Trimming them leaves a prefix of
+/// the real id, if a shorter one when the abbreviation itself ended in zero.
+fn resolve_blob(odb: &git2::Odb<'_>, blob: &mut BlobInfo) {
+    let prefix = blob.oid.trim_end_matches('0');
+    if prefix.len() < 4 {
+        return;
+    }
+    let resolved = Oid::from_str(prefix)
+        .and_then(|short| odb.exists_prefix(short, prefix.len()))
+        .ok();
+    match resolved {
+        Some(oid) => {
+            blob.oid = oid.to_string();
+            if let Ok((size, _)) = odb.read_header(oid) {
+                blob.size = size as u64;
+            }
+        }
+        None => blob.oid = prefix.to_owned(),
+    }
+}
+
+/// Collect the header block at the start of a message, unfolding
+/// continuation lines. Stops at the first blank line or at the first line
+/// that is not a header, so a bare diff yields nothing.
+fn headers<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<(String, String)> {
+    let mut headers: Vec<(String, String)> = Vec::new();
+    for line in lines {
+        if line.is_empty() {
+            break;
+        }
+        if line.starts_with([' ', '\t']) {
+            let Some((_, value)) = headers.last_mut() else {
+                break;
+            };
+            value.push(' ');
+            value.push_str(line.trim());
+            continue;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            break;
+        };
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            break;
+        }
+        headers.push((name.to_owned(), value.trim().to_owned()));
+    }
+    headers
+}
+
+/// Drop the `[PATCH v2 1/3]` style tags `git format-patch` puts in front of
+/// the commit summary.
+fn strip_subject_tags(subject: &str) -> String {
+    let mut subject = subject.trim();
+    while let Some(rest) = subject.strip_prefix('[') {
+        let Some((_, rest)) = rest.split_once(']') else {
+            break;
+        };
+        subject = rest.trim_start();
+    }
+    subject.to_owned()
+}
+
+/// Split a `Name <email>` address. A bare address is used as the name too.
+fn parse_address(value: &str) -> Signature {
+    match value.rsplit_once('<') {
+        Some((name, email)) => {
+            let email = email.trim_end_matches('>').trim().to_owned();
+            let name = name.trim().trim_matches('"').trim();
+            Signature {
+                name: if name.is_empty() {
+                    email.clone()
+                } else {
+                    name.to_owned()
+                },
+                email: Some(email),
+            }
+        }
+        None => Signature {
+            name: value.trim().to_owned(),
+            email: value.contains('@').then(|| value.trim().to_owned()),
+        },
+    }
+}
+
+/// Decode RFC 2047 encoded words such as `=?UTF-8?q?Jos=C3=A9?=`, which
+/// `git format-patch` uses for non-ASCII names and subjects. Whitespace
+/// between adjacent encoded words is dropped, as the RFC requires.
+fn decode_words(value: &str) -> String {
+    let mut out = String::new();
+    let mut rest = value;
+    let mut after_word = false;
+    while let Some(start) = rest.find("=?") {
+        let decoded = (|| {
+            let mut parts = rest[start + 2..].splitn(3, '?');
+            let (charset, kind) = (parts.next()?, parts.next()?);
+            let (data, _) = parts.next()?.split_once("?=")?;
+            let bytes = match kind {
+                "q" | "Q" => decode_q(data),
+                "b" | "B" => decode_base64(data)?,
+                _ => return None,
+            };
+            let encoding = encoding_rs::Encoding::for_label(charset.as_bytes())?;
+            Some((
+                encoding.decode_without_bom_handling(&bytes).0.into_owned(),
+                charset.len() + kind.len() + data.len() + 2,
+            ))
+        })();
+        let Some((text, word_len)) = decoded else {
+            out.push_str(&rest[..start + 2]);
+            rest = &rest[start + 2..];
+            after_word = false;
+            continue;
+        };
+        let between = &rest[..start];
+        if !(after_word && between.trim().is_empty()) {
+            out.push_str(between);
+        }
+        out.push_str(&text);
+        rest = &rest[start + 2 + word_len + 2..];
+        after_word = true;
+    }
+    out.push_str(rest);
+    out
+}
+
+fn decode_q(data: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(data.len());
+    let mut input = data.bytes();
+    while let Some(byte) = input.next() {
+        match byte {
+            b'_' => bytes.push(b' '),
+            b'=' => {
+                let hex = [input.next(), input.next()];
+                let value = hex
+                    .iter()
+                    .map(|digit| digit.and_then(|digit| char::from(digit).to_digit(16)))
+                    .try_fold(0, |value, digit| Some(value * 16 + digit?));
+                bytes.push(
+                    value
+                        .and_then(|value| u8::try_from(value).ok())
+                        .unwrap_or(b'?'),
+                );
+            }
+            _ => bytes.push(byte),
+        }
+    }
+    bytes
+}
+
+fn decode_base64(data: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(data.len() / 4 * 3);
+    let (mut buffer, mut bits) = (0_u32, 0_u32);
+    for byte in data.bytes().filter(|&byte| byte != b'=') {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push(((buffer >> bits) & 0xFF) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+/// Parse an RFC 2822 date such as `Tue, 4 Jun 2024 10:20:30 +0200` into a
+/// Unix timestamp.
+fn parse_date(value: &str) -> Option<i64> {
+    let value = value.split_once(',').map_or(value, |(_, date)| date);
+    let mut fields = value.split_whitespace();
+    let day: i64 = fields.next()?.parse().ok()?;
+    let month = fields.next()?.get(..3)?.to_ascii_lowercase();
+    let month = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ]
+    .iter()
+    .position(|name| *name == month)?;
+    let year: i64 = fields.next()?.parse().ok()?;
+    let mut time = fields.next()?.split(':').map(str::parse::<i64>);
+    let (hour, minute) = (time.next()?.ok()?, time.next()?.ok()?);
+    let second = time.next().transpose().ok()?.unwrap_or(0);
+    let zone = fields.next().unwrap_or("+0000");
+    let (sign, zone) = match zone.split_at(1) {
+        ("-", zone) => (-1, zone),
+        ("+", zone) => (1, zone),
+        _ => (1, "0000"),
+    };
+    let zone: i64 = zone.parse().ok()?;
+    let offset = sign * (zone / 100 * 3600 + zone % 100 * 60);
+
+    let days = days_from_civil(year, i64::try_from(month).ok()? + 1, day);
+    Some(days * 86_400 + hour * 3600 + minute * 60 + second - offset)
+}
+
+/// Days between 1970-01-01 and the given proleptic Gregorian date.
+const fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_format_patch_headers() {
+        let patch = b"From 0123456789abcdef0123456789abcdef01234567 Mon Sep 17 00:00:00 2001\n\
+            From: =?UTF-8?q?Jos=C3=A9?= Doe <jose@example.com>\n\
+            Date: Tue, 4 Jun 2024 10:20:30 +0200\n\
+            Subject: [PATCH v2 1/3] Add the\n \
+             widget\n\
+            \n\
+            Body text.\n\
+            ---\n";
+        let revision = revision(patch);
+        assert_eq!(revision.oid, "0123456789abcdef0123456789abcdef01234567");
+        assert_eq!(revision.summary.as_deref(), Some("Add the widget"));
+        assert_eq!(
+            revision.author,
+            Some(Signature {
+                name: "Jos\u{e9} Doe".into(),
+                email: Some("jose@example.com".into()),
+            })
+        );
+        assert_eq!(revision.timestamp, Some(1_717_489_230));
+    }
+
+    #[test]
+    fn plain_diffs_carry_no_metadata() {
+        let revision = revision(b"diff --git a/a.txt b/a.txt\n--- a/a.txt\n+++ b/a.txt\n");
+        assert_eq!(revision.oid, Oid::zero().to_string());
+        assert_eq!(revision.summary, None);
+        assert_eq!(revision.author, None);
+    }
+
+    #[test]
+    fn rewrites_traditional_file_headers() {
+        let patch = b"diff -u old/a.txt new/a.txt\n\
+            --- old/a.txt\t2024-06-04 10:20:30.000000000 +0200\n\
+            +++ new/a.txt\t2024-06-04 10:21:00.000000000 +0200\n\
+            @@ -1,2 +1,2 @@\n one\n--- two\n+three\n\
+            --- /dev/null\n\
+            +++ b.txt\n\
+            @@ -0,0 +1 @@\n+new\n";
+        assert_eq!(
+            String::from_utf8_lossy(&normalize(patch)),
+            "diff -u old/a.txt new/a.txt\n\
+             diff --git a/a.txt b/a.txt\n--- a/a.txt\n+++ b/a.txt\n\
+             @@ -1,2 +1,2 @@\n one\n--- two\n+three\n\
+             diff --git a/b.txt b/b.txt\nnew file mode 100644\n--- /dev/null\n+++ b/b.txt\n\
+             @@ -0,0 +1 @@\n+new\n"
+        );
+
+        let git = b"diff --git a/a.txt b/a.txt\n--- a/a.txt\n+++ b/a.txt\n";
+        assert!(matches!(normalize(git), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn decodes_encoded_words() {
+        assert_eq!(
+            decode_words("=?utf-8?b?SsO8cmdlbg==?= =?utf-8?q?=C3=BC_M?="),
+            "J\u{fc}rgen\u{fc} M"
+        );
+        assert_eq!(decode_words("plain =?bogus"), "plain =?bogus");
+    }
+}
@@ -0,0 +1,367 @@
+//! Rendering of diffs as unified patches that `git apply` accepts.
+//!
+//! Headers follow `git diff` output: rename and copy headers, mode lines,
+//! `index` lines, and binary markers. Line text is written back with the
+//! terminator and in the encoding it was read with, so CRLF files, legacy
+//! encodings, and missing newlines at end of file survive the round trip.
+//! UTF-16 text cannot be embedded in a patch and is marked binary, as git
+//! does.
+
+use std::io::Write as _;
+
+use crate::{
+    api::diff::{
+        BlobInfo, Diff, DiffFile, DiffHunk, DiffLineKind, FileMode, FileStatus, LineEnding,
+        PatchSelection, TextEncoding,
+    },
+    encoding, Error, Result,
+};
+
+/// Object id git prints for a side that does not exist.
+const NULL_OID: &str = "0000000000000000000000000000000000000000";
+
+/// Render `diff` as a unified patch. With a `selection`, only the selected
+/// files and hunks are rendered, in diff order.
+///
+/// # Errors
+///
+/// Returns [`Error::FileNotInDiff`] or [`Error::HunkNotInDiff`] when the
+/// selection names a file or hunk the diff does not have, and
+/// [`Error::IncompletePatch`] when a rendered file was truncated or had its
+/// hunks dropped.
+pub fn render_patch(diff: &Diff, selection: Option<&[PatchSelection]>) -> Result<Vec<u8>> {
+    let files = &diff.files;
+    let mut selected = Vec::new();
+    match selection {
+        None => selected.extend(files.iter().map(|file| (file, None))),
+        Some(selection) => {
+            if let Some(missing) = selection
+                .iter()
+                .find(|entry| !files.iter().any(|file| file.path == entry.path))
+            {
+                return Err(Error::FileNotInDiff {
+                    path: missing.path.clone(),
+                });
+            }
+            for file in files {
+                if let Some(entry) = selection.iter().find(|entry| entry.path == file.path) {
+                    selected.push((file, entry.hunks.as_deref()));
+                }
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    for (file, hunks) in selected {
+        render_file(&mut out, file, hunks)?;
+    }
+    Ok(out)
+}
+
+fn render_file(out: &mut Vec<u8>, file: &DiffFile, selection: Option<&[u32]>) -> Result<()> {
+    let is_incomplete = file.truncated
+        || file
+            .hunks
+            .iter()
+            .flat_map(|hunk| &hunk.lines)
+            .any(|line| line.truncated)
+        || (file.hunks.is_empty()
+            && !file.is_binary
+            && file.submodule.is_none()
+            && file.stats.additions + file.stats.deletions > 0);
+    if is_incomplete {
+        return Err(Error::IncompletePatch {
+            path: file.path.clone(),
+        });
+    }
+
+    if let Some(&index) = selection
+        .unwrap_or_default()
+        .iter()
+        .find(|&&index| usize::try_from(index).map_or(true, |index| index >= file.hunks.len()))
+    {
+        return Err(Error::HunkNotInDiff {
+            path: file.path.clone(),
+            index,
+        });
+    }
+    let is_selected = |index: usize| {
+        selection.map_or(true, |indices| {
+            indices
+                .iter()
+                .any(|&selected| usize::try_from(selected) == Ok(index))
+        })
+    };
+
+    let old_path = file.old_path.as_deref().unwrap_or(&file.path);
+    let (old_name, new_name) = match file.status {
+        FileStatus::Added => ("/dev/null".to_owned(), format!("b/{}", file.path)),
+        FileStatus::Deleted => (format!("a/{old_path}"), "/dev/null".to_owned()),
+        _ => (format!("a/{old_path}"), format!("b/{}", file.path)),
+    };
+
+    let (old_oid, new_oid) = write_header(out, file, old_path);
+
+    let is_utf16 = file.encoding.as_ref().is_some_and(encoding::is_utf16);
+    if file.is_binary || is_utf16 {
+        if old_oid != new_oid {
+            let _ = writeln!(out, "Binary files {old_name} and {new_name} differ");
+        }
+        return Ok(());
+    }
+
+    if let Some(submodule) = &file.submodule {
+        let _ = writeln!(out, "--- {old_name}\n+++ {new_name}");
+        let (base, head) = match (&submodule.old_oid, &submodule.new_oid) {
+            (None, _) => ("0,0", "1"),
+            (_, None) => ("1", "0,0"),
+            _ => ("1", "1"),
+        };
+        let _ = writeln!(out, "@@ -{base} +{head} @@");
+        if let Some(oid) = &submodule.old_oid {
+            let _ = writeln!(out, "-Subproject commit {oid}");
+        }
+        if let Some(oid) = &submodule.new_oid {
+            let _ = writeln!(out, "+Subproject commit {oid}");
+        }
+        return Ok(());
+    }
+
+    if !(0..file.hunks.len()).any(is_selected) {
+        return Ok(());
+    }
+    let _ = writeln!(out, "--- {old_name}\n+++ {new_name}");
+    let text_encoding = file.encoding.as_ref().unwrap_or(&TextEncoding::Utf8);
+    // Leaving a hunk out shifts the head side of every later hunk.
+    let mut shift = 0_i64;
+    for (index, hunk) in file.hunks.iter().enumerate() {
+        if is_selected(index) {
+            render_hunk(out, hunk, shift, text_encoding);
+        } else {
+            shift += i64::from(hunk.header.head_lines) - i64::from(hunk.header.base_lines);
+        }
+    }
+    Ok(())
+}
+
+/// Write the `diff --git` line and the extended headers that follow it, and
+/// return the object ids of both sides.
+fn write_header<'a>(out: &mut Vec<u8>, file: &'a DiffFile, old_path: &str) -> (&'a str, &'a str) {
+    let _ = writeln!(out, "diff --git a/{old_path} b/{}", file.path);
+    let old_mode = file.old_mode.map(mode_bits);
+    let new_mode = file.new_mode.map(mode_bits);
+    match (file.status, old_mode, new_mode) {
+        (FileStatus::Added, _, Some(mode)) => {
+            let _ = writeln!(out, "new file mode {mode}");
+        }
+        (FileStatus::Deleted, Some(mode), _) => {
+            let _ = writeln!(out, "deleted file mode {mode}");
+        }
+        (_, Some(old), Some(new)) if old != new => {
+            let _ = writeln!(out, "old mode {old}\nnew mode {new}");
+        }
+        _ => {}
+    }
+    if old_path != file.path {
+        let verb = if file.status == FileStatus::Copied {
+            "copy"
+        } else {
+            "rename"
+        };
+        let _ = writeln!(
+            out,
+            "similarity index {}%\n{verb} from {old_path}\n{verb} to {}",
+            similarity(file),
+            file.path
+        );
+    }
+
+    let (old_oid, new_oid) = file.submodule.as_ref().map_or_else(
+        || {
+            (
+                file.old_blob.as_ref().map(|blob| blob.oid.as_str()),
+                file.new_blob.as_ref().map(|blob| blob.oid.as_str()),
+            )
+        },
+        |submodule| (submodule.old_oid.as_deref(), submodule.new_oid.as_deref()),
+    );
+    let (old_oid, new_oid) = (old_oid.unwrap_or(NULL_OID), new_oid.unwrap_or(NULL_OID));
+    if old_oid != new_oid {
+        let _ = write!(out, "index {old_oid}..{new_oid}");
+        match (old_mode, new_mode) {
+            (Some(old), Some(new)) if old == new => {
+                let _ = writeln!(out, " {new}");
+            }
+            _ => out.push(b'\n'),
+        }
+    }
+    (old_oid, new_oid)
+}
+
+fn render_hunk(out: &mut Vec<u8>, hunk: &DiffHunk, shift: i64, text_encoding: &TextEncoding) {
+    let header = hunk.header;
+    let head_start = i64::from(header.head_start) - shift;
+    let _ = write!(
+        out,
+        "@@ -{} +{} @@",
+        range(i64::from(header.base_start), header.base_lines),
+        range(head_start, header.head_lines)
+    );
+    match &hunk.section {
+        Some(section) => {
+            let _ = writeln!(out, " {section}");
+        }
+        None => out.push(b'\n'),
+    }
+
+    for line in &hunk.lines {
+        out.push(match line.kind {
+            DiffLineKind::Context => b' ',
+            DiffLineKind::Addition => b'+',
+            DiffLineKind::Deletion => b'-',
+        });
+        let first_line = match line.kind {
+            DiffLineKind::Deletion => line.base_line == Some(1),
+            _ => line.head_line == Some(1),
+        };
+        if first_line && *text_encoding == TextEncoding::Utf8Bom {
+            out.extend_from_slice(b"\xEF\xBB\xBF");
+        }
+        let legacy = match text_encoding {
+            TextEncoding::Legacy { .. } => encoding::encode(&line.text, text_encoding),
+            _ => None,
+        };
+        out.extend_from_slice(legacy.as_deref().unwrap_or(line.text.as_bytes()));
+        out.extend_from_slice(match line.line_ending {
+            LineEnding::Lf => b"\n",
+            LineEnding::CrLf => b"\r\n",
+            LineEnding::Missing => b"\n\\ No newline at end of file\n",
+        });
+    }
+}
+
+/// Estimate the similarity index git prints for a rename or copy: the share
+/// of the larger side that the hunks leave in place. `git apply` does not
+/// check the figure, but it requires the header.
+fn similarity(file: &DiffFile) -> u64 {
+    let size = |blob: Option<&BlobInfo>| blob.map_or(0, |blob| blob.size);
+    let (old_size, new_size) = (size(file.old_blob.as_ref()), size(file.new_blob.as_ref()));
+    let removed: u64 = file
+        .hunks
+        .iter()
+        .flat_map(|hunk| &hunk.lines)
+        .filter(|line| line.kind == DiffLineKind::Deletion)
+        .map(|line| line.text.len() as u64 + 1)
+        .sum();
+    let total = old_size.max(new_size);
+    if total == 0 {
+        return 100;
+    }
+    old_size.saturating_sub(removed).min(total) * 100 / total
+}
+
+/// Format one side of a hunk header the way git does, leaving out a count
+/// of one.
+fn range(start: i64, count: u32) -> String {
+    if count == 1 {
+        start.to_string()
+    } else {
+        format!("{start},{count}")
+    }
+}
+
+const fn mode_bits(mode: FileMode) -> &'static str {
+    match mode {
+        FileMode::Regular => "100644",
+        FileMode::Executable => "100755",
+        FileMode::Symlink => "120000",
+        FileMode::Gitlink => "160000",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::diff::{DiffLine, DiffRange, LineEnding};
+    use crate::diff::fixtures::{hunk, line};
+
+    fn diff() -> Diff {
+        let numbered = |kind, text, base_line, head_line| DiffLine {
+            base_line,
+            head_line,
+            ..line(kind, text)
+        };
+        let intro = hunk(vec![
+            numbered(DiffLineKind::Context, "intro", Some(1), Some(1)),
+            numbered(DiffLineKind::Addition, "one", None, Some(2)),
+            numbered(DiffLineKind::Addition, "two", None, Some(3)),
+        ]);
+        let mut notes = hunk(vec![DiffLine {
+            line_ending: LineEnding::Missing,
+            ..numbered(DiffLineKind::Addition, "end", None, Some(13))
+        }]);
+        notes.header = DiffRange {
+            base_start: 10,
+            base_lines: 0,
+            head_start: 13,
+            head_lines: 1,
+        };
+        notes.section = Some("Notes".into());
+
+        let mut diff: Diff = serde_json::from_value(serde_json::json!({
+            "range": {"head": {"oid": "1111111111111111111111111111111111111111"}},
+            "files": [{
+                "path": "notes.txt",
+                "status": "modified",
+                "old_mode": "regular",
+                "new_mode": "regular",
+                "old_blob": {"oid": "aaaaaaa", "size": 40},
+                "new_blob": {"oid": "bbbbbbb", "size": 44},
+                "stats": {"additions": 3, "deletions": 0},
+            }],
+        }))
+        .expect("diff");
+        diff.files[0].hunks = vec![intro, notes];
+        diff
+    }
+
+    #[test]
+    fn renders_selected_hunks_with_shifted_ranges() {
+        let selection = [PatchSelection {
+            path: "notes.txt".into(),
+            hunks: Some(vec![1]),
+        }];
+        let patch = render_patch(&diff(), Some(&selection)).expect("patch");
+        assert_eq!(
+            String::from_utf8(patch).expect("utf-8"),
+            "diff --git a/notes.txt b/notes.txt\n\
+             index aaaaaaa..bbbbbbb 100644\n\
+             --- a/notes.txt\n\
+             +++ b/notes.txt\n\
+             @@ -10,0 +11 @@ Notes\n\
+             +end\n\
+             \\ No newline at end of file\n"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_files_and_hunks() {
+        let diff = diff();
+        let missing = [PatchSelection {
+            path: "other.txt".into(),
+            hunks: None,
+        }];
+        assert!(matches!(
+            render_patch(&diff, Some(&missing)),
+            Err(Error::FileNotInDiff { .. })
+        ));
+        let out_of_range = [PatchSelection {
+            path: "notes.txt".into(),
+            hunks: Some(vec![2]),
+        }];
+        assert!(matches!(
+            render_patch(&diff, Some(&out_of_range)),
+            Err(Error::HunkNotInDiff { index: 2, .. })
+        ));
+    }
+}
@@ -0,0 +1,288 @@
+//! `git range-diff` style pairing of commits across two versions of a branch.
+//!
+//! Each commit is rendered as a patch text: its message followed by its hunks
+//! with line numbers stripped, so rebasing onto a moved base does not make an
+//! otherwise identical commit look modified. Commits are then paired by the
+//! size of the diff between their patch texts.
+
+use std::fmt::Write as _;
+
+use git2::DiffOptions;
+
+use crate::{
+    api::diff::{CommitPair, CommitPairStatus, Diff, DiffHunk, DiffLineKind},
+    api::repository::Revision,
+    Result,
+};
+
+/// Percentage of the combined patch sizes that pairing two commits may cost
+/// before they are reported as dropped and added instead. Matches the default
+/// `--creation-factor` of `git range-diff`.
+const CREATION_FACTOR: usize = 60;
+
+/// A commit rendered for comparison with commits from the other range.
+#[derive(Debug)]
+pub struct CommitPatch {
+    revision: Revision,
+    text: String,
+}
+
+impl CommitPatch {
+    /// Render the commit diffed in `diff` together with its full `message`.
+    pub fn new(diff: &Diff, message: &str) -> Self {
+        let mut text = String::new();
+        for line in message.lines() {
+            let _ = writeln!(text, "    {line}");
+        }
+
+        for file in &diff.files {
+            let _ = write!(text, "\n## ");
+            if let Some(old_path) = file.old_path.as_ref().filter(|old| **old != file.path) {
+                let _ = write!(text, "{old_path} -> ");
+            }
+            let _ = writeln!(text, "{} ({:?})", file.path, file.status);
+            if file.is_binary {
+                let oid = file.new_blob.as_ref().map_or("", |blob| blob.oid.as_str());
+                let _ = writeln!(text, "Binary file {oid}");
+            }
+            for hunk in &file.hunks {
+                let _ = writeln!(text, "@@ {}", hunk.section.as_deref().unwrap_or_default());
+                for line in &hunk.lines {
+                    let prefix = match line.kind {
+                        DiffLineKind::Addition => '+',
+                        DiffLineKind::Deletion => '-',
+                        DiffLineKind::Context => ' ',
+                    };
+                    let _ = writeln!(text, "{prefix}{}", line.text);
+                }
+            }
+        }
+
+        Self {
+            revision: diff.range.head.clone(),
+            text,
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.text.lines().count()
+    }
+}
+
+/// Pair commits of `old` with commits of `new` and order the result the way
+/// `git range-diff` does: by the new range, with each dropped commit shown
+/// once the commits before it in the old range have been shown.
+///
+/// Candidate pairs are accepted cheapest first, so exact matches always win.
+pub fn pair(old: &[CommitPatch], new: &[CommitPatch]) -> Result<Vec<CommitPair>> {
+    let mut candidates = Vec::new();
+    for (old_index, old_patch) in old.iter().enumerate() {
+        for (new_index, new_patch) in new.iter().enumerate() {
+            let cost = if old_patch.text == new_patch.text {
+                0
+            } else {
+                changed_lines(&interdiff(&old_patch.text, &new_patch.text)?)
+            };
+            let limit = (old_patch.size() + new_patch.size()) * CREATION_FACTOR / 100;
+            if cost == 0 || cost < limit {
+                candidates.push((cost, old_index, new_index));
+            }
+        }
+    }
+    candidates.sort_unstable();
+
+    let mut old_match = vec![None; old.len()];
+    let mut new_match = vec![None; new.len()];
+    for (_, old_index, new_index) in candidates {
+        if old_match[old_index].is_none() && new_match[new_index].is_none() {
+            old_match[old_index] = Some(new_index);
+            new_match[new_index] = Some(old_index);
+        }
+    }
+
+    let mut pairs = Vec::with_capacity(old.len().max(new.len()));
+    let mut shown = vec![false; old.len()];
+    let (mut old_index, mut new_index) = (0, 0);
+    while old_index < old.len() || new_index < new.len() {
+        while old_index < old.len() && shown[old_index] {
+            old_index += 1;
+        }
+        if old_index < old.len() && old_match[old_index].is_none() {
+            pairs.push(unpaired(CommitPairStatus::Dropped, &old[old_index]));
+            shown[old_index] = true;
+            continue;
+        }
+
+        while new_index < new.len() && new_match[new_index].is_none() {
+            pairs.push(unpaired(CommitPairStatus::Added, &new[new_index]));
+            new_index += 1;
+        }
+        if let Some(matched) = new_match.get(new_index).copied().flatten() {
+            pairs.push(matched_pair(&old[matched], &new[new_index])?);
+            shown[matched] = true;
+            new_index += 1;
+        }
+    }
+
+    Ok(pairs)
+}
+
+fn unpaired(status: CommitPairStatus, patch: &CommitPatch) -> CommitPair {
+    let revision = Some(patch.revision.clone());
+    let (old, new) = if status == CommitPairStatus::Dropped {
+        (revision, None)
+    } else {
+        (None, revision)
+    };
+    CommitPair {
+        status,
+        old,
+        new,
+        interdiff: Vec::new(),
+    }
+}
+
+fn matched_pair(old: &CommitPatch, new: &CommitPatch) -> Result<CommitPair> {
+    let interdiff = interdiff(&old.text, &new.text)?;
+    Ok(CommitPair {
+        status: if interdiff.is_empty() {
+            CommitPairStatus::Unchanged
+        } else {
+            CommitPairStatus::Modified
+        },
+        old: Some(old.revision.clone()),
+        new: Some(new.revision.clone()),
+        interdiff,
+    })
+}
+
+/// Diff two patch texts.
+fn interdiff(old: &str, new: &str) -> Result<Vec<DiffHunk>> {
+    let mut options = DiffOptions::new();
+    options.context_lines(3).interhunk_lines(0);
+    let patch = git2::Patch::from_buffers(
+        old.as_bytes(),
+        None,
+        new.as_bytes(),
+        None,
+        Some(&mut options),
+    )?;
+    Ok(super::build_patch(&patch, super::DiffBuilder::default())?
+        .map_or_else(Vec::new, |file| file.hunks))
+}
+
+fn changed_lines(hunks: &[DiffHunk]) -> usize {
+    hunks
+        .iter()
+        .flat_map(|hunk| &hunk.lines)
+        .filter(|line| line.kind != DiffLineKind::Context)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::repository::RevisionKind;
+
+    fn patch(oid: &str, text: &str) -> CommitPatch {
+        CommitPatch {
+            revision: Revision {
+                oid: oid.into(),
+                reference: None,
+                summary: None,
+                author: None,
+                committer: None,
+                timestamp: None,
+                kind: RevisionKind::Commit,
+            },
+            text: text.into(),
+        }
+    }
+
+    fn statuses(pairs: &[CommitPair]) -> Vec<(CommitPairStatus, Option<&str>, Option<&str>)> {
+        pairs
+            .iter()
+            .map(|pair| {
+                (
+                    pair.status,
+                    pair.old.as_ref().map(|revision| revision.oid.as_str()),
+                    pair.new.as_ref().map(|revision| revision.oid.as_str()),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pairs_commits_by_patch_similarity() -> Result<()> {
+        let old = [
+            patch("a1", "    Add a\n## a (Added)\n@@ \n+one\n+two\n+three\n"),
+            patch(
+                "b1",
+                "    Add b\n## b (Added)\n@@ \n+alpha\n+beta\n+gamma\n",
+            ),
+            patch("c1", "    Add c\n## c (Added)\n@@ \n+x\n"),
+        ];
+        let new = [
+            patch("a2", "    Add a\n## a (Added)\n@@ \n+one\n+two\n+three\n"),
+            patch("d2", "    Add d\n## d (Added)\n@@ \n+delta\n"),
+            patch(
+                "b2",
+                "    Add b\n## b (Added)\n@@ \n+alpha\n+BETA\n+gamma\n",
+            ),
+        ];
+
+        let pairs = pair(&old, &new)?;
+        assert_eq!(
+            statuses(&pairs),
+            vec![
+                (CommitPairStatus::Unchanged, Some("a1"), Some("a2")),
+                (CommitPairStatus::Added, None, Some("d2")),
+                (CommitPairStatus::Modified, Some("b1"), Some("b2")),
+                (CommitPairStatus::Dropped, Some("c1"), None),
+            ]
+        );
+
+        let interdiff = &pairs[2].interdiff;
+        let changed: Vec<_> = interdiff
+            .iter()
+            .flat_map(|hunk| &hunk.lines)
+            .filter(|line| line.kind != DiffLineKind::Context)
+            .map(|line| (line.kind, line.text.as_str()))
+            .collect();
+        assert_eq!(
+            changed,
+            vec![
+                (DiffLineKind::Deletion, "+beta"),
+                (DiffLineKind::Addition, "+BETA"),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn renders_patch_without_line_numbers() {
+        let diff: Diff = serde_json::from_str(
+            r#"{
+                "range": {"head": {"oid": "abc"}},
+                "files": [{
+                    "path": "src/lib.rs",
+                    "status": "modified",
+                    "hunks": [{
+                        "header": {"base_start": 10, "base_lines": 1, "head_start": 12, "head_lines": 1},
+                        "section": "fn main()",
+                        "lines": [
+                            {"kind": "deletion", "text": "old", "base_line": 10},
+                            {"kind": "addition", "text": "new", "head_line": 12}
+                        ]
+                    }]
+                }]
+            }"#,
+        )
+        .expect("diff");
+        let rendered = CommitPatch::new(&diff, "Subject\n\nBody\n");
+        assert_eq!(
+            rendered.text,
+            "    Subject\n    \n    Body\n\n## src/lib.rs (Modified)\n@@ fn main()\n-old\n+new\n"
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Enclosing symbols: the innermost function or type around each diff line.
+//!
+//! git's hunk section header comes from a regex-based diff driver and is
+//! empty or misleading for languages without one configured. Outlining the
+//! file instead gives the declaration a change actually sits in. Deleted
+//! lines are looked up in the base outline and every other line in the head
+//! outline.
+
+use crate::api::diff::{DiffFile, DiffLine, DiffLineKind};
+use crate::api::review::DiffSide;
+use crate::encoding;
+use crate::syntax::{outline, Language, Symbol};
+
+use super::structure::read_outline;
+
+/// Label the lines of `file` with their enclosing symbols and replace each
+/// hunk's section with the signature around its first change. Hunks whose
+/// first change is outside any declaration keep git's section header.
+/// `workspace` marks the head side as a working directory file.
+pub fn annotate_file(repo: &git2::Repository, workspace: bool, file: &mut DiffFile) {
+    if file.hunks.is_empty()
+        || (file.is_binary && !file.encoding.as_ref().is_some_and(encoding::is_utf16))
+        || file.submodule.is_some()
+    {
+        return;
+    }
+    let Some(language) = Language::detect(repo, &file.path) else {
+        return;
+    };
+
+    let has_deletions = file
+        .hunks
+        .iter()
+        .flat_map(|hunk| &hunk.lines)
+        .any(|line| line.kind == DiffLineKind::Deletion);
+    let base = if has_deletions {
+        let base_path = file.old_path.as_deref().unwrap_or(&file.path);
+        read_outline(repo, language, file.old_blob.as_ref(), base_path, false)
+    } else {
+        None
+    };
+    let head = read_outline(
+        repo,
+        language,
+        file.new_blob.as_ref(),
+        &file.path,
+        workspace,
+    );
+    let base = base.unwrap_or_default();
+    let head = head.unwrap_or_default();
+    if base.is_empty() && head.is_empty() {
+        return;
+    }
+
+    for hunk in &mut file.hunks {
+        let mut section = None;
+        for line in &mut hunk.lines {
+            let (symbols, number) = match line.kind {
+                DiffLineKind::Deletion => (&base, line.base_line),
+                DiffLineKind::Context | DiffLineKind::Addition => (&head, line.head_line),
+            };
+            let Some(symbol) = number.and_then(|number| enclosing(symbols, number)) else {
+                continue;
+            };
+            if section.is_none() && line.kind != DiffLineKind::Context {
+                section = Some(symbol.signature.clone());
+            }
+            line.enclosing_symbol = Some(symbol.qualified_name.clone());
+        }
+        if section.is_some() {
+            hunk.section = section;
+        }
+    }
+}
+
+/// Label expanded context `lines` read from `source`, the file at `path` on
+/// `side`.
+pub fn label_lines(
+    repo: &git2::Repository,
+    path: &str,
+    source: &str,
+    side: DiffSide,
+    lines: &mut [DiffLine],
+) {
+    let Some(symbols) = Language::detect(repo, path).and_then(|language| outline(language, source))
+    else {
+        return;
+    };
+    for line in lines {
+        let number = match side {
+            DiffSide::Base => line.base_line,
+            DiffSide::Head => line.head_line,
+        };
+        line.enclosing_symbol = number
+            .and_then(|number| enclosing(&symbols, number))
+            .map(|symbol| symbol.qualified_name.clone());
+    }
+}
+
+/// The innermost symbol spanning `line`. Nested declarations start after
+/// their container, or on the same line and later in file order.
+fn enclosing(symbols: &[Symbol], line: u32) -> Option<&Symbol> {
+    symbols
+        .iter()
+        .filter(|symbol| symbol.line <= line && line <= symbol.end_line)
+        .max_by_key(|symbol| symbol.line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_innermost_declaration() {
+        let source = "struct Store;\n\
+            \n\
+            impl Store {\n\
+            \x20   fn load(&self) -> u8 {\n\
+            \x20       1\n\
+            \x20   }\n\
+            \n\
+            \x20   fn save(&self) {}\n\
+            }\n";
+        let symbols = outline(Language::Rust, source).expect("outline");
+        let name = |line| enclosing(&symbols, line).map(|symbol| symbol.qualified_name.as_str());
+        assert_eq!(name(1), Some("Store"));
+        assert_eq!(name(2), None);
+        assert_eq!(name(5), Some("Store::load"));
+        assert_eq!(name(7), Some("impl Store"));
+        assert_eq!(name(8), Some("Store::save"));
+    }
+}
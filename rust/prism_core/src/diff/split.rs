@@ -0,0 +1,98 @@
+//! Side-by-side layout of diff hunks.
+//!
+//! Rows are aligned the same way intraline highlights are paired: within each
+//! run of changed lines, the n-th deletion sits next to the n-th addition, and
+//! any surplus on one side is padded with filler on the other.
+
+use crate::api::diff::{DiffHunk, DiffLine, DiffLineKind, SplitHunk, SplitRow};
+
+/// Lay out every hunk for side-by-side display.
+#[must_use]
+pub fn split_hunks(hunks: &[DiffHunk]) -> Vec<SplitHunk> {
+    hunks.iter().map(split_hunk).collect()
+}
+
+fn split_hunk(hunk: &DiffHunk) -> SplitHunk {
+    let mut rows = Vec::with_capacity(hunk.lines.len());
+    let mut deletions: Vec<&DiffLine> = Vec::new();
+    let mut additions: Vec<&DiffLine> = Vec::new();
+
+    for line in &hunk.lines {
+        match line.kind {
+            DiffLineKind::Deletion => deletions.push(line),
+            DiffLineKind::Addition => additions.push(line),
+            DiffLineKind::Context => {
+                flush_block(&mut rows, &mut deletions, &mut additions);
+                rows.push(SplitRow {
+                    base: Some(line.clone()),
+                    head: Some(line.clone()),
+                });
+            }
+        }
+    }
+    flush_block(&mut rows, &mut deletions, &mut additions);
+
+    SplitHunk {
+        header: hunk.header,
+        section: hunk.section.clone(),
+        rows,
+    }
+}
+
+fn flush_block(
+    rows: &mut Vec<SplitRow>,
+    deletions: &mut Vec<&DiffLine>,
+    additions: &mut Vec<&DiffLine>,
+) {
+    let count = deletions.len().max(additions.len());
+    for index in 0..count {
+        rows.push(SplitRow {
+            base: deletions.get(index).map(|line| (*line).clone()),
+            head: additions.get(index).map(|line| (*line).clone()),
+        });
+    }
+    deletions.clear();
+    additions.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diff::fixtures::{hunk, line};
+
+    fn texts(row: &SplitRow) -> (Option<&str>, Option<&str>) {
+        (
+            row.base.as_ref().map(|line| line.text.as_str()),
+            row.head.as_ref().map(|line| line.text.as_str()),
+        )
+    }
+
+    #[test]
+    fn pairs_changes_and_pads_with_filler() {
+        let mut hunk = hunk(vec![
+            line(DiffLineKind::Context, "a"),
+            line(DiffLineKind::Deletion, "b"),
+            line(DiffLineKind::Deletion, "c"),
+            line(DiffLineKind::Addition, "B"),
+            line(DiffLineKind::Context, "d"),
+            line(DiffLineKind::Addition, "e"),
+            line(DiffLineKind::Addition, "f"),
+        ]);
+        hunk.section = Some("fn main()".into());
+
+        let split = split_hunks(std::slice::from_ref(&hunk));
+        assert_eq!(split[0].section.as_deref(), Some("fn main()"));
+        let rows: Vec<_> = split[0].rows.iter().map(texts).collect();
+        assert_eq!(
+            rows,
+            vec![
+                (Some("a"), Some("a")),
+                (Some("b"), Some("B")),
+                (Some("c"), None),
+                (Some("d"), Some("d")),
+                (None, Some("e")),
+                (None, Some("f")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! Staging state for workspace diffs: whether a file's uncommitted changes
+//! sit in the index, the working tree, or both.
+//!
+//! States come from `git status` flags, read without rename detection so a
+//! staged rename shows up under both of its paths.
+
+use std::collections::HashMap;
+
+use git2::{Status, StatusOptions};
+
+use crate::api::diff::{DiffFile, DiffRequest, StageState};
+use crate::Result;
+
+/// Status flags of every uncommitted path in the workspace.
+pub struct Stages(HashMap<String, Status>);
+
+impl Stages {
+    /// Read the status of the paths matched by the request's include
+    /// pathspecs.
+    pub fn read(repo: &git2::Repository, request: &DiffRequest) -> Result<Self> {
+        let mut options = StatusOptions::new();
+        options
+            .include_untracked(true)
+            .recurse_untracked_dirs(true)
+            .include_ignored(false)
+            .exclude_submodules(false);
+        for path in &request.include_paths {
+            options.pathspec(path);
+        }
+
+        let statuses = repo.statuses(Some(&mut options))?;
+        Ok(Self(
+            statuses
+                .iter()
+                .map(|entry| {
+                    let path = String::from_utf8_lossy(entry.path_bytes()).into_owned();
+                    (path, entry.status())
+                })
+                .collect(),
+        ))
+    }
+
+    /// Set `stage` on `file`. Both paths of a renamed file count, so a
+    /// rename staged with `git mv` and then edited is partially staged.
+    pub fn annotate(&self, file: &mut DiffFile) {
+        let status = |path: &str| self.0.get(path).copied().unwrap_or(Status::CURRENT);
+        let flags = status(&file.path) | file.old_path.as_deref().map_or(Status::CURRENT, status);
+        file.stage = state(flags);
+    }
+}
+
+fn state(flags: Status) -> Option<StageState> {
+    let staged = flags.intersects(
+        Status::INDEX_NEW
+            | Status::INDEX_MODIFIED
+            | Status::INDEX_DELETED
+            | Status::INDEX_RENAMED
+            | Status::INDEX_TYPECHANGE,
+    );
+    let unstaged = flags.intersects(
+        Status::WT_MODIFIED | Status::WT_DELETED | Status::WT_RENAMED | Status::WT_TYPECHANGE,
+    );
+    match (staged, unstaged) {
+        (true, true) => Some(StageState::PartiallyStaged),
+        (true, false) => Some(StageState::Staged),
+        (false, true) => Some(StageState::Unstaged),
+        (false, false) => flags
+            .contains(Status::WT_NEW)
+            .then_some(StageState::Untracked),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combines_index_and_worktree_flags() {
+        assert_eq!(
+            state(Status::INDEX_MODIFIED | Status::WT_MODIFIED),
+            Some(StageState::PartiallyStaged)
+        );
+        assert_eq!(state(Status::INDEX_NEW), Some(StageState::Staged));
+        assert_eq!(state(Status::WT_DELETED), Some(StageState::Unstaged));
+        assert_eq!(state(Status::WT_NEW), Some(StageState::Untracked));
+        assert_eq!(
+            state(Status::WT_NEW | Status::WT_DELETED),
+            Some(StageState::Unstaged)
+        );
+        assert_eq!(state(Status::CURRENT), None);
+    }
+}
@@ -0,0 +1,238 @@
+//! Structural summaries: the functions, types, and impl blocks a file diff
+//! adds, removes, renames, or edits.
+//!
+//! Both sides of the file are outlined and their declarations paired by kind
+//! and qualified name, in order, so overloads pair up one by one. Leftover
+//! declarations of the same kind and container whose bodies match are
+//! reported as renamed; the rest as added or removed.
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::api::diff::{
+    BlobInfo, DiffFile, StructuralSummary, SymbolChange, SymbolChangeKind, SymbolKind,
+};
+use crate::encoding;
+use crate::syntax::{outline, Language, Symbol};
+
+use super::{blobs, tokens::MAX_SOURCE_BYTES};
+
+/// Set `structure` on `file` when its language is recognized and both sides
+/// can be parsed. `workspace` marks the head side as a working directory
+/// file.
+pub fn annotate(repo: &git2::Repository, workspace: bool, file: &mut DiffFile) {
+    if (file.is_binary && !file.encoding.as_ref().is_some_and(encoding::is_utf16))
+        || file.submodule.is_some()
+    {
+        return;
+    }
+    let Some(language) = Language::detect(repo, &file.path) else {
+        return;
+    };
+
+    let base_path = file.old_path.as_deref().unwrap_or(&file.path);
+    let Some(base) = read_outline(repo, language, file.old_blob.as_ref(), base_path, false) else {
+        return;
+    };
+    let Some(head) = read_outline(
+        repo,
+        language,
+        file.new_blob.as_ref(),
+        &file.path,
+        workspace,
+    ) else {
+        return;
+    };
+    file.structure = Some(summarize(compare(&base, &head)));
+}
+
+/// Outline one side of a file diff. A missing side has no declarations;
+/// `None` means the side could not be read or parsed.
+pub fn read_outline(
+    repo: &git2::Repository,
+    language: Language,
+    blob: Option<&BlobInfo>,
+    path: &str,
+    workdir: bool,
+) -> Option<Vec<Symbol>> {
+    let Some(blob) = blob else {
+        return Some(Vec::new());
+    };
+    let bytes = blobs::read(repo, path, blob)?;
+    if bytes.len() > MAX_SOURCE_BYTES {
+        return None;
+    }
+    let detected = if workdir {
+        encoding::detect_in_workdir(repo, path, &bytes)
+    } else {
+        encoding::detect(&bytes)
+    };
+    outline(language, &encoding::decode(&bytes, &detected))
+}
+
+/// Pair the declarations of both sides and describe how each changed.
+fn compare(base: &[Symbol], head: &[Symbol]) -> Vec<SymbolChange> {
+    let mut by_name: HashMap<(SymbolKind, &str), VecDeque<usize>> = HashMap::new();
+    for (index, symbol) in head.iter().enumerate() {
+        by_name
+            .entry((symbol.kind, symbol.qualified_name.as_str()))
+            .or_default()
+            .push_back(index);
+    }
+
+    let mut changes = Vec::new();
+    let mut removed = Vec::new();
+    let mut head_matched = vec![false; head.len()];
+    for old in base {
+        let Some(index) = by_name
+            .get_mut(&(old.kind, old.qualified_name.as_str()))
+            .and_then(VecDeque::pop_front)
+        else {
+            removed.push(old);
+            continue;
+        };
+        head_matched[index] = true;
+        let new = &head[index];
+        let change = if old.signature != new.signature || old.is_public != new.is_public {
+            SymbolChangeKind::SignatureChanged
+        } else if old.body != new.body {
+            SymbolChangeKind::BodyChanged
+        } else {
+            continue;
+        };
+        changes.push(change_between(old, new, change));
+    }
+
+    let mut added: Vec<_> = head
+        .iter()
+        .zip(head_matched)
+        .filter_map(|(symbol, matched)| (!matched).then_some(symbol))
+        .collect();
+    for old in removed {
+        match added.iter().position(|new| is_rename(old, new)) {
+            Some(position) => {
+                let new = added.remove(position);
+                let mut change = change_between(old, new, SymbolChangeKind::Renamed);
+                change.old_name = Some(old.qualified_name.clone());
+                changes.push(change);
+            }
+            None => changes.push(SymbolChange {
+                kind: old.kind,
+                name: old.qualified_name.clone(),
+                old_name: None,
+                change: SymbolChangeKind::Removed,
+                is_public: old.is_public,
+                base_line: Some(old.line),
+                head_line: None,
+            }),
+        }
+    }
+    changes.extend(added.into_iter().map(|new| SymbolChange {
+        kind: new.kind,
+        name: new.qualified_name.clone(),
+        old_name: None,
+        change: SymbolChangeKind::Added,
+        is_public: new.is_public,
+        base_line: None,
+        head_line: Some(new.line),
+    }));
+
+    changes.sort_by_key(|change| change.head_line.or(change.base_line));
+    changes
+}
+
+fn change_between(old: &Symbol, new: &Symbol, change: SymbolChangeKind) -> SymbolChange {
+    SymbolChange {
+        kind: new.kind,
+        name: new.qualified_name.clone(),
+        old_name: None,
+        change,
+        is_public: old.is_public || new.is_public,
+        base_line: Some(old.line),
+        head_line: Some(new.line),
+    }
+}
+
+/// Whether `new` is `old` under another name: same kind and container, and
+/// the same body, or the same signature apart from the name when there is
+/// no body to compare.
+fn is_rename(old: &Symbol, new: &Symbol) -> bool {
+    if old.kind != new.kind || old.container != new.container || old.body != new.body {
+        return false;
+    }
+    !old.body.is_empty()
+        || old.signature.replacen(&old.name, "", 1) == new.signature.replacen(&new.name, "", 1)
+}
+
+fn summarize(changes: Vec<SymbolChange>) -> StructuralSummary {
+    let count = |matches: &dyn Fn(&SymbolChange) -> bool| {
+        u32::try_from(changes.iter().filter(|change| matches(change)).count()).unwrap_or(u32::MAX)
+    };
+    StructuralSummary {
+        functions_changed: count(&|change| change.kind == SymbolKind::Function),
+        types_changed: count(&|change| change.kind != SymbolKind::Function),
+        public_signatures_changed: count(&|change| {
+            change.is_public
+                && matches!(
+                    change.change,
+                    SymbolChangeKind::SignatureChanged
+                        | SymbolChangeKind::Removed
+                        | SymbolChangeKind::Renamed
+                )
+        }),
+        changes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn changes(base: &str, head: &str) -> Vec<(SymbolChangeKind, String, Option<String>)> {
+        let outline = |source| outline(Language::Rust, source).expect("outline");
+        compare(&outline(base), &outline(head))
+            .into_iter()
+            .map(|change| (change.change, change.name, change.old_name))
+            .collect()
+    }
+
+    #[test]
+    fn classifies_declaration_changes() {
+        let base = "pub fn keep() { 1; }\n\
+            pub fn body() { 1; }\n\
+            pub fn signature(a: u8) {}\n\
+            fn old_name() { work(); }\n\
+            fn gone() {}\n";
+        let head = "pub fn keep() {\n    1;\n}\n\
+            pub fn body() { 2; }\n\
+            pub fn signature(a: u16) {}\n\
+            fn new_name() { work(); }\n\
+            struct Fresh;\n";
+        assert_eq!(
+            changes(base, head),
+            vec![
+                (SymbolChangeKind::BodyChanged, "body".into(), None),
+                (SymbolChangeKind::SignatureChanged, "signature".into(), None),
+                (SymbolChangeKind::Removed, "gone".into(), None),
+                (
+                    SymbolChangeKind::Renamed,
+                    "new_name".into(),
+                    Some("old_name".into())
+                ),
+                (SymbolChangeKind::Added, "Fresh".into(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn method_edits_leave_their_impl_unchanged() {
+        let base = "impl Store {\n    pub fn load(&self) -> u8 { 1 }\n}\n";
+        let head = "impl Store {\n    pub fn load(&self) -> u16 { 1 }\n\n    fn extra() {}\n}\n";
+        let summary = summarize(compare(
+            &outline(Language::Rust, base).expect("outline"),
+            &outline(Language::Rust, head).expect("outline"),
+        ));
+        assert_eq!(summary.functions_changed, 2);
+        assert_eq!(summary.types_changed, 0);
+        assert_eq!(summary.public_signatures_changed, 1);
+    }
+}
@@ -0,0 +1,98 @@
+//! Submodule pointer changes.
+//!
+//! Gitlink entries are reported as [`FileStatus::Submodule`] with the commit
+//! oids on each side instead of the textual "Subproject commit" hunks libgit2
+//! produces for them.
+//!
+//! [`FileStatus::Submodule`]: crate::api::diff::FileStatus::Submodule
+
+use git2::{FileMode, Oid, Sort, SubmoduleIgnore, SubmoduleStatus};
+
+use crate::api::diff::{DiffFile, DiffRequest, SubmoduleChange};
+use crate::api::repository::Revision;
+use crate::repository::commit_to_revision;
+
+/// Upper bound on submodule commits listed per entry.
+const MAX_SUBMODULE_COMMITS: usize = 100;
+
+/// Whether `file` is a gitlink (submodule commit pointer).
+pub fn is_gitlink(file: &git2::DiffFile<'_>) -> bool {
+    file.exists() && file.mode() == FileMode::Commit
+}
+
+/// Describe the pointer change of a delta that touches a submodule, or `None`
+/// for regular files.
+pub fn submodule_change(delta: &git2::DiffDelta<'_>) -> Option<SubmoduleChange> {
+    let (old_file, new_file) = (delta.old_file(), delta.new_file());
+    if !is_gitlink(&old_file) && !is_gitlink(&new_file) {
+        return None;
+    }
+
+    Some(SubmoduleChange {
+        old_oid: is_gitlink(&old_file).then(|| old_file.id().to_string()),
+        new_oid: is_gitlink(&new_file).then(|| new_file.id().to_string()),
+        dirty: false,
+        commits: Vec::new(),
+    })
+}
+
+/// Fill in working directory state and, when requested, the commit log of a
+/// submodule entry.
+pub fn describe(
+    repo: &git2::Repository,
+    workspace: bool,
+    request: &DiffRequest,
+    file: &mut DiffFile,
+) {
+    let Some(change) = file.submodule.as_mut() else {
+        return;
+    };
+
+    if workspace {
+        change.dirty = repo
+            .submodule_status(&file.path, SubmoduleIgnore::None)
+            .is_ok_and(|status| {
+                status.intersects(
+                    SubmoduleStatus::WD_INDEX_MODIFIED
+                        | SubmoduleStatus::WD_WD_MODIFIED
+                        | SubmoduleStatus::WD_UNTRACKED,
+                )
+            });
+    }
+
+    if request.submodule_log {
+        change.commits = submodule_log(repo, &file.path, change).unwrap_or_default();
+    }
+}
+
+/// Commits reachable from the new pointer but not the old one, read from the
+/// checked-out submodule.
+fn submodule_log(
+    repo: &git2::Repository,
+    path: &str,
+    change: &SubmoduleChange,
+) -> Option<Vec<Revision>> {
+    let new_oid = Oid::from_str(change.new_oid.as_deref()?).ok()?;
+    let submodule = repo.find_submodule(path).ok()?.open().ok()?;
+
+    let mut walk = submodule.revwalk().ok()?;
+    walk.set_sorting(Sort::TOPOLOGICAL | Sort::TIME).ok()?;
+    walk.push(new_oid).ok()?;
+    if let Some(old_oid) = change
+        .old_oid
+        .as_deref()
+        .and_then(|oid| Oid::from_str(oid).ok())
+    {
+        // The old pointer may be missing after a force-push; list everything
+        // reachable from the new pointer in that case.
+        let _ = walk.hide(old_oid);
+    }
+
+    Some(
+        walk.filter_map(std::result::Result::ok)
+            .filter_map(|oid| submodule.find_commit(oid).ok())
+            .take(MAX_SUBMODULE_COMMITS)
+            .map(|commit| commit_to_revision(&commit, None))
+            .collect(),
+    )
+}
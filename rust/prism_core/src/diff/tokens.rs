@@ -0,0 +1,81 @@
+//! Syntax tokens for diff lines.
+//!
+//! Both sides of a file are tokenized in full, then each diff line borrows the
+//! tokens of its line number on the side it belongs to. Context lines use the
+//! head side.
+
+use crate::api::diff::{BlobInfo, DiffFile, DiffLineKind, SyntaxToken};
+use crate::encoding;
+use crate::syntax::{highlight_lines, Language};
+
+use super::blobs;
+
+/// Files larger than this are left untokenized.
+pub const MAX_SOURCE_BYTES: usize = 1 << 20;
+
+/// Populate `tokens` on every line of `file`.
+///
+/// `workspace` marks the head side as a working directory file.
+pub fn annotate_file(repo: &git2::Repository, workspace: bool, file: &mut DiffFile) {
+    if file.is_binary || file.hunks.is_empty() {
+        return;
+    }
+    let Some(language) = Language::detect(repo, &file.path) else {
+        return;
+    };
+
+    let base_path = file.old_path.as_deref().unwrap_or(&file.path);
+    let base = read_tokens(repo, language, file.old_blob.as_ref(), base_path, false);
+    let head = read_tokens(
+        repo,
+        language,
+        file.new_blob.as_ref(),
+        &file.path,
+        workspace,
+    );
+
+    for line in file.hunks.iter_mut().flat_map(|hunk| hunk.lines.iter_mut()) {
+        let from_base = || line_tokens(base.as_deref(), line.base_line);
+        let from_head = || line_tokens(head.as_deref(), line.head_line);
+        line.tokens = match line.kind {
+            DiffLineKind::Deletion => from_base(),
+            DiffLineKind::Addition => from_head(),
+            DiffLineKind::Context => from_head().or_else(from_base),
+        }
+        .unwrap_or_default();
+        if line.truncated {
+            let length = u32::try_from(line.text.chars().count()).unwrap_or(u32::MAX);
+            line.tokens.retain(|token| token.start_column < length);
+            for token in &mut line.tokens {
+                token.end_column = token.end_column.min(length);
+            }
+        }
+    }
+}
+
+/// Tokenize one side of a file diff, or `None` when the side is missing or
+/// cannot be read. Blobs are read the way [`blobs::read`] reads them, so a
+/// working directory symlink yields its link text.
+fn read_tokens(
+    repo: &git2::Repository,
+    language: Language,
+    blob: Option<&BlobInfo>,
+    path: &str,
+    workdir: bool,
+) -> Option<Vec<Vec<SyntaxToken>>> {
+    let source = blobs::read(repo, path, blob?)?;
+    if source.len() > MAX_SOURCE_BYTES {
+        return None;
+    }
+    let detected = if workdir {
+        encoding::detect_in_workdir(repo, path, &source)
+    } else {
+        encoding::detect(&source)
+    };
+    highlight_lines(language, &encoding::decode(&source, &detected))
+}
+
+fn line_tokens(tokens: Option<&[Vec<SyntaxToken>]>, line: Option<u32>) -> Option<Vec<SyntaxToken>> {
+    let index = usize::try_from(line?.checked_sub(1)?).ok()?;
+    tokens?.get(index).cloned()
+}
@@ -0,0 +1,100 @@
+//! Detection of hunks and files that only change whitespace or line endings.
+//!
+//! Line text is stored without its terminator, so a hunk whose deleted lines
+//! read exactly like its added lines can only differ in line endings: CRLF
+//! versus LF, or a missing newline at the end of the file. A hunk whose
+//! deleted and added lines carry the same characters once all whitespace,
+//! line breaks included, is dropped only changes whitespace.
+
+use crate::api::diff::{DiffFile, DiffHunk, DiffLine, DiffLineKind};
+
+/// Set `whitespace_only` and `line_endings_only` on `hunk`.
+///
+/// Hunks that are missing lines or hold truncated lines are left unflagged,
+/// since the lines that were cut may carry visible changes.
+pub fn annotate_hunk(hunk: &mut DiffHunk) {
+    let count = |kind: DiffLineKind| hunk.lines.iter().filter(|line| line.kind == kind).count();
+    let (context, deletions, additions) = (
+        count(DiffLineKind::Context),
+        count(DiffLineKind::Deletion),
+        count(DiffLineKind::Addition),
+    );
+    let complete = (context + deletions) as u64 == u64::from(hunk.header.base_lines)
+        && (context + additions) as u64 == u64::from(hunk.header.head_lines)
+        && !hunk.lines.iter().any(|line| line.truncated);
+    if !complete || deletions + additions == 0 {
+        return;
+    }
+
+    let side = |kind: DiffLineKind| hunk.lines.iter().filter(move |line| line.kind == kind);
+    let texts = |kind| side(kind).map(|line: &DiffLine| line.text.as_str());
+    hunk.line_endings_only = texts(DiffLineKind::Deletion).eq(texts(DiffLineKind::Addition));
+
+    let visible = |kind| {
+        side(kind)
+            .flat_map(|line: &DiffLine| line.text.chars())
+            .filter(|ch| !ch.is_whitespace())
+    };
+    hunk.whitespace_only = hunk.line_endings_only
+        || visible(DiffLineKind::Deletion).eq(visible(DiffLineKind::Addition));
+}
+
+/// Set the file-level flags from the flags of its hunks. Truncated files and
+/// files without hunks are left unflagged.
+pub fn annotate_file(file: &mut DiffFile) {
+    if file.truncated || file.hunks.is_empty() {
+        return;
+    }
+    file.whitespace_only = file.hunks.iter().all(|hunk| hunk.whitespace_only);
+    file.line_endings_only = file.hunks.iter().all(|hunk| hunk.line_endings_only);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diff::fixtures::{hunk, line};
+
+    fn flags(lines: Vec<DiffLine>) -> (bool, bool) {
+        let mut hunk = hunk(lines);
+        annotate_hunk(&mut hunk);
+        (hunk.whitespace_only, hunk.line_endings_only)
+    }
+
+    #[test]
+    fn flags_line_ending_and_whitespace_changes() {
+        assert_eq!(
+            flags(vec![
+                line(DiffLineKind::Deletion, "let a = 1;"),
+                line(DiffLineKind::Addition, "let a = 1;"),
+            ]),
+            (true, true)
+        );
+        assert_eq!(
+            flags(vec![
+                line(DiffLineKind::Context, "fn main() {"),
+                line(DiffLineKind::Deletion, "  run(a,b);"),
+                line(DiffLineKind::Addition, "    run(a, b);"),
+                line(DiffLineKind::Addition, ""),
+            ]),
+            (true, false)
+        );
+        assert_eq!(
+            flags(vec![
+                line(DiffLineKind::Deletion, "run(a);"),
+                line(DiffLineKind::Addition, "run(b);"),
+            ]),
+            (false, false)
+        );
+    }
+
+    #[test]
+    fn leaves_incomplete_hunks_unflagged() {
+        let mut truncated = hunk(vec![
+            line(DiffLineKind::Deletion, "same"),
+            line(DiffLineKind::Addition, "same"),
+        ]);
+        truncated.header.head_lines += 1;
+        annotate_hunk(&mut truncated);
+        assert!(!truncated.whitespace_only);
+    }
+}
@@ -0,0 +1,263 @@
+//! Detection and conversion of file text encodings.
+//!
+//! A byte order mark identifies UTF-8 and UTF-16 text wherever it appears.
+//! Working directory files may also declare their encoding through the
+//! `working-tree-encoding` attribute; git converts such files to UTF-8 when
+//! staging them, so the attribute is not consulted for blobs. Anything else
+//! that is not valid UTF-8 is decoded as windows-1252, which maps every byte,
+//! so legacy Latin-1 sources read correctly and round-trip unchanged.
+
+use std::path::Path;
+
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, WINDOWS_1252};
+
+use crate::api::TextEncoding;
+
+/// Number of leading bytes [`detect_prefix`] looks at, the same prefix git
+/// scans when deciding whether a file is binary.
+pub const PREFIX_LEN: usize = 8000;
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+const UTF16LE_BOM: &[u8] = b"\xFF\xFE";
+const UTF16BE_BOM: &[u8] = b"\xFE\xFF";
+
+/// Detect the encoding of blob contents from their bytes alone.
+#[must_use]
+pub fn detect(bytes: &[u8]) -> TextEncoding {
+    sniff_bom(bytes).unwrap_or_else(|| fallback(bytes))
+}
+
+/// Detect the encoding of the working directory file at `path`, honouring
+/// its `working-tree-encoding` attribute when no byte order mark is present.
+#[must_use]
+pub fn detect_in_workdir(repo: &git2::Repository, path: &str, bytes: &[u8]) -> TextEncoding {
+    if let Some(encoding) = sniff_bom(bytes) {
+        return encoding;
+    }
+    repo.get_attr(
+        Path::new(path),
+        "working-tree-encoding",
+        git2::AttrCheckFlags::default(),
+    )
+    .ok()
+    .flatten()
+    .and_then(|label| from_label(label, bytes))
+    .unwrap_or_else(|| fallback(bytes))
+}
+
+/// Detect the encoding of blob contents from their first [`PREFIX_LEN`]
+/// bytes. Files that are only invalid UTF-8 past the prefix read as UTF-8.
+#[must_use]
+pub fn detect_prefix(prefix: &[u8]) -> TextEncoding {
+    detect(whole_chars(prefix))
+}
+
+/// Like [`detect_in_workdir`], looking only at the first [`PREFIX_LEN`]
+/// bytes of the file.
+#[must_use]
+pub fn detect_prefix_in_workdir(
+    repo: &git2::Repository,
+    path: &str,
+    prefix: &[u8],
+) -> TextEncoding {
+    detect_in_workdir(repo, path, whole_chars(prefix))
+}
+
+/// Whether `encoding` stores text in two-byte units. Such text contains NUL
+/// bytes and must not be mistaken for binary content.
+#[must_use]
+pub const fn is_utf16(encoding: &TextEncoding) -> bool {
+    matches!(
+        encoding,
+        TextEncoding::Utf16Le
+            | TextEncoding::Utf16LeBom
+            | TextEncoding::Utf16Be
+            | TextEncoding::Utf16BeBom
+    )
+}
+
+/// Whether text in `encoding` is UTF-8 without a byte order mark, so that
+/// libgit2's line output can be used as is.
+#[must_use]
+pub const fn is_plain_utf8(encoding: &TextEncoding) -> bool {
+    matches!(encoding, TextEncoding::Utf8 | TextEncoding::Utf8Lossy)
+}
+
+/// Decode `bytes` from `encoding`, dropping any byte order mark. Malformed
+/// sequences are replaced with U+FFFD.
+#[must_use]
+pub fn decode(bytes: &[u8], encoding: &TextEncoding) -> String {
+    let (encoding, bom) = match encoding {
+        TextEncoding::Utf8 | TextEncoding::Utf8Lossy => {
+            return String::from_utf8_lossy(bytes).into_owned();
+        }
+        TextEncoding::Utf8Bom => {
+            let text = bytes.strip_prefix(UTF8_BOM).unwrap_or(bytes);
+            return String::from_utf8_lossy(text).into_owned();
+        }
+        TextEncoding::Utf16Le | TextEncoding::Utf16LeBom => (UTF_16LE, UTF16LE_BOM),
+        TextEncoding::Utf16Be | TextEncoding::Utf16BeBom => (UTF_16BE, UTF16BE_BOM),
+        TextEncoding::Legacy { name } => (legacy(name), &[][..]),
+    };
+    let bytes = bytes.strip_prefix(bom).unwrap_or(bytes);
+    encoding.decode_without_bom_handling(bytes).0.into_owned()
+}
+
+/// Encode `text` back into `encoding`, restoring the byte order mark it was
+/// read with. Returns `None` when `text` cannot be represented exactly,
+/// including any text read as [`TextEncoding::Utf8Lossy`].
+#[must_use]
+pub fn encode(text: &str, encoding: &TextEncoding) -> Option<Vec<u8>> {
+    let utf16 = |bom: bool, to_bytes: fn(u16) -> [u8; 2]| {
+        let bom = bom.then_some(0xFEFF);
+        bom.into_iter()
+            .chain(text.encode_utf16())
+            .flat_map(to_bytes)
+            .collect()
+    };
+    match encoding {
+        TextEncoding::Utf8 => Some(text.as_bytes().to_vec()),
+        TextEncoding::Utf8Lossy => None,
+        TextEncoding::Utf8Bom => Some([UTF8_BOM, text.as_bytes()].concat()),
+        TextEncoding::Utf16Le => Some(utf16(false, u16::to_le_bytes)),
+        TextEncoding::Utf16LeBom => Some(utf16(true, u16::to_le_bytes)),
+        TextEncoding::Utf16Be => Some(utf16(false, u16::to_be_bytes)),
+        TextEncoding::Utf16BeBom => Some(utf16(true, u16::to_be_bytes)),
+        TextEncoding::Legacy { name } => {
+            let (bytes, _, had_errors) = legacy(name).encode(text);
+            (!had_errors).then(|| bytes.into_owned())
+        }
+    }
+}
+
+/// Drop a UTF-8 sequence cut off by the end of a full-length prefix, so it
+/// does not count as invalid. Shorter prefixes hold the whole file.
+fn whole_chars(prefix: &[u8]) -> &[u8] {
+    if prefix.len() < PREFIX_LEN {
+        return prefix;
+    }
+    match std::str::from_utf8(prefix) {
+        Err(error) if error.error_len().is_none() => &prefix[..error.valid_up_to()],
+        _ => prefix,
+    }
+}
+
+fn sniff_bom(bytes: &[u8]) -> Option<TextEncoding> {
+    if bytes.starts_with(UTF8_BOM) {
+        Some(TextEncoding::Utf8Bom)
+    } else if bytes.starts_with(UTF16LE_BOM) {
+        Some(TextEncoding::Utf16LeBom)
+    } else if bytes.starts_with(UTF16BE_BOM) {
+        Some(TextEncoding::Utf16BeBom)
+    } else {
+        None
+    }
+}
+
+/// Interpret a `working-tree-encoding` label the way git's iconv does.
+/// Labels git accepts but this crate cannot decode, such as UTF-32, yield
+/// `None`.
+fn from_label(label: &str, bytes: &[u8]) -> Option<TextEncoding> {
+    match label.to_ascii_uppercase().as_str() {
+        // Without a byte order mark iconv reads UTF-16 as big-endian.
+        "UTF-16" | "UTF16" | "UTF-16BE" | "UTF16BE" => return Some(TextEncoding::Utf16Be),
+        "UTF-16LE" | "UTF16LE" => return Some(TextEncoding::Utf16Le),
+        "UTF-16LE-BOM" => return Some(TextEncoding::Utf16LeBom),
+        "UTF-16BE-BOM" => return Some(TextEncoding::Utf16BeBom),
+        _ => {}
+    }
+    let encoding = Encoding::for_label(label.as_bytes())?;
+    if encoding == encoding_rs::UTF_8 {
+        return Some(fallback_utf8(bytes));
+    }
+    if encoding == UTF_16LE || encoding == UTF_16BE || encoding.output_encoding() != encoding {
+        return None;
+    }
+    Some(TextEncoding::Legacy {
+        name: encoding.name().to_owned(),
+    })
+}
+
+fn fallback(bytes: &[u8]) -> TextEncoding {
+    if std::str::from_utf8(bytes).is_ok() {
+        TextEncoding::Utf8
+    } else {
+        TextEncoding::Legacy {
+            name: WINDOWS_1252.name().to_owned(),
+        }
+    }
+}
+
+const fn fallback_utf8(bytes: &[u8]) -> TextEncoding {
+    if std::str::from_utf8(bytes).is_ok() {
+        TextEncoding::Utf8
+    } else {
+        TextEncoding::Utf8Lossy
+    }
+}
+
+fn legacy(name: &str) -> &'static Encoding {
+    Encoding::for_label(name.as_bytes()).unwrap_or(WINDOWS_1252)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_byte_order_marks_and_utf16() {
+        let bytes = b"\xFF\xFEh\0i\0\n\0";
+        let encoding = detect(bytes);
+        assert_eq!(encoding, TextEncoding::Utf16LeBom);
+        assert_eq!(decode(bytes, &encoding), "hi\n");
+        assert_eq!(encode("hi\n", &encoding).as_deref(), Some(&bytes[..]));
+
+        let bytes = b"\xEF\xBB\xBFhi\n";
+        assert_eq!(detect(bytes), TextEncoding::Utf8Bom);
+        assert_eq!(decode(bytes, &TextEncoding::Utf8Bom), "hi\n");
+        assert_eq!(
+            encode("hi\n", &TextEncoding::Utf8Bom).as_deref(),
+            Some(&bytes[..])
+        );
+    }
+
+    #[test]
+    fn falls_back_to_windows_1252_for_invalid_utf8() {
+        let bytes = b"caf\xe9 \x80\n";
+        let encoding = detect(bytes);
+        assert_eq!(
+            encoding,
+            TextEncoding::Legacy {
+                name: "windows-1252".into()
+            }
+        );
+        assert_eq!(decode(bytes, &encoding), "caf\u{e9} \u{20ac}\n");
+        assert_eq!(
+            encode("caf\u{e9} \u{20ac}\n", &encoding).as_deref(),
+            Some(&bytes[..])
+        );
+        assert_eq!(encode("\u{1F600}", &encoding), None);
+    }
+
+    #[test]
+    fn ignores_characters_split_by_the_prefix() {
+        let mut prefix = vec![b'a'; PREFIX_LEN - 1];
+        prefix.push(0xC3);
+        assert_eq!(detect_prefix(&prefix), TextEncoding::Utf8);
+        assert_ne!(detect_prefix(b"caf\xc3"), TextEncoding::Utf8);
+    }
+
+    #[test]
+    fn interprets_working_tree_encoding_labels() {
+        assert_eq!(from_label("UTF-16LE", b""), Some(TextEncoding::Utf16Le));
+        assert_eq!(from_label("utf-16", b""), Some(TextEncoding::Utf16Be));
+        assert_eq!(
+            from_label("Shift_JIS", b""),
+            Some(TextEncoding::Legacy {
+                name: "Shift_JIS".into()
+            })
+        );
+        assert_eq!(from_label("UTF-8", b"\xff"), Some(TextEncoding::Utf8Lossy));
+        assert_eq!(from_label("UTF-32", b""), None);
+    }
+}
@@ -16,6 +16,27 @@ pub enum CoreError {
     /// Repository has no head revision to diff.
     #[error("repository has no head revision to diff")]
     MissingHeadRevision,
+    /// Repository default branch could not be determined.
+    #[error("repository default branch could not be determined")]
+    MissingDefaultBranch,
+    /// Requested file is not part of the diff.
+    #[error("file is not part of the diff")]
+    FileNotInDiff,
+    /// Path is absolute or escapes the repository root.
+    #[error("path must stay within the repository")]
+    InvalidPath,
+    /// A requested review iteration has not been recorded.
+    #[error("review iteration not found")]
+    IterationNotFound,
+    /// A file's diff is incomplete and cannot be exported as a patch.
+    #[error("incomplete diff cannot be exported")]
+    IncompletePatch,
+    /// A selected hunk is not part of its file's diff.
+    #[error("hunk not found in diff")]
+    HunkNotInDiff,
+    /// A patch does not apply cleanly to the repository head.
+    #[error("patch does not apply")]
+    PatchDoesNotApply,
     /// Underlying git operation failed.
     #[error("git error")]
     Git,
@@ -48,6 +69,15 @@ impl From<Error> for CoreError {
             Error::BareRepository { .. } => Self::BareRepository,
             Error::Io { .. } => Self::Io,
             Error::MissingHeadRevision => Self::MissingHeadRevision,
+            Error::MissingDefaultBranch => Self::MissingDefaultBranch,
+            Error::FileNotInDiff { .. } => Self::FileNotInDiff,
+            Error::InvalidPath { .. } => Self::InvalidPath,
+            Error::IterationNotFound { .. } | Error::NoIterationsRecorded => {
+                Self::IterationNotFound
+            }
+            Error::IncompletePatch { .. } => Self::IncompletePatch,
+            Error::HunkNotInDiff { .. } => Self::HunkNotInDiff,
+            Error::PatchDoesNotApply { .. } => Self::PatchDoesNotApply,
             Error::PluginNotRegistered { .. } => Self::PluginNotRegistered,
             Error::Plugin { .. } => Self::Plugin,
             Error::Suggestion { .. } => Self::Suggestion,
@@ -14,11 +14,17 @@ pub use session::{open, CoreSession};
 mod scaffolding {
     use super::{open, CoreError, CoreSession};
     use crate::{
-        CommentDraft, Diagnostic, Diff, DiffFile, DiffHunk, DiffLine, DiffLineKind, DiffRange,
-        DiffSide, DiffStats, FileRange, FileStatus, LineHighlight, PluginCapabilities,
-        PluginSession, PluginSummary, Position, Range, RepositoryInfo, RepositorySnapshot,
-        ReviewPayload, Revision, RevisionProgress, RevisionRange, RevisionState, Severity,
-        Signature, SubmissionResult, Suggestion, TextEdit, ThreadRef, WorkspaceStatus,
+        BlobInfo, ClassificationRule, CommentDraft, CommitDiffPage, CommitPair, CommitPairStatus,
+        Diagnostic, Diff, DiffFile, DiffFileSummary, DiffHunk, DiffLine, DiffLineKind, DiffRange,
+        DiffRequest, DiffSide, DiffStats, DiffSummary, FileBlobs, FileClassification, FileContents,
+        FileHunks, FileMode, FileRange, FileStatus, FileVersion, ImageFormat, ImageInfo,
+        LineEnding, LineHighlight, LineMove, PatchSelection, PluginCapabilities, PluginSession,
+        PluginSummary, Position, Range, RangeDiff, RepositoryInfo, RepositorySnapshot,
+        ReviewIteration, ReviewPayload, Revision, RevisionKind, RevisionProgress, RevisionRange,
+        RevisionState, Severity, Signature, SplitHunk, SplitRow, StageState, StructuralSummary,
+        SubmissionResult, SubmoduleChange, Suggestion, SymbolChange, SymbolChangeKind, SymbolKind,
+        SyntaxKind, SyntaxToken, TextEdit, TextEncoding, ThreadRef, WhitespaceMode,
+        WorkspaceStatus,
     };
 
     uniffi::include_scaffolding!("prism_core");
@@ -1,13 +1,15 @@
 use std::sync::{Arc, Mutex};
 
 use crate::{
-    diff::DiffEngine,
+    diff::{self, DiffEngine},
     plugins::{
         default_registry, PluginService, PluginSession, PluginSummary, ReviewPayload,
         RevisionProgress, SubmissionResult, ThreadRef,
     },
     repository::{Repository, RepositorySnapshot},
-    Diff, RepositoryInfo, Revision, RevisionRange, WorkspaceStatus,
+    ClassificationRule, CommitDiffPage, Diff, DiffHunk, DiffLine, DiffRequest, DiffSide,
+    DiffSummary, FileBlobs, FileContents, FileHunks, FileVersion, PatchSelection, RangeDiff,
+    RepositoryInfo, ReviewIteration, Revision, RevisionRange, SplitHunk, WorkspaceStatus,
 };
 
 use super::CoreError;
@@ -89,12 +91,16 @@ impl CoreSession {
 
     /// Generate a diff for the current head/base range.
     ///
+    /// Uses [`DiffRequest::default`] when no request is supplied.
+    ///
     /// # Errors
     ///
     /// Returns an error when diff computation fails or repository access is unavailable.
-    pub fn diff_head(&self) -> Result<Diff> {
+    pub fn diff_head(&self, request: Option<DiffRequest>) -> Result<Diff> {
         let repository = self.repository.lock().map_err(CoreError::from)?;
-        self.diff_engine.diff(&repository).map_err(CoreError::from)
+        self.diff_engine
+            .diff(&repository, &request.unwrap_or_default())
+            .map_err(CoreError::from)
     }
 
     /// Generate a diff representing staged and unstaged workspace changes.
@@ -102,10 +108,34 @@ impl CoreSession {
     /// # Errors
     ///
     /// Returns an error when diff computation fails or repository access is unavailable.
-    pub fn diff_workspace(&self) -> Result<Diff> {
+    pub fn diff_workspace(&self, request: Option<DiffRequest>) -> Result<Diff> {
+        let repository = self.repository.lock().map_err(CoreError::from)?;
+        self.diff_engine
+            .diff_workspace(&repository, &request.unwrap_or_default())
+            .map_err(CoreError::from)
+    }
+
+    /// Generate a diff of the changes staged in the index against HEAD.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when diff computation fails or repository access is unavailable.
+    pub fn diff_index(&self, request: Option<DiffRequest>) -> Result<Diff> {
         let repository = self.repository.lock().map_err(CoreError::from)?;
         self.diff_engine
-            .diff_workspace(&repository)
+            .diff_index(&repository, &request.unwrap_or_default())
+            .map_err(CoreError::from)
+    }
+
+    /// Generate a diff of the working tree changes not yet staged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when diff computation fails or repository access is unavailable.
+    pub fn diff_unstaged(&self, request: Option<DiffRequest>) -> Result<Diff> {
+        let repository = self.repository.lock().map_err(CoreError::from)?;
+        self.diff_engine
+            .diff_unstaged(&repository, &request.unwrap_or_default())
             .map_err(CoreError::from)
     }
 
@@ -114,10 +144,362 @@ impl CoreSession {
     /// # Errors
     ///
     /// Returns an error when diff computation fails or the repository lock is poisoned.
-    pub fn diff_for_range(&self, range: RevisionRange) -> Result<Diff> {
+    pub fn diff_for_range(
+        &self,
+        range: RevisionRange,
+        request: Option<DiffRequest>,
+    ) -> Result<Diff> {
+        let repository = self.repository.lock().map_err(CoreError::from)?;
+        self.diff_engine
+            .diff_for_range(&repository, range, &request.unwrap_or_default())
+            .map_err(CoreError::from)
+    }
+
+    /// Generate a diff against the merge-base of HEAD and `target`, or the
+    /// default branch when no target is given.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the target or merge-base cannot be resolved, diff
+    /// computation fails, or the repository lock is poisoned.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn diff_merge_base(
+        &self,
+        target: Option<String>,
+        include_workspace: bool,
+        request: Option<DiffRequest>,
+    ) -> Result<Diff> {
+        let repository = self.repository.lock().map_err(CoreError::from)?;
+        self.diff_engine
+            .diff_merge_base(
+                &repository,
+                target.as_deref(),
+                include_workspace,
+                &request.unwrap_or_default(),
+            )
+            .map_err(CoreError::from)
+    }
+
+    /// Generate per-commit diffs for a page of the commits in `range`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the range cannot be walked, diff computation
+    /// fails, or the repository lock is poisoned.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn diff_commits(
+        &self,
+        range: RevisionRange,
+        offset: u32,
+        limit: u32,
+        request: Option<DiffRequest>,
+    ) -> Result<CommitDiffPage> {
+        let repository = self.repository.lock().map_err(CoreError::from)?;
+        self.diff_engine
+            .diff_commits(
+                &repository,
+                &range,
+                offset,
+                limit,
+                &request.unwrap_or_default(),
+            )
+            .map_err(CoreError::from)
+    }
+
+    /// Summarize the current head/base diff without loading hunks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when diff computation fails or repository access is unavailable.
+    pub fn diff_summary_head(&self, request: Option<DiffRequest>) -> Result<DiffSummary> {
+        let repository = self.repository.lock().map_err(CoreError::from)?;
+        self.diff_engine
+            .diff_summary(&repository, &request.unwrap_or_default())
+            .map_err(CoreError::from)
+    }
+
+    /// Summarize staged and unstaged workspace changes without loading hunks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when diff computation fails or repository access is unavailable.
+    pub fn diff_summary_workspace(&self, request: Option<DiffRequest>) -> Result<DiffSummary> {
+        let repository = self.repository.lock().map_err(CoreError::from)?;
+        self.diff_engine
+            .diff_summary_workspace(&repository, &request.unwrap_or_default())
+            .map_err(CoreError::from)
+    }
+
+    /// Summarize the diff for an explicit revision range without loading hunks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when diff computation fails or the repository lock is poisoned.
+    pub fn diff_summary_for_range(
+        &self,
+        range: RevisionRange,
+        request: Option<DiffRequest>,
+    ) -> Result<DiffSummary> {
+        let repository = self.repository.lock().map_err(CoreError::from)?;
+        self.diff_engine
+            .diff_summary_for_range(&repository, range, &request.unwrap_or_default())
+            .map_err(CoreError::from)
+    }
+
+    /// Load hunks for one file of a previously summarized diff.
+    ///
+    /// Pass the summary's `range` and `workspace` values along with the same
+    /// request used to produce it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::FileNotInDiff`] when the path is not part of the
+    /// diff, or an error when diff computation fails.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn load_file_hunks(
+        &self,
+        range: RevisionRange,
+        workspace: bool,
+        path: String,
+        max_lines: Option<u32>,
+        request: Option<DiffRequest>,
+    ) -> Result<FileHunks> {
+        let repository = self.repository.lock().map_err(CoreError::from)?;
+        self.diff_engine
+            .load_file_hunks(
+                &repository,
+                &range,
+                workspace,
+                &path,
+                max_lines,
+                &request.unwrap_or_default(),
+            )
+            .map_err(CoreError::from)
+    }
+
+    /// Return unchanged lines around the hunks of `path` for "expand context"
+    /// controls, numbered on both sides of the diff.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::FileNotInDiff`] when the path is not part of the
+    /// diff, or an error when file contents or the diff cannot be read.
+    #[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+    pub fn expand_context(
+        &self,
+        range: RevisionRange,
+        workspace: bool,
+        path: String,
+        side: DiffSide,
+        start_line: u32,
+        line_count: u32,
+        request: Option<DiffRequest>,
+    ) -> Result<Vec<DiffLine>> {
+        let repository = self.repository.lock().map_err(CoreError::from)?;
+        self.diff_engine
+            .expand_context(
+                &repository,
+                &range,
+                workspace,
+                &path,
+                side,
+                start_line,
+                line_count,
+                &request.unwrap_or_default(),
+            )
+            .map_err(CoreError::from)
+    }
+
+    /// Read the full contents of `path` at the base, head, or working
+    /// directory version of `range`. Returns `None` when the file does not
+    /// exist at that version.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the revision cannot be resolved or the file cannot be read.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn file_contents(
+        &self,
+        range: RevisionRange,
+        path: String,
+        version: FileVersion,
+    ) -> Result<Option<FileContents>> {
+        let repository = self.repository.lock().map_err(CoreError::from)?;
+        repository
+            .file_contents(&range, &path, version)
+            .map_err(CoreError::from)
+    }
+
+    /// Lay out already loaded hunks for side-by-side display.
+    #[allow(clippy::needless_pass_by_value, clippy::unused_self)]
+    pub fn split_hunks(&self, hunks: Vec<DiffHunk>) -> Vec<SplitHunk> {
+        diff::split_hunks(&hunks)
+    }
+
+    /// Load the hunks for a single file of a diff, laid out for side-by-side display.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::FileNotInDiff`] when the path is not part of the
+    /// diff, or an error when diff computation fails.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn load_split_hunks(
+        &self,
+        range: RevisionRange,
+        workspace: bool,
+        path: String,
+        request: Option<DiffRequest>,
+    ) -> Result<Vec<SplitHunk>> {
+        let repository = self.repository.lock().map_err(CoreError::from)?;
+        self.diff_engine
+            .load_split_hunks(
+                &repository,
+                &range,
+                workspace,
+                &path,
+                &request.unwrap_or_default(),
+            )
+            .map_err(CoreError::from)
+    }
+
+    /// Read the raw bytes of both sides of a file in a diff, for rendering
+    /// binary and image changes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::FileNotInDiff`] when the path is not part of the
+    /// diff, or an error when diff computation fails.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn load_file_blobs(
+        &self,
+        range: RevisionRange,
+        workspace: bool,
+        path: String,
+        request: Option<DiffRequest>,
+    ) -> Result<FileBlobs> {
+        let repository = self.repository.lock().map_err(CoreError::from)?;
+        self.diff_engine
+            .load_file_blobs(
+                &repository,
+                &range,
+                workspace,
+                &path,
+                &request.unwrap_or_default(),
+            )
+            .map_err(CoreError::from)
+    }
+
+    /// Record the tree the reviewer has just seen as a new review iteration.
+    ///
+    /// Pass a commit or tree revspec, or `None` to snapshot the current
+    /// workspace.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the revision cannot be resolved, the workspace tree
+    /// cannot be written, or the repository lock is poisoned.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn record_iteration(&self, revision: Option<String>) -> Result<ReviewIteration> {
+        let repository = self.repository.lock().map_err(CoreError::from)?;
+        self.diff_engine
+            .record_iteration(&repository, revision.as_deref())
+            .map_err(CoreError::from)
+    }
+
+    /// List review iterations recorded in this session, oldest first.
+    #[must_use]
+    pub fn review_iterations(&self) -> Vec<ReviewIteration> {
+        self.diff_engine.review_iterations()
+    }
+
+    /// Generate a diff from a recorded review iteration to the current
+    /// workspace. `None` selects the most recent iteration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the iteration has not been recorded, diff
+    /// computation fails, or the repository lock is poisoned.
+    pub fn diff_since_iteration(
+        &self,
+        number: Option<u32>,
+        request: Option<DiffRequest>,
+    ) -> Result<Diff> {
+        let repository = self.repository.lock().map_err(CoreError::from)?;
+        self.diff_engine
+            .diff_since_iteration(&repository, number, &request.unwrap_or_default())
+            .map_err(CoreError::from)
+    }
+
+    /// Compare two versions of a rewritten branch, `git range-diff` style.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when either range cannot be walked, diff computation
+    /// fails, or the repository lock is poisoned.
+    pub fn range_diff(
+        &self,
+        old_range: RevisionRange,
+        new_range: RevisionRange,
+        request: Option<DiffRequest>,
+    ) -> Result<RangeDiff> {
+        let repository = self.repository.lock().map_err(CoreError::from)?;
+        self.diff_engine
+            .range_diff(
+                &repository,
+                old_range,
+                new_range,
+                &request.unwrap_or_default(),
+            )
+            .map_err(CoreError::from)
+    }
+
+    /// Replace the globs that classify diff files ahead of `.gitattributes`
+    /// and built-in naming conventions.
+    pub fn set_classification_rules(&self, rules: Vec<ClassificationRule>) {
+        self.diff_engine.set_classification_rules(rules);
+    }
+
+    /// Render a diff, or the selected files and hunks of it, as a unified
+    /// patch that `git apply` accepts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::FileNotInDiff`] or [`CoreError::HunkNotInDiff`]
+    /// for selections the diff does not contain, and
+    /// [`CoreError::IncompletePatch`] when a selected file was truncated.
+    #[allow(clippy::needless_pass_by_value, clippy::unused_self)]
+    pub fn export_patch(
+        &self,
+        diff: Diff,
+        selection: Option<Vec<PatchSelection>>,
+    ) -> Result<Vec<u8>> {
+        diff::render_patch(&diff, selection.as_deref()).map_err(CoreError::from)
+    }
+
+    /// Build a reviewable diff from `git diff`, `diff -u`, or
+    /// `git format-patch` output, optionally checking that it applies to the
+    /// current head.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::PatchDoesNotApply`] when the requested check
+    /// fails, or an error when the patch cannot be parsed or the repository
+    /// lock is poisoned.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn diff_patch(
+        &self,
+        patch: Vec<u8>,
+        check_applies: bool,
+        request: Option<DiffRequest>,
+    ) -> Result<Diff> {
         let repository = self.repository.lock().map_err(CoreError::from)?;
         self.diff_engine
-            .diff_for_range(&repository, range)
+            .diff_patch(
+                &repository,
+                &patch,
+                check_applies,
+                &request.unwrap_or_default(),
+            )
             .map_err(CoreError::from)
     }
 
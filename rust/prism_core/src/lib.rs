@@ -31,6 +31,8 @@
 pub mod api;
 /// Diff generation and patching primitives.
 pub mod diff;
+/// Text encoding detection and conversion.
+pub mod encoding;
 /// `UniFFI` bindings and session management.
 pub mod ffi;
 /// Plugin registry and agent integration.
@@ -39,12 +41,19 @@ pub mod plugins;
 pub mod repository;
 /// Suggestion dry-run and apply helpers.
 pub mod suggestion;
+/// Language detection and syntax tokenization.
+pub mod syntax;
 
 pub use api::{
-    CommentDraft, Diagnostic, Diff, DiffFile, DiffHunk, DiffLine, DiffLineKind, DiffRange,
-    DiffSide, DiffStats, FileRange, FileStatus, LineHighlight, Position, Range, RepositoryInfo,
-    ReviewComment, ReviewThread, Revision, RevisionRange, Severity, Signature, Suggestion,
-    TextEdit, WorkspaceStatus,
+    BlobInfo, ClassificationRule, CommentDraft, CommitDiffPage, CommitPair, CommitPairStatus,
+    Diagnostic, Diff, DiffFile, DiffFileSummary, DiffHunk, DiffLine, DiffLineKind, DiffRange,
+    DiffRequest, DiffSide, DiffStats, DiffSummary, FileBlobs, FileClassification, FileContents,
+    FileHunks, FileMode, FileRange, FileStatus, FileVersion, ImageFormat, ImageInfo, LineEnding,
+    LineHighlight, LineMove, PatchSelection, Position, Range, RangeDiff, RepositoryInfo,
+    ReviewComment, ReviewIteration, ReviewThread, Revision, RevisionKind, RevisionRange, Severity,
+    Signature, SplitHunk, SplitRow, StageState, StructuralSummary, SubmoduleChange, Suggestion,
+    SymbolChange, SymbolChangeKind, SymbolKind, SyntaxKind, SyntaxToken, TextEdit, TextEncoding,
+    WhitespaceMode, WorkspaceStatus,
 };
 pub use plugins::{
     PluginCapabilities, PluginRegistry, PluginService, PluginSession, PluginSummary, ReviewPayload,
@@ -96,6 +105,51 @@ pub enum Error {
     /// Repository does not yet have a head revision to diff against.
     #[error("repository has no head revision to diff")]
     MissingHeadRevision,
+    /// Repository default branch could not be determined.
+    #[error("repository default branch could not be determined")]
+    MissingDefaultBranch,
+    /// Requested file is not part of the diff.
+    #[error("file is not part of the diff: {path}")]
+    FileNotInDiff {
+        /// Path that was requested.
+        path: String,
+    },
+    /// Path is absolute or escapes the repository root.
+    #[error("path must stay within the repository: {path}")]
+    InvalidPath {
+        /// Offending path.
+        path: String,
+    },
+    /// A requested review iteration has not been recorded.
+    #[error("review iteration {number} has not been recorded")]
+    IterationNotFound {
+        /// Requested iteration number.
+        number: u32,
+    },
+    /// The latest review iteration was requested before any was recorded.
+    #[error("no review iteration has been recorded")]
+    NoIterationsRecorded,
+    /// A file's diff was truncated or had its hunks dropped, so it cannot be
+    /// exported as a patch.
+    #[error("diff of {path} is incomplete and cannot be exported as a patch")]
+    IncompletePatch {
+        /// File whose diff is incomplete.
+        path: String,
+    },
+    /// A selected hunk index is out of range for its file.
+    #[error("hunk {index} is not part of the diff of {path}")]
+    HunkNotInDiff {
+        /// File the hunk was selected from.
+        path: String,
+        /// Requested hunk index.
+        index: u32,
+    },
+    /// A patch does not apply cleanly to the repository head.
+    #[error("patch does not apply: {message}")]
+    PatchDoesNotApply {
+        /// Reason reported by git.
+        message: String,
+    },
     /// Requested plugin is not registered.
     #[error("plugin '{plugin}' is not registered")]
     PluginNotRegistered {
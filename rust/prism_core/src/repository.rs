@@ -1,14 +1,20 @@
 //! Repository access and snapshot management built on top of libgit2.
 
 use std::fmt;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
-use git2::{ErrorClass, ErrorCode, Repository as GitRepository, Status, StatusOptions};
+use git2::{
+    Delta, DiffOptions, ErrorClass, ErrorCode, FileMode, IndexEntry, IndexTime, ObjectType, Oid,
+    Repository as GitRepository, Sort, Status, StatusOptions,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    api::{RepositoryInfo, Revision, RevisionRange, Signature, WorkspaceStatus},
-    Error, Result,
+    api::{
+        FileContents, FileVersion, RepositoryInfo, Revision, RevisionKind, RevisionRange,
+        Signature, WorkspaceStatus,
+    },
+    encoding, Error, Result,
 };
 
 /// Immutable snapshot of the repository state that Prism uses as a baseline.
@@ -168,6 +174,311 @@ impl Repository {
         }))
     }
 
+    /// Returns the range from the merge-base of HEAD and `target` to HEAD.
+    ///
+    /// When `target` is `None` the repository default branch is used,
+    /// preferring its `origin` remote-tracking branch when one exists. This
+    /// mirrors the "three-dot" comparison a forge shows for a pull request.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingDefaultBranch`] when no target is given and the
+    /// default branch cannot be determined, or any error produced while
+    /// resolving the target or computing the merge-base.
+    pub fn merge_base_range(&self, target: Option<&str>) -> Result<Option<RevisionRange>> {
+        let Some((reference, head)) = self.head_commit()? else {
+            return Ok(None);
+        };
+
+        let target_commit = match target {
+            Some(spec) => self.inner.revparse_single(spec)?.peel_to_commit()?,
+            None => self.default_branch_commit()?,
+        };
+
+        let merge_base = self.inner.merge_base(head.id(), target_commit.id())?;
+        let base = self.inner.find_commit(merge_base)?;
+
+        Ok(Some(RevisionRange {
+            base: Some(commit_to_revision(&base, None)),
+            head: commit_to_revision(&head, reference),
+        }))
+    }
+
+    /// Returns one single-commit range per commit reachable from
+    /// `range.head` but not from `range.base`, oldest first.
+    ///
+    /// Each returned range pairs a commit with its first parent, matching the
+    /// behaviour of [`Repository::revision_range`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either revision cannot be resolved or the revision
+    /// walk fails.
+    pub fn commit_ranges(&self, range: &RevisionRange) -> Result<Vec<RevisionRange>> {
+        self.walk_range(range)?
+            .map(|oid| self.commit_range(oid?, range))
+            .collect()
+    }
+
+    /// Returns the ranges of [`Repository::commit_ranges`] from `offset`,
+    /// at most `limit` of them, together with the total number of commits.
+    /// Commits outside the window are counted but never loaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either revision cannot be resolved or the revision
+    /// walk fails.
+    pub fn commit_ranges_page(
+        &self,
+        range: &RevisionRange,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<RevisionRange>, usize)> {
+        let mut page = Vec::new();
+        let mut total = 0;
+        for oid in self.walk_range(range)? {
+            let oid = oid?;
+            if total >= offset && page.len() < limit {
+                page.push(self.commit_range(oid, range)?);
+            }
+            total += 1;
+        }
+        Ok((page, total))
+    }
+
+    /// Walk the commits reachable from `range.head` but not `range.base`,
+    /// oldest first.
+    fn walk_range(&self, range: &RevisionRange) -> Result<git2::Revwalk<'_>> {
+        let mut walk = self.inner.revwalk()?;
+        walk.set_sorting(Sort::TOPOLOGICAL | Sort::REVERSE)?;
+        walk.push(Oid::from_str(&range.head.oid)?)?;
+        if let Some(base) = range.base.as_ref() {
+            walk.hide(Oid::from_str(&base.oid)?)?;
+        }
+        Ok(walk)
+    }
+
+    /// Pair commit `oid` with its first parent.
+    fn commit_range(&self, oid: Oid, range: &RevisionRange) -> Result<RevisionRange> {
+        let commit = self.inner.find_commit(oid)?;
+        let reference = if commit.id().to_string() == range.head.oid {
+            range.head.reference.clone()
+        } else {
+            None
+        };
+        let base = if commit.parent_count() == 0 {
+            None
+        } else {
+            Some(commit_to_revision(&commit.parent(0)?, None))
+        };
+
+        Ok(RevisionRange {
+            base,
+            head: commit_to_revision(&commit, reference),
+        })
+    }
+
+    /// Returns the contents of `path` at `revision`, or `None` when the path
+    /// does not exist (or is not a blob) there.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the revision cannot be resolved or its tree cannot
+    /// be read.
+    pub fn blob_at(&self, revision: &Revision, path: &str) -> Result<Option<Vec<u8>>> {
+        Ok(self
+            .find_blob(revision, path)?
+            .map(|blob| blob.content().to_vec()))
+    }
+
+    /// Reads `path` at the requested version of `range`, or returns `None`
+    /// when the file does not exist there.
+    ///
+    /// [`FileVersion::Base`] yields `None` when the range has no base.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a revision in `range` cannot be resolved, or if
+    /// the working directory file cannot be read.
+    pub fn file_contents(
+        &self,
+        range: &RevisionRange,
+        path: &str,
+        version: FileVersion,
+    ) -> Result<Option<FileContents>> {
+        let (oid, bytes) = match version {
+            FileVersion::Base => {
+                let Some(base) = range.base.as_ref() else {
+                    return Ok(None);
+                };
+                match self.find_blob(base, path)? {
+                    Some(blob) => (blob.id(), blob.content().to_vec()),
+                    None => return Ok(None),
+                }
+            }
+            FileVersion::Head => match self.find_blob(&range.head, path)? {
+                Some(blob) => (blob.id(), blob.content().to_vec()),
+                None => return Ok(None),
+            },
+            FileVersion::Workspace => match self.workdir_file(path)? {
+                Some(bytes) => (Oid::hash_object(ObjectType::Blob, &bytes)?, bytes),
+                None => return Ok(None),
+            },
+        };
+
+        let encoding = if version == FileVersion::Workspace {
+            encoding::detect_in_workdir(&self.inner, path, &bytes)
+        } else {
+            encoding::detect(&bytes)
+        };
+        let is_binary = !encoding::is_utf16(&encoding) && looks_binary(&bytes);
+        let (text, encoding) = if is_binary {
+            (None, None)
+        } else {
+            (Some(encoding::decode(&bytes, &encoding)), Some(encoding))
+        };
+
+        Ok(Some(FileContents {
+            path: path.to_owned(),
+            version,
+            oid: oid.to_string(),
+            size: bytes.len() as u64,
+            is_binary,
+            text,
+            encoding,
+        }))
+    }
+
+    /// Returns the contents of `path` in the working directory, or `None`
+    /// when the file does not exist.
+    ///
+    /// Symbolic links are read the way git stores them, as their target
+    /// path, and paths beneath a symbolic link are treated as missing, so no
+    /// file outside the repository is read.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPath`] when `path` is absolute or escapes the
+    /// repository root, or [`Error::Io`] when the file cannot be read.
+    pub fn workdir_file(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        let relative = Path::new(path);
+        if relative.is_absolute()
+            || relative
+                .components()
+                .any(|component| matches!(component, Component::ParentDir))
+        {
+            return Err(Error::InvalidPath {
+                path: path.to_owned(),
+            });
+        }
+
+        let beneath_symlink = relative
+            .ancestors()
+            .skip(1)
+            .filter(|ancestor| !ancestor.as_os_str().is_empty())
+            .any(|ancestor| {
+                std::fs::symlink_metadata(self.root.join(ancestor))
+                    .is_ok_and(|metadata| metadata.file_type().is_symlink())
+            });
+        if beneath_symlink {
+            return Ok(None);
+        }
+
+        let absolute = self.root.join(relative);
+        let read = std::fs::symlink_metadata(&absolute).and_then(|metadata| {
+            if metadata.file_type().is_symlink() {
+                let target = std::fs::read_link(&absolute)?;
+                Ok(target.to_string_lossy().into_owned().into_bytes())
+            } else {
+                std::fs::read(&absolute)
+            }
+        });
+        match read {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(source) => Err(Error::Io {
+                path: display_path(&absolute),
+                source,
+            }),
+        }
+    }
+
+    /// Writes the current working directory state (including untracked,
+    /// non-ignored files) as a tree object and returns its oid.
+    ///
+    /// The tree starts from HEAD and applies every index and working tree
+    /// change on top. The repository index itself is left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingHeadRevision`] when HEAD is unborn, or an error
+    /// if the working directory cannot be read or objects cannot be written.
+    pub fn write_workspace_tree(&self) -> Result<String> {
+        let (_, head) = self.head_commit()?.ok_or(Error::MissingHeadRevision)?;
+        let head_tree = head.tree()?;
+
+        let mut options = DiffOptions::new();
+        options
+            .include_untracked(true)
+            .recurse_untracked_dirs(true)
+            .include_typechange(true);
+        let changes = self
+            .inner
+            .diff_tree_to_workdir_with_index(Some(&head_tree), Some(&mut options))?;
+
+        let mut index = git2::Index::new()?;
+        index.read_tree(&head_tree)?;
+        for delta in changes.deltas() {
+            let file = delta.new_file();
+            let Some(path) = file.path().or_else(|| delta.old_file().path()) else {
+                continue;
+            };
+            if delta.status() == Delta::Deleted {
+                index.remove_path(path)?;
+                continue;
+            }
+
+            let absolute = self.root.join(path);
+            let id = match file.mode() {
+                FileMode::Commit => file.id(),
+                FileMode::Link => {
+                    let target = std::fs::read_link(&absolute).map_err(|source| Error::Io {
+                        path: display_path(&absolute),
+                        source,
+                    })?;
+                    self.inner.blob(target.to_string_lossy().as_bytes())?
+                }
+                _ => self.inner.blob_path(&absolute)?,
+            };
+            index.add(&IndexEntry {
+                ctime: IndexTime::new(0, 0),
+                mtime: IndexTime::new(0, 0),
+                dev: 0,
+                ino: 0,
+                mode: u32::from(file.mode()),
+                uid: 0,
+                gid: 0,
+                file_size: 0,
+                id,
+                flags: 0,
+                flags_extended: 0,
+                path: path.to_string_lossy().into_owned().into_bytes(),
+            })?;
+        }
+
+        Ok(index.write_tree_to(&self.inner)?.to_string())
+    }
+
+    /// Writes the repository index as a tree object and returns its oid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index cannot be read, holds unresolved merge
+    /// conflicts, or objects cannot be written.
+    pub fn write_index_tree(&self) -> Result<String> {
+        Ok(self.inner.index()?.write_tree()?.to_string())
+    }
+
     /// Captures a snapshot of the repository and workspace state.
     ///
     /// # Errors
@@ -187,6 +498,17 @@ impl Repository {
         &self.inner
     }
 
+    fn find_blob(&self, revision: &Revision, path: &str) -> Result<Option<git2::Blob<'_>>> {
+        let tree = revision_tree(&self.inner, revision)?;
+        let entry = match tree.get_path(Path::new(path)) {
+            Ok(entry) => entry,
+            Err(err) if err.code() == ErrorCode::NotFound => return Ok(None),
+            Err(err) => return Err(Error::from(err)),
+        };
+
+        Ok(entry.to_object(&self.inner)?.into_blob().ok())
+    }
+
     fn default_branch(&self) -> Result<Option<String>> {
         match self.inner.find_reference("refs/remotes/origin/HEAD") {
             Ok(reference) => Ok(reference
@@ -197,6 +519,22 @@ impl Repository {
         }
     }
 
+    fn default_branch_commit(&self) -> Result<git2::Commit<'_>> {
+        let branch = self.default_branch()?.ok_or(Error::MissingDefaultBranch)?;
+        let reference = match self
+            .inner
+            .find_reference(&format!("refs/remotes/origin/{branch}"))
+        {
+            Ok(reference) => reference,
+            Err(err) if err.code() == ErrorCode::NotFound => {
+                self.inner.find_reference(&format!("refs/heads/{branch}"))?
+            }
+            Err(err) => return Err(Error::from(err)),
+        };
+
+        Ok(reference.peel_to_commit()?)
+    }
+
     fn current_branch(&self) -> Result<Option<String>> {
         let head = match self.inner.head() {
             Ok(head) => head,
@@ -250,7 +588,20 @@ impl Repository {
     }
 }
 
-fn commit_to_revision(commit: &git2::Commit<'_>, reference: Option<String>) -> Revision {
+/// The tree `revision` stands for: the tree of a commit, or the tree its oid
+/// names for the other kinds.
+pub(crate) fn revision_tree<'repo>(
+    repo: &'repo GitRepository,
+    revision: &Revision,
+) -> Result<git2::Tree<'repo>> {
+    let oid = Oid::from_str(&revision.oid)?;
+    Ok(match revision.kind {
+        RevisionKind::Commit => repo.find_commit(oid)?.tree()?,
+        RevisionKind::Tree | RevisionKind::Index | RevisionKind::Workdir => repo.find_tree(oid)?,
+    })
+}
+
+pub(crate) fn commit_to_revision(commit: &git2::Commit<'_>, reference: Option<String>) -> Revision {
     let author = commit.author();
     let committer = commit.committer();
     Revision {
@@ -260,6 +611,7 @@ fn commit_to_revision(commit: &git2::Commit<'_>, reference: Option<String>) -> R
         author: convert_signature(&author),
         committer: convert_signature(&committer),
         timestamp: Some(commit.time().seconds()),
+        kind: RevisionKind::Commit,
     }
 }
 
@@ -270,6 +622,15 @@ fn convert_signature(signature: &git2::Signature<'_>) -> Option<Signature> {
     })
 }
 
+/// Number of leading bytes inspected for binary detection, matching git.
+const BINARY_SNIFF_LEN: usize = 8000;
+
+/// Treat contents as binary when a NUL byte appears near the start, the same
+/// heuristic git uses.
+fn looks_binary(bytes: &[u8]) -> bool {
+    bytes.iter().take(BINARY_SNIFF_LEN).any(|byte| *byte == 0)
+}
+
 fn display_path(path: &Path) -> String {
     path.to_path_buf()
         .into_os_string()
@@ -288,6 +649,7 @@ impl fmt::Debug for Repository {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::TextEncoding;
     use git2::{IndexAddOption, Repository as GitRepository};
     use tempfile::TempDir;
 
@@ -335,6 +697,216 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn merge_base_range_uses_default_branch() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("file.txt"), "one\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+        let fork_point = git_repo.head()?.peel_to_commit()?;
+        git_repo.reference(
+            "refs/remotes/origin/main",
+            fork_point.id(),
+            true,
+            "test remote",
+        )?;
+        git_repo.reference_symbolic(
+            "refs/remotes/origin/HEAD",
+            "refs/remotes/origin/main",
+            true,
+            "test remote head",
+        )?;
+
+        git_repo.branch("feature", &fork_point, false)?;
+        git_repo.set_head("refs/heads/feature")?;
+        write_file(temp.path().join("file.txt"), "two\n");
+        stage_and_commit(&git_repo, "First feature commit")?;
+        write_file(temp.path().join("file.txt"), "three\n");
+        stage_and_commit(&git_repo, "Second feature commit")?;
+
+        let repo = Repository::open(temp.path())?;
+        let range = repo.merge_base_range(None)?.expect("merge-base range");
+
+        let base = range.base.expect("merge-base revision");
+        assert_eq!(base.oid, fork_point.id().to_string());
+        assert_eq!(range.head.summary.as_deref(), Some("Second feature commit"));
+
+        let explicit = repo
+            .merge_base_range(Some("HEAD~1"))?
+            .expect("explicit range");
+        assert_eq!(
+            explicit.base.expect("explicit base").summary.as_deref(),
+            Some("First feature commit")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn commit_ranges_walk_oldest_first() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        for (index, message) in ["Initial commit", "Second commit", "Third commit"]
+            .iter()
+            .enumerate()
+        {
+            write_file(temp.path().join("file.txt"), &format!("{index}\n"));
+            stage_and_commit(&git_repo, message)?;
+        }
+
+        let repo = Repository::open(temp.path())?;
+        let head = repo.head_revision()?.expect("head revision");
+        let root = repo
+            .commit_ranges(&RevisionRange {
+                base: None,
+                head: head.clone(),
+            })?
+            .remove(0);
+        assert!(root.base.is_none());
+        assert_eq!(root.head.summary.as_deref(), Some("Initial commit"));
+
+        let ranges = repo.commit_ranges(&RevisionRange {
+            base: Some(root.head.clone()),
+            head,
+        })?;
+        let summaries: Vec<_> = ranges
+            .iter()
+            .map(|range| range.head.summary.as_deref())
+            .collect();
+        assert_eq!(summaries, vec![Some("Second commit"), Some("Third commit")]);
+        assert_eq!(
+            ranges[0].base.as_ref().map(|base| base.oid.as_str()),
+            Some(root.head.oid.as_str())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reads_blobs_and_workdir_files() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("file.txt"), "committed\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+        write_file(temp.path().join("file.txt"), "edited\n");
+
+        let repo = Repository::open(temp.path())?;
+        let head = repo.head_revision()?.expect("head revision");
+
+        assert_eq!(
+            repo.blob_at(&head, "file.txt")?.as_deref(),
+            Some(b"committed\n".as_slice())
+        );
+        assert!(repo.blob_at(&head, "missing.txt")?.is_none());
+        assert_eq!(
+            repo.workdir_file("file.txt")?.as_deref(),
+            Some(b"edited\n".as_slice())
+        );
+        assert!(repo.workdir_file("missing.txt")?.is_none());
+        assert!(matches!(
+            repo.workdir_file("../outside.txt"),
+            Err(Error::InvalidPath { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn workdir_symlinks_are_read_as_their_target() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let outside = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(outside.path().join("secret.txt"), "outside\n");
+        write_file(temp.path().join("file.txt"), "inside\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+        let target = outside.path().join("secret.txt");
+        std::os::unix::fs::symlink(&target, temp.path().join("link")).expect("symlink");
+        std::os::unix::fs::symlink(outside.path(), temp.path().join("dir")).expect("symlink");
+
+        let repo = Repository::open(temp.path())?;
+        let link = repo.workdir_file("link")?.expect("link contents");
+        assert_eq!(link, target.to_string_lossy().as_bytes());
+        assert!(repo.workdir_file("dir/secret.txt")?.is_none());
+
+        let range = repo.revision_range()?.expect("revision range");
+        let contents = repo
+            .file_contents(&range, "link", FileVersion::Workspace)?
+            .expect("workspace contents");
+        assert_eq!(
+            contents.oid,
+            Oid::hash_object(ObjectType::Blob, &link)?.to_string()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn file_contents_reads_each_version() -> Result<()> {
+        let temp = TempDir::new().expect("tempdir");
+        let git_repo = GitRepository::init(temp.path())?;
+
+        write_file(temp.path().join("file.txt"), "first\n");
+        stage_and_commit(&git_repo, "Initial commit")?;
+        write_file(temp.path().join("file.txt"), "second\n");
+        std::fs::write(temp.path().join("image.bin"), [0x89, b'P', 0, 1]).expect("write");
+        stage_and_commit(&git_repo, "Update")?;
+        write_file(temp.path().join("file.txt"), "caf\u{e9}\n");
+        std::fs::write(temp.path().join("latin1.txt"), b"caf\xe9\n").expect("write");
+
+        let repo = Repository::open(temp.path())?;
+        let range = repo.revision_range()?.expect("revision range");
+
+        let base = repo
+            .file_contents(&range, "file.txt", FileVersion::Base)?
+            .expect("base contents");
+        assert_eq!(base.text.as_deref(), Some("first\n"));
+        assert_eq!(base.encoding, Some(TextEncoding::Utf8));
+
+        let head = repo
+            .file_contents(&range, "file.txt", FileVersion::Head)?
+            .expect("head contents");
+        assert_eq!(head.text.as_deref(), Some("second\n"));
+        assert_eq!(head.size, 7);
+
+        let workspace = repo
+            .file_contents(&range, "file.txt", FileVersion::Workspace)?
+            .expect("workspace contents");
+        assert_eq!(workspace.text.as_deref(), Some("caf\u{e9}\n"));
+        assert_eq!(
+            workspace.oid,
+            Oid::hash_object(ObjectType::Blob, "caf\u{e9}\n".as_bytes())?.to_string()
+        );
+
+        let binary = repo
+            .file_contents(&range, "image.bin", FileVersion::Head)?
+            .expect("binary contents");
+        assert!(binary.is_binary);
+        assert!(binary.text.is_none());
+        assert_eq!(binary.size, 4);
+
+        let latin1 = repo
+            .file_contents(&range, "latin1.txt", FileVersion::Workspace)?
+            .expect("latin-1 contents");
+        assert_eq!(latin1.text.as_deref(), Some("caf\u{e9}\n"));
+        assert_eq!(
+            latin1.encoding,
+            Some(TextEncoding::Legacy {
+                name: "windows-1252".into()
+            })
+        );
+
+        assert!(repo
+            .file_contents(&range, "image.bin", FileVersion::Base)?
+            .is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn open_non_repository_returns_error() {
         let temp = TempDir::new().expect("tempdir");
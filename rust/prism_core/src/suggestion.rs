@@ -6,7 +6,8 @@ use std::path::{Component, Path, PathBuf};
 
 use git2::Patch;
 
-use crate::api::{DiffSide, FileRange, Position, Suggestion, TextEdit};
+use crate::api::{DiffSide, FileRange, Position, Suggestion, TextEdit, TextEncoding};
+use crate::encoding;
 use crate::repository::Repository;
 use crate::Result;
 
@@ -85,8 +86,16 @@ impl<'repo> SuggestionApplier<'repo> {
                 continue;
             }
 
+            // Write the file back in the encoding it was read with rather
+            // than transcoding it to UTF-8.
+            let bytes = encoding::encode(&change.updated, &change.encoding).ok_or_else(|| {
+                SuggestionError::Unencodable {
+                    path: change.path.clone(),
+                    encoding: change.encoding.clone(),
+                }
+            })?;
             let target_path = repo_root.join(&change.path);
-            fs::write(&target_path, &change.updated).map_err(|source| SuggestionError::Io {
+            fs::write(&target_path, bytes).map_err(|source| SuggestionError::Io {
                 path: change.path.clone(),
                 source,
             })?;
@@ -124,7 +133,7 @@ impl<'repo> SuggestionApplier<'repo> {
     fn build_change(&self, path: &str, edits: &[&TextEdit]) -> Result<FileChange> {
         let repo_root = self.repository.root();
         let absolute = sanitize_path(repo_root, path)?;
-        let original = fs::read_to_string(&absolute).map_err(|source| {
+        let bytes = fs::read(&absolute).map_err(|source| {
             if source.kind() == std::io::ErrorKind::NotFound {
                 SuggestionError::MissingFile {
                     path: path.to_owned(),
@@ -136,6 +145,8 @@ impl<'repo> SuggestionApplier<'repo> {
                 }
             }
         })?;
+        let encoding = encoding::detect_in_workdir(self.repository.git_repo(), path, &bytes);
+        let original = encoding::decode(&bytes, &encoding);
 
         let converter = OffsetConverter::new(&original);
         let mut replacements = Vec::with_capacity(edits.len());
@@ -147,7 +158,7 @@ impl<'repo> SuggestionApplier<'repo> {
             replacements.push(Replacement {
                 start,
                 end,
-                replacement: edit.replacement.clone(),
+                text: edit.replacement.clone(),
             });
         }
 
@@ -156,11 +167,12 @@ impl<'repo> SuggestionApplier<'repo> {
 
         let mut updated = original.clone();
         for replacement in replacements.iter().rev() {
-            updated.replace_range(replacement.start..replacement.end, &replacement.replacement);
+            updated.replace_range(replacement.start..replacement.end, &replacement.text);
         }
 
         Ok(FileChange {
             path: path.to_owned(),
+            encoding,
             original,
             updated,
         })
@@ -193,9 +205,8 @@ fn ensure_non_overlapping(
     edits: &[Replacement],
 ) -> std::result::Result<(), SuggestionError> {
     for window in edits.windows(2) {
-        let [first, second] = match window {
-            [a, b] => [a, b],
-            _ => continue,
+        let [first, second] = window else {
+            continue;
         };
 
         if first.end > second.start {
@@ -214,7 +225,7 @@ fn build_patch(
     updated: &str,
 ) -> std::result::Result<String, git2::Error> {
     let path_ref = Path::new(path);
-    let mut patch = Patch::from_buffers(
+    let mut file_patch = Patch::from_buffers(
         original.as_bytes(),
         Some(path_ref),
         updated.as_bytes(),
@@ -222,13 +233,14 @@ fn build_patch(
         None,
     )?;
 
-    let buffer = patch.to_buf()?;
+    let buffer = file_patch.to_buf()?;
     Ok(String::from_utf8_lossy(buffer.as_ref()).into_owned())
 }
 
 #[derive(Debug)]
 struct FileChange {
     path: String,
+    encoding: TextEncoding,
     original: String,
     updated: String,
 }
@@ -237,7 +249,7 @@ struct FileChange {
 struct Replacement {
     start: usize,
     end: usize,
-    replacement: String,
+    text: String,
 }
 
 #[derive(Debug)]
@@ -426,6 +438,14 @@ pub enum SuggestionError {
         #[source]
         source: git2::Error,
     },
+    /// Edited text cannot be represented in the file's original encoding.
+    #[error("edited text of {path} cannot be written as {encoding:?}")]
+    Unencodable {
+        /// Target file path.
+        path: String,
+        /// Encoding the file was read with.
+        encoding: TextEncoding,
+    },
     /// File write failed while applying edits.
     #[error("failed to write {path}: {source}")]
     Io {
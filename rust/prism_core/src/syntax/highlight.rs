@@ -0,0 +1,255 @@
+//! Classification of syntax tree nodes into per-line token spans.
+
+use std::ops::Range;
+
+use tree_sitter::{Node, TreeCursor};
+
+use super::Language;
+use crate::api::diff::{SyntaxKind, SyntaxToken};
+
+/// Tokenize `source` and return the tokens of each line, indexed by zero-based
+/// line number.
+///
+/// Columns are zero-based character offsets, matching [`crate::LineHighlight`].
+/// Returns `None` when the grammar fails to produce a tree.
+#[must_use]
+pub fn highlight_lines(language: Language, source: &str) -> Option<Vec<Vec<SyntaxToken>>> {
+    let tree = language.parse(source)?;
+    let lines = line_ranges(source);
+    let mut tokens = vec![Vec::new(); lines.len()];
+
+    for (span, kind) in classify_tree(&mut tree.walk()) {
+        push_span(source, &lines, span, kind, &mut tokens);
+    }
+
+    Some(tokens)
+}
+
+/// Walk the tree depth first, emitting classified nodes without descending
+/// into them so strings and comments keep their full extent.
+fn classify_tree(cursor: &mut TreeCursor<'_>) -> Vec<(Range<usize>, SyntaxKind)> {
+    let mut spans = Vec::new();
+    loop {
+        let node = cursor.node();
+        let classified = classify(&node, cursor.field_name());
+        if let Some(kind) = classified {
+            spans.push((node.byte_range(), kind));
+        }
+        if classified.is_none() && cursor.goto_first_child() {
+            continue;
+        }
+
+        loop {
+            if cursor.goto_next_sibling() {
+                break;
+            }
+            if !cursor.goto_parent() {
+                return spans;
+            }
+        }
+    }
+}
+
+/// Map a node to a highlight class using naming conventions shared by the
+/// bundled grammars.
+fn classify(node: &Node<'_>, field: Option<&str>) -> Option<SyntaxKind> {
+    let kind = node.kind();
+    if !node.is_named() {
+        return classify_anonymous(kind);
+    }
+
+    if kind.contains("comment") {
+        return Some(SyntaxKind::Comment);
+    }
+    if kind.contains("string") || matches!(kind, "char_literal" | "rune_literal") {
+        return Some(SyntaxKind::String);
+    }
+    if is_number(kind) {
+        return Some(SyntaxKind::Number);
+    }
+
+    match kind {
+        "true" | "false" | "none" | "null" | "nil" | "undefined" | "boolean_literal" => {
+            Some(SyntaxKind::Constant)
+        }
+        "type_identifier" | "primitive_type" | "predefined_type" => Some(SyntaxKind::Type),
+        "attribute_item" | "inner_attribute_item" | "decorator" | "attribute" => {
+            Some(SyntaxKind::Attribute)
+        }
+        "self" | "this" | "super" | "crate" | "mutable_specifier" => Some(SyntaxKind::Keyword),
+        "identifier" | "simple_identifier" | "field_identifier" | "property_identifier" => {
+            is_function_name(node, field).then_some(SyntaxKind::Function)
+        }
+        _ => None,
+    }
+}
+
+fn classify_anonymous(kind: &str) -> Option<SyntaxKind> {
+    if kind.is_empty() {
+        return None;
+    }
+    if kind.chars().all(|ch| ch.is_ascii_alphabetic() || ch == '_') {
+        return Some(SyntaxKind::Keyword);
+    }
+    if kind.chars().all(|ch| "()[]{},;.:".contains(ch)) {
+        return Some(SyntaxKind::Punctuation);
+    }
+    if kind.chars().all(|ch| ch.is_ascii_punctuation()) {
+        return Some(SyntaxKind::Operator);
+    }
+    None
+}
+
+fn is_number(kind: &str) -> bool {
+    kind == "number"
+        || kind.starts_with("integer")
+        || kind.starts_with("float")
+        || [
+            "int_literal",
+            "real_literal",
+            "hex_literal",
+            "oct_literal",
+            "bin_literal",
+            "imaginary_literal",
+        ]
+        .contains(&kind)
+}
+
+/// Whether an identifier names a function at its definition or call site.
+fn is_function_name(node: &Node<'_>, field: Option<&str>) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    let parent_kind = parent.kind();
+    match field {
+        Some("name") => parent_kind.contains("function") || parent_kind.contains("method"),
+        Some("function" | "macro") => true,
+        _ => false,
+    }
+}
+
+/// Byte ranges of each line, excluding the line terminator.
+fn line_ranges(source: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    for (offset, _) in source.match_indices('\n') {
+        let end = if source[..offset].ends_with('\r') {
+            offset - 1
+        } else {
+            offset
+        };
+        ranges.push(start..end);
+        start = offset + 1;
+    }
+    if start < source.len() {
+        ranges.push(start..source.len());
+    }
+    ranges
+}
+
+/// Cut a byte span into per-line tokens with character columns.
+fn push_span(
+    source: &str,
+    lines: &[Range<usize>],
+    span: Range<usize>,
+    kind: SyntaxKind,
+    tokens: &mut [Vec<SyntaxToken>],
+) {
+    let first = lines.partition_point(|line| line.end < span.start);
+    for (index, line) in lines.iter().enumerate().skip(first) {
+        if line.start >= span.end {
+            break;
+        }
+        let start = span.start.max(line.start);
+        let end = span.end.min(line.end);
+        if start >= end {
+            continue;
+        }
+        let (Some(before), Some(text)) = (source.get(line.start..start), source.get(start..end))
+        else {
+            continue;
+        };
+
+        let start_column = char_count(before);
+        tokens[index].push(SyntaxToken {
+            start_column,
+            end_column: start_column + char_count(text),
+            kind,
+        });
+    }
+}
+
+fn char_count(text: &str) -> u32 {
+    u32::try_from(text.chars().count()).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds_on_line(
+        tokens: &[Vec<SyntaxToken>],
+        line: usize,
+        source: &str,
+    ) -> Vec<(String, SyntaxKind)> {
+        let text = source.lines().nth(line).expect("line");
+        tokens[line]
+            .iter()
+            .map(|token| {
+                let span: String = text
+                    .chars()
+                    .skip(token.start_column as usize)
+                    .take((token.end_column - token.start_column) as usize)
+                    .collect();
+                (span, token.kind)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn classifies_rust_tokens() {
+        let source = "fn main() {\n    let count = 42;\n    println!(\"hi\");\n}\n";
+        let tokens = highlight_lines(Language::Rust, source).expect("tokens");
+
+        let first = kinds_on_line(&tokens, 0, source);
+        assert!(first.contains(&("fn".into(), SyntaxKind::Keyword)));
+        assert!(first.contains(&("main".into(), SyntaxKind::Function)));
+
+        let second = kinds_on_line(&tokens, 1, source);
+        assert!(second.contains(&("let".into(), SyntaxKind::Keyword)));
+        assert!(second.contains(&("42".into(), SyntaxKind::Number)));
+
+        let third = kinds_on_line(&tokens, 2, source);
+        assert!(third.contains(&("println".into(), SyntaxKind::Function)));
+        assert!(third.contains(&("\"hi\"".into(), SyntaxKind::String)));
+    }
+
+    #[test]
+    fn multi_line_comments_cover_every_line() {
+        let source = "/* start\n   still comment é\n end */\nlet x = 1;\n";
+        let tokens = highlight_lines(Language::TypeScript, source).expect("tokens");
+
+        for (line, text) in tokens.iter().zip(source.lines()).take(3) {
+            assert_eq!(
+                *line,
+                vec![SyntaxToken {
+                    start_column: 0,
+                    end_column: char_count(text),
+                    kind: SyntaxKind::Comment,
+                }]
+            );
+        }
+        assert_eq!(
+            kinds_on_line(&tokens, 3, source)[0],
+            ("let".into(), SyntaxKind::Keyword)
+        );
+    }
+
+    #[test]
+    fn python_docstrings_are_strings() {
+        let source = "def f():\n    \"\"\"Doc\n    more\"\"\"\n    return None\n";
+        let tokens = highlight_lines(Language::Python, source).expect("tokens");
+        assert_eq!(tokens[2][0].kind, SyntaxKind::String);
+        assert!(kinds_on_line(&tokens, 3, source).contains(&("None".into(), SyntaxKind::Constant)));
+    }
+}
@@ -0,0 +1,143 @@
+//! Language detection, tree-sitter backed syntax tokens, and declaration
+//! outlines.
+//!
+//! Files are parsed whole so that constructs spanning several lines, such as
+//! block comments and multi-line strings, are classified correctly before the
+//! tokens are cut into per-line spans.
+
+mod highlight;
+mod outline;
+
+use std::path::Path;
+
+pub use self::highlight::highlight_lines;
+pub use self::outline::{outline, Symbol};
+
+/// Languages Prism can parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// Rust sources.
+    Rust,
+    /// Swift sources.
+    Swift,
+    /// TypeScript sources without JSX.
+    TypeScript,
+    /// TypeScript sources with JSX.
+    Tsx,
+    /// JavaScript sources, including JSX.
+    JavaScript,
+    /// Python sources.
+    Python,
+    /// Go sources.
+    Go,
+    /// JSON documents.
+    Json,
+}
+
+impl Language {
+    /// Guess the language from the extension of `path`.
+    #[must_use]
+    pub fn from_path(path: &str) -> Option<Self> {
+        let extension = Path::new(path).extension()?.to_str()?;
+        match extension.to_ascii_lowercase().as_str() {
+            "rs" => Some(Self::Rust),
+            "swift" => Some(Self::Swift),
+            "ts" | "mts" | "cts" => Some(Self::TypeScript),
+            "tsx" => Some(Self::Tsx),
+            "js" | "mjs" | "cjs" | "jsx" => Some(Self::JavaScript),
+            "py" | "pyi" => Some(Self::Python),
+            "go" => Some(Self::Go),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// Resolve a language name as written in `.gitattributes`, either a
+    /// `linguist-language` value or a git `diff` driver name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "rust" => Some(Self::Rust),
+            "swift" => Some(Self::Swift),
+            "typescript" => Some(Self::TypeScript),
+            "tsx" => Some(Self::Tsx),
+            "javascript" | "jsx" => Some(Self::JavaScript),
+            "python" => Some(Self::Python),
+            "go" | "golang" => Some(Self::Go),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// Detect the language of `path`, preferring `.gitattributes` over the
+    /// file extension.
+    ///
+    /// The `linguist-language` attribute is consulted first, followed by the
+    /// `diff` driver name (e.g. `*.inc diff=rust`).
+    #[must_use]
+    pub fn detect(repo: &git2::Repository, path: &str) -> Option<Self> {
+        ["linguist-language", "diff"]
+            .into_iter()
+            .find_map(|attribute| {
+                repo.get_attr(Path::new(path), attribute, git2::AttrCheckFlags::default())
+                    .ok()
+                    .flatten()
+                    .and_then(Self::from_name)
+            })
+            .or_else(|| Self::from_path(path))
+    }
+
+    fn grammar(self) -> tree_sitter::Language {
+        match self {
+            Self::Rust => tree_sitter_rust::LANGUAGE.into(),
+            Self::Swift => tree_sitter_swift::LANGUAGE.into(),
+            Self::TypeScript => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+            Self::Tsx => tree_sitter_typescript::LANGUAGE_TSX.into(),
+            Self::JavaScript => tree_sitter_javascript::LANGUAGE.into(),
+            Self::Python => tree_sitter_python::LANGUAGE.into(),
+            Self::Go => tree_sitter_go::LANGUAGE.into(),
+            Self::Json => tree_sitter_json::LANGUAGE.into(),
+        }
+    }
+
+    /// Parse `source` with this language's grammar.
+    fn parse(self, source: &str) -> Option<tree_sitter::Tree> {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&self.grammar()).ok()?;
+        parser.parse(source, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_language_from_extension_and_name() {
+        assert_eq!(Language::from_path("src/lib.rs"), Some(Language::Rust));
+        assert_eq!(Language::from_path("App/View.SWIFT"), Some(Language::Swift));
+        assert_eq!(Language::from_path("web/App.tsx"), Some(Language::Tsx));
+        assert_eq!(Language::from_path("README.md"), None);
+        assert_eq!(Language::from_path("Makefile"), None);
+
+        assert_eq!(Language::from_name("Python"), Some(Language::Python));
+        assert_eq!(Language::from_name("golang"), Some(Language::Go));
+        assert_eq!(Language::from_name("cobol"), None);
+    }
+
+    #[test]
+    fn every_grammar_loads() {
+        for language in [
+            Language::Rust,
+            Language::Swift,
+            Language::TypeScript,
+            Language::Tsx,
+            Language::JavaScript,
+            Language::Python,
+            Language::Go,
+            Language::Json,
+        ] {
+            assert!(language.parse("").is_some(), "{language:?} failed to load");
+        }
+    }
+}
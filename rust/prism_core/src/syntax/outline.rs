@@ -0,0 +1,556 @@
+//! Outlines of the functions, types, and impl blocks a source file declares.
+//!
+//! Declarations are collected from the top of the tree and from the bodies
+//! of types, impl blocks, and modules. Function bodies are not searched, so
+//! nested helpers count as part of the function that holds them. Each
+//! declaration is split into its signature, the text before its body, and
+//! the body itself, both with whitespace collapsed so that reformatting alone
+//! does not register as a change. Members are cut out of the body of the
+//! type that holds them, so an edited method does not also mark its type as
+//! edited.
+
+use std::ops::Range;
+
+use tree_sitter::Node;
+
+use super::Language;
+use crate::api::diff::SymbolKind;
+
+/// A declaration found in a source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    /// What kind of declaration this is.
+    pub kind: SymbolKind,
+    /// Declared name, or a header such as `impl Display for Token` for impl
+    /// blocks and extensions.
+    pub name: String,
+    /// Name qualified by the enclosing modules and types, e.g.
+    /// `Parser::parse`.
+    pub qualified_name: String,
+    /// Qualified name of the enclosing module or type, if any.
+    pub container: Option<String>,
+    /// Declaration text before the body.
+    pub signature: String,
+    /// Body text without member declarations.
+    pub body: String,
+    /// Whether the declaration is visible outside its module.
+    pub is_public: bool,
+    /// One-based line the declaration starts on.
+    pub line: u32,
+    /// One-based line the declaration ends on.
+    pub end_line: u32,
+}
+
+/// List the declarations in `source`, in file order. Returns `None` when the
+/// grammar fails to produce a tree or the language has no declarations to
+/// track.
+#[must_use]
+pub fn outline(language: Language, source: &str) -> Option<Vec<Symbol>> {
+    if language == Language::Json {
+        return None;
+    }
+    let tree = language.parse(source)?;
+    let mut outline = Outline {
+        language,
+        source,
+        symbols: Vec::new(),
+    };
+    let scope = Scope {
+        prefix: None,
+        public: language != Language::TypeScript
+            && language != Language::Tsx
+            && language != Language::JavaScript,
+        inherit: matches!(language, Language::Python | Language::Go),
+    };
+    outline.collect(tree.root_node(), &scope);
+    Some(outline.symbols)
+}
+
+/// Where declarations are found, and how visibility carries into them.
+#[derive(Debug, Clone)]
+struct Scope {
+    /// Qualified name of the enclosing module or type.
+    prefix: Option<String>,
+    /// Whether declarations here can be visible outside the module at all.
+    public: bool,
+    /// Whether declarations here are public unless marked otherwise, as for
+    /// trait and protocol members or exported TypeScript declarations.
+    inherit: bool,
+}
+
+impl Scope {
+    fn nested(&self, name: &str, separator: &str, public: bool, inherit: bool) -> Self {
+        Self {
+            prefix: Some(self.qualify(name, separator)),
+            public,
+            inherit,
+        }
+    }
+
+    fn qualify(&self, name: &str, separator: &str) -> String {
+        self.prefix.as_ref().map_or_else(
+            || name.to_owned(),
+            |prefix| format!("{prefix}{separator}{name}"),
+        )
+    }
+}
+
+/// A declaration recognized by one of the language rules.
+struct Declaration<'tree> {
+    kind: SymbolKind,
+    name: String,
+    is_public: bool,
+    body: Option<Node<'tree>>,
+    /// Container to report instead of the scope's, for Go methods.
+    container: Option<String>,
+    /// Scope for member declarations, when the body holds any.
+    members: Option<Scope>,
+}
+
+struct Outline<'source> {
+    language: Language,
+    source: &'source str,
+    symbols: Vec<Symbol>,
+}
+
+impl Outline<'_> {
+    /// Record the declarations among the children of `node` and return the
+    /// byte ranges they cover.
+    fn collect(&mut self, node: Node<'_>, scope: &Scope) -> Vec<Range<usize>> {
+        let mut covered = Vec::new();
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            if let Some(declaration) = self.declaration(child, scope) {
+                self.push(child, declaration, scope);
+                covered.push(child.byte_range());
+            } else if let Some((inner, inner_scope)) = self.wrapper(child, scope) {
+                let inner_covered = self.collect(inner, &inner_scope);
+                if !inner_covered.is_empty() {
+                    covered.push(child.byte_range());
+                }
+            }
+        }
+        covered
+    }
+
+    fn push(&mut self, node: Node<'_>, declaration: Declaration<'_>, scope: &Scope) {
+        let end = declaration
+            .body
+            .map_or_else(|| node.end_byte(), |body| body.start_byte());
+        let container = declaration.container.or_else(|| scope.prefix.clone());
+        let qualified_name = container.as_ref().map_or_else(
+            || declaration.name.clone(),
+            |container| format!("{container}{}{}", self.separator(), declaration.name),
+        );
+        let index = self.symbols.len();
+        self.symbols.push(Symbol {
+            kind: declaration.kind,
+            name: declaration.name,
+            qualified_name,
+            container,
+            signature: collapse(&self.source[node.start_byte()..end]),
+            body: String::new(),
+            is_public: declaration.is_public,
+            line: u32::try_from(node.start_position().row + 1).unwrap_or(u32::MAX),
+            end_line: u32::try_from(node.end_position().row + 1).unwrap_or(u32::MAX),
+        });
+
+        let Some(body) = declaration.body else {
+            return;
+        };
+        let covered = declaration
+            .members
+            .map(|members| self.collect(body, &members))
+            .unwrap_or_default();
+        let mut text = String::new();
+        let mut start = body.start_byte();
+        for range in covered {
+            text.push_str(&self.source[start..range.start]);
+            text.push(' ');
+            start = range.end;
+        }
+        text.push_str(&self.source[start..body.end_byte()]);
+        self.symbols[index].body = collapse(&text);
+    }
+
+    const fn separator(&self) -> &'static str {
+        match self.language {
+            Language::Rust => "::",
+            _ => ".",
+        }
+    }
+
+    fn text(&self, node: Node<'_>) -> String {
+        collapse(&self.source[node.byte_range()])
+    }
+
+    fn field(&self, node: Node<'_>, field: &str) -> Option<String> {
+        node.child_by_field_name(field)
+            .map(|child| self.text(child))
+    }
+
+    /// Nodes whose children are searched for declarations in place of the
+    /// node itself: export statements, decorators, modules, namespaces, and
+    /// regions the parser could not make sense of.
+    fn wrapper<'tree>(&self, node: Node<'tree>, scope: &Scope) -> Option<(Node<'tree>, Scope)> {
+        let module = |public: bool| {
+            let name = self.field(node, "name")?;
+            let body = node.child_by_field_name("body")?;
+            Some((body, scope.nested(&name, self.separator(), public, false)))
+        };
+        match (self.language, node.kind()) {
+            (Language::Rust, "mod_item") => {
+                module(scope.public && self.has_child(node, "visibility_modifier", is_pub))
+            }
+            (
+                Language::TypeScript | Language::Tsx | Language::JavaScript,
+                "lexical_declaration" | "variable_declaration" | "ambient_declaration",
+            )
+            | (Language::Python, "decorated_definition")
+            | (Language::Go, "type_declaration")
+            | (_, "ERROR") => Some((node, scope.clone())),
+            (Language::TypeScript | Language::Tsx | Language::JavaScript, "export_statement") => {
+                let exported = Scope {
+                    public: scope.prefix.is_none() || scope.public,
+                    inherit: true,
+                    ..scope.clone()
+                };
+                Some((node, exported))
+            }
+            (Language::TypeScript | Language::Tsx, "internal_module" | "module") => {
+                module(scope.public && scope.inherit)
+            }
+            _ => None,
+        }
+    }
+
+    fn declaration<'tree>(&self, node: Node<'tree>, scope: &Scope) -> Option<Declaration<'tree>> {
+        match self.language {
+            Language::Rust => self.rust(node, scope),
+            Language::Swift => self.swift(node, scope),
+            Language::TypeScript | Language::Tsx | Language::JavaScript => self.script(node, scope),
+            Language::Python => self.python(node, scope),
+            Language::Go => self.go(node, scope),
+            Language::Json => None,
+        }
+    }
+
+    fn rust<'tree>(&self, node: Node<'tree>, scope: &Scope) -> Option<Declaration<'tree>> {
+        let is_public =
+            scope.public && (scope.inherit || self.has_child(node, "visibility_modifier", is_pub));
+        let (kind, name, members) = match node.kind() {
+            "function_item" | "function_signature_item" => {
+                (SymbolKind::Function, self.field(node, "name")?, None)
+            }
+            "struct_item" | "enum_item" | "union_item" | "type_item" => {
+                (SymbolKind::Type, self.field(node, "name")?, None)
+            }
+            "trait_item" => {
+                let name = self.field(node, "name")?;
+                let members = scope.nested(&name, "::", is_public, true);
+                (SymbolKind::Type, name, Some(members))
+            }
+            "impl_item" => {
+                let self_type = self.field(node, "type")?;
+                match self.field(node, "trait") {
+                    Some(trait_name) => (
+                        SymbolKind::Impl,
+                        format!("impl {trait_name} for {self_type}"),
+                        Some(Scope {
+                            prefix: Some(format!("<{self_type} as {trait_name}>")),
+                            public: scope.public,
+                            inherit: true,
+                        }),
+                    ),
+                    None => (
+                        SymbolKind::Impl,
+                        format!("impl {self_type}"),
+                        Some(Scope {
+                            prefix: Some(self_type),
+                            public: scope.public,
+                            inherit: false,
+                        }),
+                    ),
+                }
+            }
+            _ => return None,
+        };
+        Some(Declaration {
+            kind,
+            name,
+            is_public,
+            body: node.child_by_field_name("body"),
+            container: None,
+            members,
+        })
+    }
+
+    fn swift<'tree>(&self, node: Node<'tree>, scope: &Scope) -> Option<Declaration<'tree>> {
+        let marked_public = self.has_child(node, "modifiers", |modifiers| {
+            modifiers
+                .split_whitespace()
+                .any(|word| word == "public" || word == "open" || word.starts_with("public("))
+        });
+        let is_public = scope.public && (scope.inherit || marked_public);
+        let (kind, name, members) = match node.kind() {
+            "function_declaration" | "protocol_function_declaration" => {
+                (SymbolKind::Function, self.field(node, "name")?, None)
+            }
+            "init_declaration" => (SymbolKind::Function, "init".to_owned(), None),
+            "deinit_declaration" => (SymbolKind::Function, "deinit".to_owned(), None),
+            "typealias_declaration" => (SymbolKind::Type, self.field(node, "name")?, None),
+            "protocol_declaration" => {
+                let name = self.field(node, "name")?;
+                let members = scope.nested(&name, ".", is_public, true);
+                (SymbolKind::Type, name, Some(members))
+            }
+            "class_declaration" => {
+                let name = self.field(node, "name")?;
+                let is_extension = node
+                    .child_by_field_name("declaration_kind")
+                    .or_else(|| node.child(0).filter(|child| !child.is_named()))
+                    .is_some_and(|keyword| keyword.kind() == "extension");
+                if is_extension {
+                    // A public extension makes its members public by default.
+                    let members = scope.nested(&name, ".", scope.public, marked_public);
+                    (SymbolKind::Impl, format!("extension {name}"), Some(members))
+                } else {
+                    let members = scope.nested(&name, ".", is_public, false);
+                    (SymbolKind::Type, name, Some(members))
+                }
+            }
+            _ => return None,
+        };
+        Some(Declaration {
+            kind,
+            name,
+            is_public,
+            body: node.child_by_field_name("body"),
+            container: None,
+            members,
+        })
+    }
+
+    fn script<'tree>(&self, node: Node<'tree>, scope: &Scope) -> Option<Declaration<'tree>> {
+        let private = self.has_child(node, "accessibility_modifier", |modifier| {
+            modifier == "private" || modifier == "protected"
+        }) || node
+            .child_by_field_name("name")
+            .is_some_and(|name| name.kind() == "private_property_identifier");
+        let is_public = scope.public && scope.inherit && !private;
+        let mut body = node.child_by_field_name("body");
+        let (kind, name, members) = match node.kind() {
+            "function_declaration"
+            | "generator_function_declaration"
+            | "function_signature"
+            | "method_definition"
+            | "method_signature"
+            | "abstract_method_signature" => {
+                (SymbolKind::Function, self.field(node, "name")?, None)
+            }
+            "class_declaration" | "abstract_class_declaration" | "interface_declaration" => {
+                let name = self.field(node, "name")?;
+                let members = scope.nested(&name, ".", is_public, true);
+                (SymbolKind::Type, name, Some(members))
+            }
+            "type_alias_declaration" | "enum_declaration" => {
+                (SymbolKind::Type, self.field(node, "name")?, None)
+            }
+            "variable_declarator" => {
+                let value = node.child_by_field_name("value")?;
+                if !matches!(
+                    value.kind(),
+                    "arrow_function" | "function_expression" | "function" | "generator_function"
+                ) {
+                    return None;
+                }
+                body = value.child_by_field_name("body");
+                (SymbolKind::Function, self.field(node, "name")?, None)
+            }
+            _ => return None,
+        };
+        Some(Declaration {
+            kind,
+            name,
+            is_public,
+            body,
+            container: None,
+            members,
+        })
+    }
+
+    fn python<'tree>(&self, node: Node<'tree>, scope: &Scope) -> Option<Declaration<'tree>> {
+        let name = self.field(node, "name")?;
+        let is_dunder = name.starts_with("__") && name.ends_with("__");
+        let is_public = scope.public && (is_dunder || !name.starts_with('_'));
+        let (kind, members) = match node.kind() {
+            "function_definition" => (SymbolKind::Function, None),
+            "class_definition" => {
+                let members = scope.nested(&name, ".", is_public, true);
+                (SymbolKind::Type, Some(members))
+            }
+            _ => return None,
+        };
+        Some(Declaration {
+            kind,
+            name,
+            is_public,
+            body: node.child_by_field_name("body"),
+            container: None,
+            members,
+        })
+    }
+
+    fn go<'tree>(&self, node: Node<'tree>, scope: &Scope) -> Option<Declaration<'tree>> {
+        let (kind, body, container) = match node.kind() {
+            "function_declaration" => {
+                (SymbolKind::Function, node.child_by_field_name("body"), None)
+            }
+            "method_declaration" => {
+                let receiver = node.child_by_field_name("receiver")?;
+                let receiver_type = find_descendant(receiver, "type_identifier")?;
+                let container = scope.qualify(&self.text(receiver_type), ".");
+                (
+                    SymbolKind::Function,
+                    node.child_by_field_name("body"),
+                    Some(container),
+                )
+            }
+            "type_spec" => (SymbolKind::Type, node.child_by_field_name("type"), None),
+            "type_alias" => (SymbolKind::Type, None, None),
+            _ => return None,
+        };
+        let name = self.field(node, "name")?;
+        let is_public = scope.public && name.starts_with(|ch: char| ch.is_uppercase());
+        Some(Declaration {
+            kind,
+            name,
+            is_public,
+            body,
+            container,
+            members: None,
+        })
+    }
+
+    /// Whether `node` has a direct child of `kind` whose text satisfies
+    /// `accept`.
+    fn has_child(&self, node: Node<'_>, kind: &str, accept: impl Fn(&str) -> bool) -> bool {
+        let mut cursor = node.walk();
+        let found = node
+            .named_children(&mut cursor)
+            .any(|child| child.kind() == kind && accept(&self.text(child)));
+        found
+    }
+}
+
+fn is_pub(visibility: &str) -> bool {
+    visibility == "pub"
+}
+
+fn find_descendant<'tree>(node: Node<'tree>, kind: &str) -> Option<Node<'tree>> {
+    if node.kind() == kind {
+        return Some(node);
+    }
+    let mut cursor = node.walk();
+    let found = node
+        .named_children(&mut cursor)
+        .find_map(|child| find_descendant(child, kind));
+    found
+}
+
+/// Collapse runs of whitespace to single spaces.
+fn collapse(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(language: Language, source: &str) -> Vec<(SymbolKind, String, bool)> {
+        outline(language, source)
+            .expect("outline")
+            .into_iter()
+            .map(|symbol| (symbol.kind, symbol.qualified_name, symbol.is_public))
+            .collect()
+    }
+
+    #[test]
+    fn outlines_rust_items_and_impl_members() {
+        let source = "pub struct Token { kind: u8 }\n\
+            impl Token {\n    pub fn new() -> Self { Self { kind: 0 } }\n    fn helper(&self) {}\n}\n\
+            impl Display for Token {\n    fn fmt(&self) -> u8 { 1 }\n}\n\
+            mod inner { pub fn hidden() {} }\n";
+        assert_eq!(
+            names(Language::Rust, source),
+            vec![
+                (SymbolKind::Type, "Token".into(), true),
+                (SymbolKind::Impl, "impl Token".into(), false),
+                (SymbolKind::Function, "Token::new".into(), true),
+                (SymbolKind::Function, "Token::helper".into(), false),
+                (SymbolKind::Impl, "impl Display for Token".into(), false),
+                (SymbolKind::Function, "<Token as Display>::fmt".into(), true),
+                (SymbolKind::Function, "inner::hidden".into(), false),
+            ]
+        );
+
+        let symbols = outline(Language::Rust, source).expect("outline");
+        assert_eq!(symbols[1].body, "{ }");
+        assert_eq!(symbols[2].signature, "pub fn new() -> Self");
+        assert_eq!(symbols[2].line, 3);
+    }
+
+    #[test]
+    fn outlines_typescript_python_swift_and_go() {
+        assert_eq!(
+            names(
+                Language::TypeScript,
+                "export class Store { load(): void {} private reset() {} }\n\
+                 function local() {}\nexport const pick = (a: number) => a;\n"
+            ),
+            vec![
+                (SymbolKind::Type, "Store".into(), true),
+                (SymbolKind::Function, "Store.load".into(), true),
+                (SymbolKind::Function, "Store.reset".into(), false),
+                (SymbolKind::Function, "local".into(), false),
+                (SymbolKind::Function, "pick".into(), true),
+            ]
+        );
+        assert_eq!(
+            names(
+                Language::Python,
+                "class Store:\n    @property\n    def _cache(self):\n        pass\n\n    def __init__(self):\n        pass\n"
+            ),
+            vec![
+                (SymbolKind::Type, "Store".into(), true),
+                (SymbolKind::Function, "Store._cache".into(), false),
+                (SymbolKind::Function, "Store.__init__".into(), true),
+            ]
+        );
+        assert_eq!(
+            names(
+                Language::Swift,
+                "public struct Store {\n    public func load() {}\n    func reset() {}\n}\n\
+                 extension Store {\n    func extra() {}\n}\n"
+            ),
+            vec![
+                (SymbolKind::Type, "Store".into(), true),
+                (SymbolKind::Function, "Store.load".into(), true),
+                (SymbolKind::Function, "Store.reset".into(), false),
+                (SymbolKind::Impl, "extension Store".into(), false),
+                (SymbolKind::Function, "Store.extra".into(), false),
+            ]
+        );
+        assert_eq!(
+            names(
+                Language::Go,
+                "package store\n\ntype Store struct{}\n\nfunc (s *Store) Load() {}\n\nfunc reset() {}\n"
+            ),
+            vec![
+                (SymbolKind::Type, "Store".into(), true),
+                (SymbolKind::Function, "Store.Load".into(), true),
+                (SymbolKind::Function, "reset".into(), false),
+            ]
+        );
+    }
+}
@@ -4,7 +4,16 @@ use std::os::unix::fs::symlink;
 use std::path::Path;
 
 use git2::{IndexAddOption, Repository as GitRepository};
-use prism_core::{api::diff::FileStatus, diff::DiffEngine, repository::Repository, Error, Result};
+use prism_core::{
+    api::diff::{
+        CommitPairStatus, DiffLineKind, DiffRequest, FileMode, FileStatus, FileVersion,
+        PatchSelection,
+    },
+    api::repository::{RevisionKind, RevisionRange},
+    diff::{render_patch, DiffEngine},
+    repository::Repository,
+    Error, Result,
+};
 use tempfile::TempDir;
 
 #[test]
@@ -29,7 +38,7 @@ fn diff_engine_reports_mixed_changes() -> Result<()> {
     commit(&git_repo, "Mixed changes")?;
 
     let repository = Repository::open(temp.path())?;
-    let diff = DiffEngine::new().diff(&repository)?;
+    let diff = DiffEngine::new().diff(&repository, &DiffRequest::default())?;
 
     assert!(diff.range.base.is_some());
     assert_eq!(diff.files.len(), 4);
@@ -66,7 +75,7 @@ fn diff_engine_errors_without_head_commit() {
     GitRepository::init(temp.path()).expect("init repo");
 
     let repository = Repository::open(temp.path()).expect("open repo");
-    let result = DiffEngine::new().diff(&repository);
+    let result = DiffEngine::new().diff(&repository, &DiffRequest::default());
     assert!(matches!(result, Err(Error::MissingHeadRevision)));
 }
 
@@ -84,7 +93,7 @@ fn diff_engine_detects_copied_file() -> Result<()> {
     stage_and_commit(&git_repo, "Add copied file")?;
 
     let repository = Repository::open(temp.path())?;
-    let diff = DiffEngine::new().diff(&repository)?;
+    let diff = DiffEngine::new().diff(&repository, &DiffRequest::default())?;
 
     let copied = diff
         .files
@@ -97,6 +106,37 @@ fn diff_engine_detects_copied_file() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn diff_engine_skips_copy_detection_when_disabled() -> Result<()> {
+    let temp = TempDir::new().expect("tempdir");
+    let git_repo = GitRepository::init(temp.path())?;
+
+    write_text(temp.path().join("original.txt"), "hello\n");
+    stage_and_commit(&git_repo, "Initial commit")?;
+
+    std::fs::copy(
+        temp.path().join("original.txt"),
+        temp.path().join("copy.txt"),
+    )
+    .expect("copy file");
+    stage_and_commit(&git_repo, "Add copied file")?;
+
+    let repository = Repository::open(temp.path())?;
+    let request = DiffRequest {
+        detect_copies: false,
+        ..DiffRequest::default()
+    };
+    let diff = DiffEngine::new().diff(&repository, &request)?;
+
+    assert_eq!(diff.files.len(), 1);
+    let added = &diff.files[0];
+    assert_eq!(added.path, "copy.txt");
+    assert_eq!(added.status, FileStatus::Added);
+    assert!(added.old_path.is_none());
+
+    Ok(())
+}
+
 #[cfg(unix)]
 #[test]
 fn diff_engine_flags_type_change() -> Result<()> {
@@ -112,7 +152,7 @@ fn diff_engine_flags_type_change() -> Result<()> {
     stage_and_commit(&git_repo, "Convert to symlink")?;
 
     let repository = Repository::open(temp.path())?;
-    let diff = DiffEngine::new().diff(&repository)?;
+    let diff = DiffEngine::new().diff(&repository, &DiffRequest::default())?;
 
     let entry = diff
         .files
@@ -120,6 +160,62 @@ fn diff_engine_flags_type_change() -> Result<()> {
         .find(|file| file.path == "node")
         .expect("typechange diff");
     assert_eq!(entry.status, FileStatus::TypeChange);
+    assert_eq!(entry.old_mode, Some(FileMode::Regular));
+    assert_eq!(entry.new_mode, Some(FileMode::Symlink));
+    let new_blob = entry.new_blob.as_ref().expect("symlink blob");
+    assert_eq!(new_blob.symlink_target.as_deref(), Some("target"));
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn diff_engine_reports_mode_only_changes() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp = TempDir::new().expect("tempdir");
+    let git_repo = GitRepository::init(temp.path())?;
+
+    let script = temp.path().join("run.sh");
+    write_text(&script, "echo hi\n");
+    stage_and_commit(&git_repo, "Add script")?;
+
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))
+        .expect("chmod script");
+    symlink("run.sh", temp.path().join("link")).expect("create symlink");
+
+    let repository = Repository::open(temp.path())?;
+    let engine = DiffEngine::new();
+    let workspace = engine.diff_workspace(&repository, &DiffRequest::default())?;
+
+    let entry = workspace
+        .files
+        .iter()
+        .find(|file| file.path == "run.sh")
+        .expect("mode change");
+    assert_eq!(entry.status, FileStatus::Modified);
+    assert!(entry.hunks.is_empty());
+    assert_eq!(entry.old_mode, Some(FileMode::Regular));
+    assert_eq!(entry.new_mode, Some(FileMode::Executable));
+
+    let link = workspace
+        .files
+        .iter()
+        .find(|file| file.path == "link")
+        .expect("untracked symlink");
+    assert_eq!(link.new_mode, Some(FileMode::Symlink));
+    let new_blob = link.new_blob.as_ref().expect("symlink blob");
+    assert_eq!(new_blob.symlink_target.as_deref(), Some("run.sh"));
+    assert_eq!(new_blob.size, 6);
+
+    stage_and_commit(&git_repo, "Make script executable")?;
+    let summary = engine.diff_summary(&repository, &DiffRequest::default())?;
+    let entry = summary
+        .files
+        .iter()
+        .find(|file| file.path == "run.sh")
+        .expect("mode change summary");
+    assert_eq!(entry.new_mode, Some(FileMode::Executable));
 
     Ok(())
 }
@@ -131,6 +227,383 @@ fn diff_engine_flags_type_change() {
     assert!(true);
 }
 
+#[test]
+fn diff_engine_reports_submodule_pointer_changes() -> Result<()> {
+    let temp = TempDir::new().expect("tempdir");
+    let git_repo = GitRepository::init(temp.path())?;
+    let sub_path = temp.path().join("sub");
+    let sub_repo = GitRepository::init(&sub_path)?;
+
+    write_text(sub_path.join("lib.txt"), "v1\n");
+    stage_and_commit(&sub_repo, "Submodule v1")?;
+    let first = sub_repo.head()?.peel_to_commit()?.id();
+    write_text(
+        temp.path().join(".gitmodules"),
+        "[submodule \"sub\"]\n\tpath = sub\n\turl = ./sub\n",
+    );
+    commit_gitlink(&git_repo, "sub", first, "Add submodule")?;
+
+    write_text(sub_path.join("lib.txt"), "v2\n");
+    stage_and_commit(&sub_repo, "Submodule v2")?;
+    let second = sub_repo.head()?.peel_to_commit()?.id();
+    commit_gitlink(&git_repo, "sub", second, "Bump submodule")?;
+
+    let repository = Repository::open(temp.path())?;
+    let engine = DiffEngine::new();
+    let request = DiffRequest {
+        submodule_log: true,
+        ..DiffRequest::default()
+    };
+    let diff = engine.diff(&repository, &request)?;
+
+    let entry = diff
+        .files
+        .iter()
+        .find(|file| file.path == "sub")
+        .expect("submodule diff");
+    assert_eq!(entry.status, FileStatus::Submodule);
+    assert!(entry.hunks.is_empty());
+    assert!(entry.new_blob.is_none());
+    let change = entry.submodule.as_ref().expect("submodule change");
+    assert_eq!(change.old_oid, Some(first.to_string()));
+    assert_eq!(change.new_oid, Some(second.to_string()));
+    assert!(!change.dirty);
+    let summaries: Vec<_> = change
+        .commits
+        .iter()
+        .map(|commit| commit.summary.as_deref())
+        .collect();
+    assert_eq!(summaries, vec![Some("Submodule v2")]);
+
+    write_text(sub_path.join("lib.txt"), "local edit\n");
+    let workspace = engine.diff_workspace(&repository, &DiffRequest::default())?;
+    let entry = workspace
+        .files
+        .iter()
+        .find(|file| file.path == "sub")
+        .expect("dirty submodule");
+    let change = entry.submodule.as_ref().expect("submodule change");
+    assert!(change.dirty);
+    assert_eq!(change.old_oid, change.new_oid);
+    assert!(change.commits.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn diff_engine_pairs_commits_across_rewritten_branch() -> Result<()> {
+    let temp = TempDir::new().expect("tempdir");
+    let git_repo = GitRepository::init(temp.path())?;
+
+    write_text(temp.path().join("README.md"), "base\n");
+    stage_and_commit(&git_repo, "Initial commit")?;
+    let repository = Repository::open(temp.path())?;
+    let base = repository.head_revision()?.expect("base");
+
+    write_text(temp.path().join("a.txt"), "one\ntwo\nthree\n");
+    stage_and_commit(&git_repo, "Add a")?;
+    write_text(temp.path().join("b.txt"), "alpha\nbeta\ngamma\ndelta\n");
+    stage_and_commit(&git_repo, "Add b")?;
+    write_text(temp.path().join("c.txt"), "scratch\n");
+    stage_and_commit(&git_repo, "Add c")?;
+    let old_head = repository.head_revision()?.expect("old head");
+
+    let base_commit = git_repo.find_object(git2::Oid::from_str(&base.oid)?, None)?;
+    git_repo.reset(&base_commit, git2::ResetType::Hard, None)?;
+    write_text(temp.path().join("a.txt"), "one\ntwo\nthree\n");
+    stage_and_commit(&git_repo, "Add a")?;
+    write_text(temp.path().join("d.txt"), "new\n");
+    stage_and_commit(&git_repo, "Add d")?;
+    write_text(temp.path().join("b.txt"), "alpha\nBETA\ngamma\ndelta\n");
+    stage_and_commit(&git_repo, "Add b")?;
+    let new_head = repository.head_revision()?.expect("new head");
+
+    let old_range = RevisionRange {
+        base: Some(base.clone()),
+        head: old_head,
+    };
+    let new_range = RevisionRange {
+        base: Some(base),
+        head: new_head,
+    };
+    let engine = DiffEngine::new();
+    let range_diff = engine.range_diff(
+        &repository,
+        old_range.clone(),
+        new_range.clone(),
+        &DiffRequest::default(),
+    )?;
+
+    let summaries: Vec<_> = range_diff
+        .pairs
+        .iter()
+        .map(|pair| {
+            let summary = pair
+                .new
+                .as_ref()
+                .or(pair.old.as_ref())
+                .and_then(|revision| revision.summary.as_deref());
+            (pair.status, summary)
+        })
+        .collect();
+    assert_eq!(
+        summaries,
+        vec![
+            (CommitPairStatus::Unchanged, Some("Add a")),
+            (CommitPairStatus::Added, Some("Add d")),
+            (CommitPairStatus::Modified, Some("Add b")),
+            (CommitPairStatus::Dropped, Some("Add c")),
+        ]
+    );
+
+    let modified = &range_diff.pairs[2];
+    assert_ne!(modified.old, modified.new);
+    let changed: Vec<_> = modified
+        .interdiff
+        .iter()
+        .flat_map(|hunk| &hunk.lines)
+        .filter(|line| line.kind != DiffLineKind::Context)
+        .map(|line| line.text.as_str())
+        .collect();
+    assert_eq!(changed, vec!["+beta", "+BETA"]);
+
+    // Presentation options do not change how commits are compared.
+    let decorated = DiffRequest {
+        syntax_highlighting: true,
+        structural_summary: true,
+        enclosing_symbols: true,
+        detect_moves: true,
+        max_line_chars: Some(2),
+        max_file_lines: Some(1),
+        ..DiffRequest::default()
+    };
+    assert_eq!(
+        engine.range_diff(&repository, old_range, new_range, &decorated)?,
+        range_diff
+    );
+
+    Ok(())
+}
+
+#[test]
+fn exported_patch_applies_to_base_revision() -> Result<()> {
+    let temp = TempDir::new().expect("tempdir");
+    let git_repo = GitRepository::init(temp.path())?;
+
+    write_bytes(temp.path().join("crlf.txt"), b"one\r\ntwo\r\nthree\r\n");
+    write_bytes(temp.path().join("tail.txt"), b"alpha\nbeta\n");
+    write_bytes(temp.path().join("latin.txt"), b"caf\xe9\nna\xefve\n");
+    write_bytes(temp.path().join("remove.txt"), b"bye\n");
+    write_bytes(temp.path().join("rename.txt"), b"a\nb\nc\nd\ne\nf\n");
+    stage_and_commit(&git_repo, "Initial commit")?;
+    let base = git_repo.head()?.peel_to_commit()?;
+
+    let head_contents: [(&str, &[u8]); 5] = [
+        ("crlf.txt", b"one\r\nTWO\r\nthree\r\n"),
+        ("tail.txt", b"alpha\nbeta\ngamma"),
+        ("latin.txt", b"caf\xe9\nna\xefve r\xe9sum\xe9\n"),
+        ("renamed.txt", b"a\nb\nc\nd\ne\nf\ng\n"),
+        ("added.txt", b"brand new\n"),
+    ];
+    for (path, contents) in head_contents {
+        write_bytes(temp.path().join(path), contents);
+    }
+    remove_path(temp.path().join("rename.txt"))?;
+    remove_path(temp.path().join("remove.txt"))?;
+    stage_all(&git_repo, &["remove.txt", "rename.txt"])?;
+    commit(&git_repo, "Mixed changes")?;
+
+    let repository = Repository::open(temp.path())?;
+    let diff = DiffEngine::new().diff(&repository, &DiffRequest::default())?;
+    let patch = render_patch(&diff, None)?;
+
+    git_repo.reset(base.as_object(), git2::ResetType::Hard, None)?;
+    let parsed = git2::Diff::from_buffer(&patch)?;
+    git_repo.apply(&parsed, git2::ApplyLocation::WorkDir, None)?;
+
+    for (path, contents) in head_contents {
+        let applied = std::fs::read(temp.path().join(path)).expect("read applied file");
+        assert_eq!(applied, contents, "{path}");
+    }
+    assert!(!temp.path().join("rename.txt").exists());
+    assert!(!temp.path().join("remove.txt").exists());
+
+    let selection = [PatchSelection {
+        path: "added.txt".into(),
+        hunks: None,
+    }];
+    let patch = String::from_utf8(render_patch(&diff, Some(&selection))?).expect("utf-8 patch");
+    assert!(patch.starts_with("diff --git a/added.txt b/added.txt\nnew file mode 100644\n"));
+    assert!(!patch.contains("crlf.txt"));
+
+    Ok(())
+}
+
+#[test]
+fn diff_engine_parses_format_patch_messages() -> Result<()> {
+    let temp = TempDir::new().expect("tempdir");
+    let git_repo = GitRepository::init(temp.path())?;
+    write_text(temp.path().join("a.txt"), "one\ntwo\n");
+    stage_and_commit(&git_repo, "Initial commit")?;
+    let repository = Repository::open(temp.path())?;
+
+    let old_oid = git2::Oid::hash_object(git2::ObjectType::Blob, b"one\ntwo\n")?.to_string();
+    let new_oid = git2::Oid::hash_object(git2::ObjectType::Blob, b"one\nTWO\n")?.to_string();
+    let message = format!(
+        "From 0123456789abcdef0123456789abcdef01234567 Mon Sep 17 00:00:00 2001\n\
+         From: Ada Lovelace <ada@example.com>\n\
+         Date: Tue, 4 Jun 2024 10:20:30 +0200\n\
+         Subject: [PATCH] Shout the second line\n\
+         \n\
+         ---\n a.txt | 2 +-\n\
+         \n\
+         diff --git a/a.txt b/a.txt\n\
+         index {}..{} 100644\n\
+         --- a/a.txt\n\
+         +++ b/a.txt\n\
+         @@ -1,2 +1,2 @@\n one\n-two\n+TWO\n\
+         -- \n2.43.0\n",
+        &old_oid[..7],
+        &new_oid[..7]
+    );
+
+    let engine = DiffEngine::new();
+    let diff = engine.diff_patch(
+        &repository,
+        message.as_bytes(),
+        true,
+        &DiffRequest::default(),
+    )?;
+    assert_eq!(diff.range.base, repository.head_revision()?);
+    assert_eq!(
+        diff.range.head.summary.as_deref(),
+        Some("Shout the second line")
+    );
+    assert_eq!(
+        diff.range
+            .head
+            .author
+            .as_ref()
+            .map(|author| author.name.as_str()),
+        Some("Ada Lovelace")
+    );
+    assert_eq!(diff.files.len(), 1);
+    let file = &diff.files[0];
+    assert_eq!(file.status, FileStatus::Modified);
+    assert_eq!(
+        file.old_blob.as_ref().map(|blob| blob.oid.as_str()),
+        Some(old_oid.as_str())
+    );
+    // Checking the patch stored its result, so the new side resolves too.
+    assert_eq!(
+        file.new_blob.as_ref().map(|blob| blob.oid.as_str()),
+        Some(new_oid.as_str())
+    );
+    let changed: Vec<_> = file.hunks[0]
+        .lines
+        .iter()
+        .map(|line| (line.kind, line.text.as_str()))
+        .collect();
+    assert_eq!(
+        changed,
+        vec![
+            (DiffLineKind::Context, "one"),
+            (DiffLineKind::Deletion, "two"),
+            (DiffLineKind::Addition, "TWO"),
+        ]
+    );
+
+    let plain = b"--- /dev/null\n+++ b/new.txt\n@@ -0,0 +1 @@\n+hello\n";
+    let diff = engine.diff_patch(&repository, plain, true, &DiffRequest::default())?;
+    assert_eq!(diff.range.head.oid, git2::Oid::zero().to_string());
+    assert_eq!(diff.files.len(), 1);
+    assert_eq!(diff.files[0].path, "new.txt");
+    assert_eq!(diff.files[0].status, FileStatus::Added);
+
+    let stale = b"--- a/a.txt\n+++ b/a.txt\n@@ -1,2 +1,2 @@\n one\n-three\n+four\n";
+    let result = engine.diff_patch(&repository, stale, true, &DiffRequest::default());
+    assert!(matches!(result, Err(Error::PatchDoesNotApply { .. })));
+    let unchecked = engine.diff_patch(&repository, stale, false, &DiffRequest::default())?;
+    assert_eq!(unchecked.files[0].stats.additions, 1);
+
+    Ok(())
+}
+
+#[test]
+fn staged_and_unstaged_diffs_resolve_follow_up_requests() -> Result<()> {
+    let temp = TempDir::new().expect("tempdir");
+    let git_repo = GitRepository::init(temp.path())?;
+    write_text(temp.path().join("a.txt"), "one\n");
+    stage_and_commit(&git_repo, "Initial commit")?;
+
+    write_text(temp.path().join("a.txt"), "one\ntwo\n");
+    stage_all(&git_repo, &[])?;
+    write_text(temp.path().join("a.txt"), "one\ntwo\nthree\n");
+
+    let repository = Repository::open(temp.path())?;
+    let engine = DiffEngine::new();
+    let request = DiffRequest::default();
+    let added_lines = |range: &RevisionRange| -> Result<Vec<String>> {
+        let hunks = engine.load_file_hunks(&repository, range, false, "a.txt", None, &request)?;
+        Ok(hunks.hunks[0]
+            .lines
+            .iter()
+            .filter(|line| line.kind == DiffLineKind::Addition)
+            .map(|line| line.text.clone())
+            .collect())
+    };
+    let text = |range: &RevisionRange, version| -> Result<Option<String>> {
+        Ok(repository
+            .file_contents(range, "a.txt", version)?
+            .and_then(|contents| contents.text))
+    };
+
+    let staged = engine.diff_index(&repository, &request)?;
+    assert_eq!(
+        staged.range.base.as_ref().map(|base| base.kind),
+        Some(RevisionKind::Commit)
+    );
+    assert_eq!(staged.range.head.kind, RevisionKind::Index);
+    assert_eq!(added_lines(&staged.range)?, vec!["two"]);
+    assert_eq!(
+        text(&staged.range, FileVersion::Base)?.as_deref(),
+        Some("one\n")
+    );
+    assert_eq!(
+        text(&staged.range, FileVersion::Head)?.as_deref(),
+        Some("one\ntwo\n")
+    );
+
+    let unstaged = engine.diff_unstaged(&repository, &request)?;
+    assert_eq!(unstaged.range.base.as_ref(), Some(&staged.range.head));
+    assert_eq!(unstaged.range.head.kind, RevisionKind::Workdir);
+    assert_eq!(added_lines(&unstaged.range)?, vec!["three"]);
+    // The diff is taken against the snapshot its head names.
+    assert_eq!(
+        unstaged.files[0]
+            .new_blob
+            .as_ref()
+            .map(|blob| blob.oid.clone()),
+        repository
+            .file_contents(&unstaged.range, "a.txt", FileVersion::Head)?
+            .map(|contents| contents.oid)
+    );
+    assert_eq!(
+        text(&unstaged.range, FileVersion::Base)?.as_deref(),
+        Some("one\ntwo\n")
+    );
+
+    // The head names the snapshot taken for the diff, not the live file.
+    write_text(temp.path().join("a.txt"), "changed\n");
+    assert_eq!(added_lines(&unstaged.range)?, vec!["three"]);
+    assert_eq!(
+        text(&unstaged.range, FileVersion::Head)?.as_deref(),
+        Some("one\ntwo\nthree\n")
+    );
+
+    Ok(())
+}
+
 fn write_text(path: impl AsRef<Path>, contents: &str) {
     std::fs::write(path, contents).expect("write text file");
 }
@@ -145,6 +618,10 @@ fn rename_path(from: impl AsRef<Path>, to: impl AsRef<Path>) -> Result<()> {
     Ok(())
 }
 
+fn write_bytes(path: impl AsRef<Path>, contents: &[u8]) {
+    std::fs::write(path, contents).expect("write file");
+}
+
 fn remove_path(path: impl AsRef<Path>) -> Result<()> {
     let path_ref = path.as_ref();
     std::fs::remove_file(path_ref).map_err(|source| Error::Io {
@@ -154,6 +631,47 @@ fn remove_path(path: impl AsRef<Path>) -> Result<()> {
     Ok(())
 }
 
+/// Record `oid` as a submodule pointer at `path`, alongside `.gitmodules`.
+/// `add_all` refuses nested repositories, so the gitlink entry is staged by hand.
+fn commit_gitlink(repo: &GitRepository, path: &str, oid: git2::Oid, message: &str) -> Result<()> {
+    let mut index = repo.index()?;
+    index.add_path(Path::new(".gitmodules"))?;
+    index.add(&git2::IndexEntry {
+        ctime: git2::IndexTime::new(0, 0),
+        mtime: git2::IndexTime::new(0, 0),
+        dev: 0,
+        ino: 0,
+        mode: 0o160_000,
+        uid: 0,
+        gid: 0,
+        file_size: 0,
+        id: oid,
+        flags: 0,
+        flags_extended: 0,
+        path: path.as_bytes().to_vec(),
+    })?;
+    index.write()?;
+
+    let tree = repo.find_tree(index.write_tree()?)?;
+    let signature = git2::Signature::now("Test", "test@example.com")?;
+    let parents: Vec<git2::Commit> = repo
+        .head()
+        .ok()
+        .and_then(|head| head.peel_to_commit().ok())
+        .into_iter()
+        .collect();
+    let parent_refs: Vec<&git2::Commit> = parents.iter().collect();
+    repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        message,
+        &tree,
+        &parent_refs,
+    )?;
+    Ok(())
+}
+
 fn stage_and_commit(repo: &GitRepository, message: &str) -> Result<()> {
     stage_all(repo, &[])?;
     commit(repo, message)
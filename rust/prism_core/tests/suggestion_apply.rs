@@ -83,6 +83,34 @@ fn apply_fails_when_range_out_of_bounds_and_preserves_file() -> prism_core::Resu
     Ok(())
 }
 
+#[test]
+fn apply_preserves_original_file_encoding() -> prism_core::Result<()> {
+    let fixture = RepoFixture::new()?;
+    let utf16: Vec<u8> = std::iter::once(0xFEFF)
+        .chain("line 1\nline 2\n".encode_utf16())
+        .flat_map(u16::to_le_bytes)
+        .collect();
+    std::fs::write(fixture.path("file.txt"), &utf16).expect("write file");
+
+    let repository = Repository::open(fixture.root())?;
+    let applier = SuggestionApplier::new(&repository);
+    applier.apply(&line_replacement_suggestion())?;
+
+    let expected: Vec<u8> = std::iter::once(0xFEFF)
+        .chain("line 1\nline two\n".encode_utf16())
+        .flat_map(u16::to_le_bytes)
+        .collect();
+    let updated = std::fs::read(fixture.path("file.txt")).expect("read file");
+    assert_eq!(updated, expected);
+
+    std::fs::write(fixture.path("file.txt"), b"caf\xe9\nline 2\n").expect("write file");
+    applier.apply(&line_replacement_suggestion())?;
+    let updated = std::fs::read(fixture.path("file.txt")).expect("read file");
+    assert_eq!(updated, b"caf\xe9\nline two\n");
+
+    Ok(())
+}
+
 struct RepoFixture {
     dir: TempDir,
     git_repo: GitRepository,
@@ -129,10 +129,11 @@ pub struct SubmissionResult {
 }
 
 /// States reported when polling for revision progress.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum RevisionState {
     /// Work has been queued or is awaiting processing.
+    #[default]
     Pending,
     /// Work has started and is in progress.
     InProgress,
@@ -142,12 +143,6 @@ pub enum RevisionState {
     Failed,
 }
 
-impl Default for RevisionState {
-    fn default() -> Self {
-        Self::Pending
-    }
-}
-
 /// Poll result describing the latest revision state.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub struct RevisionProgress {
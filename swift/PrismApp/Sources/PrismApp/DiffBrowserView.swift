@@ -95,6 +95,8 @@ private struct DiffFileRow: View {
       return "doc.on.doc"
     case .typeChange:
       return "arrow.triangle.2.circlepath"
+    case .submodule:
+      return "shippingbox"
     }
   }
 
@@ -112,6 +114,8 @@ private struct DiffFileRow: View {
       return .purple
     case .typeChange:
       return .orange
+    case .submodule:
+      return .teal
     }
   }
 }
@@ -639,15 +643,50 @@ private struct QuickLabelButtonStyle: ButtonStyle {
   private enum DiffPreviewFactory {
     static func previewViewModel() -> DiffBrowserViewModel {
       let lines = [
-        DiffLine(kind: .context, text: " func greet() {", baseLine: UInt32(10), headLine: UInt32(10), highlights: []),
-        DiffLine(kind: .deletion, text: "  print(\"Hello\")", baseLine: UInt32(11), headLine: nil, highlights: []),
-        DiffLine(kind: .addition, text: "  print(\"Hello, Prism\")", baseLine: nil, headLine: UInt32(11), highlights: [])
+        DiffLine(
+          kind: .context,
+          text: " func greet() {",
+          baseLine: UInt32(10),
+          headLine: UInt32(10),
+          highlights: [],
+          tokens: [],
+          moved: nil,
+          truncated: false,
+          lineEnding: .lf,
+          enclosingSymbol: "greet()"
+        ),
+        DiffLine(
+          kind: .deletion,
+          text: "  print(\"Hello\")",
+          baseLine: UInt32(11),
+          headLine: nil,
+          highlights: [],
+          tokens: [],
+          moved: nil,
+          truncated: false,
+          lineEnding: .lf,
+          enclosingSymbol: "greet()"
+        ),
+        DiffLine(
+          kind: .addition,
+          text: "  print(\"Hello, Prism\")",
+          baseLine: nil,
+          headLine: UInt32(11),
+          highlights: [],
+          tokens: [],
+          moved: nil,
+          truncated: false,
+          lineEnding: .lf,
+          enclosingSymbol: "greet()"
+        )
       ]
 
       let hunk = DiffHunk(
         header: DiffRange(baseStart: UInt32(10), baseLines: UInt32(2), headStart: UInt32(10), headLines: UInt32(2)),
         section: "greet()",
-        lines: lines
+        lines: lines,
+        whitespaceOnly: false,
+        lineEndingsOnly: false
       )
 
       let file = DiffFile(
@@ -656,13 +695,25 @@ private struct QuickLabelButtonStyle: ButtonStyle {
         status: .modified,
         stats: DiffStats(additions: UInt32(1), deletions: UInt32(1)),
         isBinary: false,
+        oldMode: .regular,
+        newMode: .regular,
+        oldBlob: nil,
+        newBlob: nil,
+        submodule: nil,
+        classification: .source,
+        stage: nil,
+        truncated: false,
+        encoding: nil,
+        whitespaceOnly: false,
+        lineEndingsOnly: false,
+        structure: nil,
         hunks: [hunk]
       )
 
       let diff = Diff(
         range: RevisionRange(
-          base: Revision(oid: "BASE", reference: nil, summary: nil, author: nil, committer: nil, timestamp: nil),
-          head: Revision(oid: "HEAD", reference: nil, summary: nil, author: nil, committer: nil, timestamp: nil)
+          base: Revision(oid: "BASE", reference: nil, summary: nil, author: nil, committer: nil, timestamp: nil, kind: .commit),
+          head: Revision(oid: "HEAD", reference: nil, summary: nil, author: nil, committer: nil, timestamp: nil, kind: .commit)
         ),
         files: [file]
       )
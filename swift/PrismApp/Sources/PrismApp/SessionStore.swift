@@ -548,12 +548,20 @@ final class SessionStore: ObservableObject {
       case let .notARepository(message),
         let .bareRepository(message),
         let .missingHeadRevision(message),
+        let .missingDefaultBranch(message),
+        let .fileNotInDiff(message),
+        let .invalidPath(message),
+        let .iterationNotFound(message),
+        let .incompletePatch(message),
+        let .hunkNotInDiff(message),
+        let .patchDoesNotApply(message),
         let .git(message),
         let .io(message),
         let .unimplemented(message),
         let .internalError(message),
         let .pluginNotRegistered(message),
-        let .plugin(message):
+        let .plugin(message),
+        let .suggestion(message):
         return message
       }
     }
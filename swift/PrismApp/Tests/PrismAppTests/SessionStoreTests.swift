@@ -546,8 +546,8 @@ private final class MockSession: PrismSession {
   static func makeDiff(files: [DiffFile] = []) -> Diff {
     Diff(
       range: RevisionRange(
-        base: Revision(oid: "BASE", reference: nil, summary: nil, author: nil, committer: nil, timestamp: nil),
-        head: Revision(oid: "HEAD", reference: nil, summary: nil, author: nil, committer: nil, timestamp: nil)
+        base: Revision(oid: "BASE", reference: nil, summary: nil, author: nil, committer: nil, timestamp: nil, kind: .commit),
+        head: Revision(oid: "HEAD", reference: nil, summary: nil, author: nil, committer: nil, timestamp: nil, kind: .commit)
       ),
       files: files
     )
@@ -560,6 +560,18 @@ private final class MockSession: PrismSession {
       status: .modified,
       stats: DiffStats(additions: additions, deletions: deletions),
       isBinary: false,
+      oldMode: .regular,
+      newMode: .regular,
+      oldBlob: nil,
+      newBlob: nil,
+      submodule: nil,
+      classification: .source,
+      stage: nil,
+      truncated: false,
+      encoding: nil,
+      whitespaceOnly: false,
+      lineEndingsOnly: false,
+      structure: nil,
       hunks: []
     )
   }
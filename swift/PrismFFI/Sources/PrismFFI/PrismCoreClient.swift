@@ -4,12 +4,20 @@ public enum PrismCoreError: Error, Equatable {
     case notARepository(message: String)
     case bareRepository(message: String)
     case missingHeadRevision(message: String)
+    case missingDefaultBranch(message: String)
+    case fileNotInDiff(message: String)
+    case invalidPath(message: String)
+    case iterationNotFound(message: String)
+    case incompletePatch(message: String)
+    case hunkNotInDiff(message: String)
+    case patchDoesNotApply(message: String)
     case git(message: String)
     case io(message: String)
     case unimplemented(message: String)
     case internalError(message: String)
     case pluginNotRegistered(message: String)
     case plugin(message: String)
+    case suggestion(message: String)
 
     init(coreError: CoreError) {
         switch coreError {
@@ -19,6 +27,20 @@ public enum PrismCoreError: Error, Equatable {
             self = .bareRepository(message: message)
         case let .MissingHeadRevision(message):
             self = .missingHeadRevision(message: message)
+        case let .MissingDefaultBranch(message):
+            self = .missingDefaultBranch(message: message)
+        case let .FileNotInDiff(message):
+            self = .fileNotInDiff(message: message)
+        case let .InvalidPath(message):
+            self = .invalidPath(message: message)
+        case let .IterationNotFound(message):
+            self = .iterationNotFound(message: message)
+        case let .IncompletePatch(message):
+            self = .incompletePatch(message: message)
+        case let .HunkNotInDiff(message):
+            self = .hunkNotInDiff(message: message)
+        case let .PatchDoesNotApply(message):
+            self = .patchDoesNotApply(message: message)
         case let .Git(message):
             self = .git(message: message)
         case let .Io(message):
@@ -31,6 +53,8 @@ public enum PrismCoreError: Error, Equatable {
             self = .pluginNotRegistered(message: message)
         case let .Plugin(message):
             self = .plugin(message: message)
+        case let .Suggestion(message):
+            self = .suggestion(message: message)
         }
     }
 }
@@ -103,16 +127,152 @@ public final class PrismCoreSession {
         try await call { try self.coreSession.refresh() }
     }
 
-    public func diffHead() async throws -> Diff {
-        try await call { try self.coreSession.diffHead() }
+    public func diffHead(request: DiffRequest? = nil) async throws -> Diff {
+        try await call { try self.coreSession.diffHead(request: request) }
     }
 
-    public func diffWorkspace() async throws -> Diff {
-        try await call { try self.coreSession.diffWorkspace() }
+    public func diffWorkspace(request: DiffRequest? = nil) async throws -> Diff {
+        try await call { try self.coreSession.diffWorkspace(request: request) }
     }
 
-    public func diffForRange(_ range: RevisionRange) async throws -> Diff {
-        try await call { try self.coreSession.diffForRange(range: range) }
+    public func diffIndex(request: DiffRequest? = nil) async throws -> Diff {
+        try await call { try self.coreSession.diffIndex(request: request) }
+    }
+
+    public func diffUnstaged(request: DiffRequest? = nil) async throws -> Diff {
+        try await call { try self.coreSession.diffUnstaged(request: request) }
+    }
+
+    public func diffForRange(_ range: RevisionRange, request: DiffRequest? = nil) async throws -> Diff {
+        try await call { try self.coreSession.diffForRange(range: range, request: request) }
+    }
+
+    public func diffMergeBase(
+        target: String?,
+        includeWorkspace: Bool,
+        request: DiffRequest? = nil
+    ) async throws -> Diff {
+        try await call {
+            try self.coreSession.diffMergeBase(target: target, includeWorkspace: includeWorkspace, request: request)
+        }
+    }
+
+    public func diffCommits(
+        _ range: RevisionRange,
+        offset: UInt32,
+        limit: UInt32,
+        request: DiffRequest? = nil
+    ) async throws -> CommitDiffPage {
+        try await call {
+            try self.coreSession.diffCommits(range: range, offset: offset, limit: limit, request: request)
+        }
+    }
+
+    public func diffSummaryHead(request: DiffRequest? = nil) async throws -> DiffSummary {
+        try await call { try self.coreSession.diffSummaryHead(request: request) }
+    }
+
+    public func diffSummaryWorkspace(request: DiffRequest? = nil) async throws -> DiffSummary {
+        try await call { try self.coreSession.diffSummaryWorkspace(request: request) }
+    }
+
+    public func diffSummaryForRange(_ range: RevisionRange, request: DiffRequest? = nil) async throws -> DiffSummary {
+        try await call { try self.coreSession.diffSummaryForRange(range: range, request: request) }
+    }
+
+    public func loadFileHunks(
+        _ range: RevisionRange,
+        workspace: Bool,
+        path: String,
+        maxLines: UInt32? = nil,
+        request: DiffRequest? = nil
+    ) async throws -> FileHunks {
+        try await call {
+            try self.coreSession.loadFileHunks(
+                range: range, workspace: workspace, path: path, maxLines: maxLines, request: request)
+        }
+    }
+
+    public func loadSplitHunks(
+        _ range: RevisionRange,
+        workspace: Bool,
+        path: String,
+        request: DiffRequest? = nil
+    ) async throws -> [SplitHunk] {
+        try await call {
+            try self.coreSession.loadSplitHunks(range: range, workspace: workspace, path: path, request: request)
+        }
+    }
+
+    public func loadFileBlobs(
+        _ range: RevisionRange,
+        workspace: Bool,
+        path: String,
+        request: DiffRequest? = nil
+    ) async throws -> FileBlobs {
+        try await call {
+            try self.coreSession.loadFileBlobs(range: range, workspace: workspace, path: path, request: request)
+        }
+    }
+
+    public func expandContext(
+        _ range: RevisionRange,
+        workspace: Bool,
+        path: String,
+        side: DiffSide,
+        startLine: UInt32,
+        lineCount: UInt32,
+        request: DiffRequest? = nil
+    ) async throws -> [DiffLine] {
+        try await call {
+            try self.coreSession.expandContext(
+                range: range, workspace: workspace, path: path, side: side,
+                startLine: startLine, lineCount: lineCount, request: request)
+        }
+    }
+
+    public func fileContents(
+        _ range: RevisionRange,
+        path: String,
+        version: FileVersion
+    ) async throws -> FileContents? {
+        try await call { try self.coreSession.fileContents(range: range, path: path, version: version) }
+    }
+
+    public func splitHunks(_ hunks: [DiffHunk]) async throws -> [SplitHunk] {
+        try await call { self.coreSession.splitHunks(hunks: hunks) }
+    }
+
+    public func recordIteration(revision: String? = nil) async throws -> ReviewIteration {
+        try await call { try self.coreSession.recordIteration(revision: revision) }
+    }
+
+    public func reviewIterations() async throws -> [ReviewIteration] {
+        try await call { self.coreSession.reviewIterations() }
+    }
+
+    public func diffSinceIteration(_ number: UInt32? = nil, request: DiffRequest? = nil) async throws -> Diff {
+        try await call { try self.coreSession.diffSinceIteration(number: number, request: request) }
+    }
+
+    public func rangeDiff(
+        old oldRange: RevisionRange,
+        new newRange: RevisionRange,
+        request: DiffRequest? = nil
+    ) async throws -> RangeDiff {
+        try await call { try self.coreSession.rangeDiff(oldRange: oldRange, newRange: newRange, request: request) }
+    }
+
+    public func setClassificationRules(_ rules: [ClassificationRule]) async throws {
+        try await call { self.coreSession.setClassificationRules(rules: rules) }
+    }
+
+    public func exportPatch(_ diff: Diff, selection: [PatchSelection]? = nil) async throws -> Data {
+        try await call { try self.coreSession.exportPatch(diff: diff, selection: selection) }
+    }
+
+    public func diffPatch(_ patch: Data, checkApplies: Bool, request: DiffRequest? = nil) async throws -> Diff {
+        try await call { try self.coreSession.diffPatch(patch: patch, checkApplies: checkApplies, request: request) }
     }
 
     public func plugins() async throws -> [PluginSummary] {
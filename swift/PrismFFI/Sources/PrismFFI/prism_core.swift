@@ -8,10 +8,10 @@ import Foundation
 // might be in a separate module, or it might be compiled inline into
 // this module. This is a bit of light hackery to work with both.
 #if canImport(prism_coreFFI)
-    import prism_coreFFI
+import prism_coreFFI
 #endif
 
-private extension RustBuffer {
+fileprivate extension RustBuffer {
     // Allocate a new buffer, copying the contents of a `UInt8` array.
     init(bytes: [UInt8]) {
         let rbuf = bytes.withUnsafeBufferPointer { ptr in
@@ -21,7 +21,7 @@ private extension RustBuffer {
     }
 
     static func empty() -> RustBuffer {
-        RustBuffer(capacity: 0, len: 0, data: nil)
+        RustBuffer(capacity: 0, len:0, data: nil)
     }
 
     static func from(_ ptr: UnsafeBufferPointer<UInt8>) -> RustBuffer {
@@ -35,7 +35,7 @@ private extension RustBuffer {
     }
 }
 
-private extension ForeignBytes {
+fileprivate extension ForeignBytes {
     init(bufferPointer: UnsafeBufferPointer<UInt8>) {
         self.init(len: Int32(bufferPointer.count), data: bufferPointer.baseAddress)
     }
@@ -48,7 +48,7 @@ private extension ForeignBytes {
 // Helper classes/extensions that don't change.
 // Someday, this will be in a library of its own.
 
-private extension Data {
+fileprivate extension Data {
     init(rustBuffer: RustBuffer) {
         self.init(
             bytesNoCopy: rustBuffer.data!,
@@ -72,15 +72,15 @@ private extension Data {
 //
 // Instead, the read() method and these helper functions input a tuple of data
 
-private func createReader(data: Data) -> (data: Data, offset: Data.Index) {
+fileprivate func createReader(data: Data) -> (data: Data, offset: Data.Index) {
     (data: data, offset: 0)
 }
 
 // Reads an integer at the current offset, in big-endian order, and advances
 // the offset on success. Throws if reading the integer would move the
 // offset past the end of the buffer.
-private func readInt<T: FixedWidthInteger>(_ reader: inout (data: Data, offset: Data.Index)) throws -> T {
-    let range = reader.offset ..< reader.offset + MemoryLayout<T>.size
+fileprivate func readInt<T: FixedWidthInteger>(_ reader: inout (data: Data, offset: Data.Index)) throws -> T {
+    let range = reader.offset..<reader.offset + MemoryLayout<T>.size
     guard reader.data.count >= range.upperBound else {
         throw UniffiInternalError.bufferOverflow
     }
@@ -90,38 +90,38 @@ private func readInt<T: FixedWidthInteger>(_ reader: inout (data: Data, offset:
         return value as! T
     }
     var value: T = 0
-    let _ = withUnsafeMutableBytes(of: &value) { reader.data.copyBytes(to: $0, from: range) }
+    let _ = withUnsafeMutableBytes(of: &value, { reader.data.copyBytes(to: $0, from: range)})
     reader.offset = range.upperBound
     return value.bigEndian
 }
 
 // Reads an arbitrary number of bytes, to be used to read
 // raw bytes, this is useful when lifting strings
-private func readBytes(_ reader: inout (data: Data, offset: Data.Index), count: Int) throws -> [UInt8] {
-    let range = reader.offset ..< (reader.offset + count)
+fileprivate func readBytes(_ reader: inout (data: Data, offset: Data.Index), count: Int) throws -> Array<UInt8> {
+    let range = reader.offset..<(reader.offset+count)
     guard reader.data.count >= range.upperBound else {
         throw UniffiInternalError.bufferOverflow
     }
     var value = [UInt8](repeating: 0, count: count)
-    value.withUnsafeMutableBufferPointer { buffer in
+    value.withUnsafeMutableBufferPointer({ buffer in
         reader.data.copyBytes(to: buffer, from: range)
-    }
+    })
     reader.offset = range.upperBound
     return value
 }
 
 // Reads a float at the current offset.
-private func readFloat(_ reader: inout (data: Data, offset: Data.Index)) throws -> Float {
-    return try Float(bitPattern: readInt(&reader))
+fileprivate func readFloat(_ reader: inout (data: Data, offset: Data.Index)) throws -> Float {
+    return Float(bitPattern: try readInt(&reader))
 }
 
 // Reads a float at the current offset.
-private func readDouble(_ reader: inout (data: Data, offset: Data.Index)) throws -> Double {
-    return try Double(bitPattern: readInt(&reader))
+fileprivate func readDouble(_ reader: inout (data: Data, offset: Data.Index)) throws -> Double {
+    return Double(bitPattern: try readInt(&reader))
 }
 
 // Indicates if the offset has reached the end of the buffer.
-private func hasRemaining(_ reader: (data: Data, offset: Data.Index)) -> Bool {
+fileprivate func hasRemaining(_ reader: (data: Data, offset: Data.Index)) -> Bool {
     return reader.offset < reader.data.count
 }
 
@@ -129,11 +129,11 @@ private func hasRemaining(_ reader: (data: Data, offset: Data.Index)) -> Bool {
 // struct, but we use standalone functions instead in order to make external
 // types work.  See the above discussion on Readers for details.
 
-private func createWriter() -> [UInt8] {
+fileprivate func createWriter() -> [UInt8] {
     return []
 }
 
-private func writeBytes<S>(_ writer: inout [UInt8], _ byteArr: S) where S: Sequence, S.Element == UInt8 {
+fileprivate func writeBytes<S>(_ writer: inout [UInt8], _ byteArr: S) where S: Sequence, S.Element == UInt8 {
     writer.append(contentsOf: byteArr)
 }
 
@@ -141,22 +141,22 @@ private func writeBytes<S>(_ writer: inout [UInt8], _ byteArr: S) where S: Seque
 //
 // Warning: make sure what you are trying to write
 // is in the correct type!
-private func writeInt<T: FixedWidthInteger>(_ writer: inout [UInt8], _ value: T) {
+fileprivate func writeInt<T: FixedWidthInteger>(_ writer: inout [UInt8], _ value: T) {
     var value = value.bigEndian
     withUnsafeBytes(of: &value) { writer.append(contentsOf: $0) }
 }
 
-private func writeFloat(_ writer: inout [UInt8], _ value: Float) {
+fileprivate func writeFloat(_ writer: inout [UInt8], _ value: Float) {
     writeInt(&writer, value.bitPattern)
 }
 
-private func writeDouble(_ writer: inout [UInt8], _ value: Double) {
+fileprivate func writeDouble(_ writer: inout [UInt8], _ value: Double) {
     writeInt(&writer, value.bitPattern)
 }
 
 // Protocol for types that transfer other types across the FFI. This is
 // analogous to the Rust trait of the same name.
-private protocol FfiConverter {
+fileprivate protocol FfiConverter {
     associatedtype FfiType
     associatedtype SwiftType
 
@@ -167,19 +167,19 @@ private protocol FfiConverter {
 }
 
 // Types conforming to `Primitive` pass themselves directly over the FFI.
-private protocol FfiConverterPrimitive: FfiConverter where FfiType == SwiftType {}
+fileprivate protocol FfiConverterPrimitive: FfiConverter where FfiType == SwiftType { }
 
 extension FfiConverterPrimitive {
-    #if swift(>=5.8)
-        @_documentation(visibility: private)
-    #endif
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
     public static func lift(_ value: FfiType) throws -> SwiftType {
         return value
     }
 
-    #if swift(>=5.8)
-        @_documentation(visibility: private)
-    #endif
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
     public static func lower(_ value: SwiftType) -> FfiType {
         return value
     }
@@ -187,12 +187,12 @@ extension FfiConverterPrimitive {
 
 // Types conforming to `FfiConverterRustBuffer` lift and lower into a `RustBuffer`.
 // Used for complex types where it's hard to write a custom lift/lower.
-private protocol FfiConverterRustBuffer: FfiConverter where FfiType == RustBuffer {}
+fileprivate protocol FfiConverterRustBuffer: FfiConverter where FfiType == RustBuffer {}
 
 extension FfiConverterRustBuffer {
-    #if swift(>=5.8)
-        @_documentation(visibility: private)
-    #endif
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
     public static func lift(_ buf: RustBuffer) throws -> SwiftType {
         var reader = createReader(data: Data(rustBuffer: buf))
         let value = try read(from: &reader)
@@ -203,19 +203,18 @@ extension FfiConverterRustBuffer {
         return value
     }
 
-    #if swift(>=5.8)
-        @_documentation(visibility: private)
-    #endif
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
     public static func lower(_ value: SwiftType) -> RustBuffer {
-        var writer = createWriter()
-        write(value, into: &writer)
-        return RustBuffer(bytes: writer)
+          var writer = createWriter()
+          write(value, into: &writer)
+          return RustBuffer(bytes: writer)
     }
 }
-
 // An error type for FFI errors. These errors occur at the UniFFI level, not
 // the library level.
-private enum UniffiInternalError: LocalizedError {
+fileprivate enum UniffiInternalError: LocalizedError {
     case bufferOverflow
     case incompleteData
     case unexpectedOptionalTag
@@ -226,7 +225,7 @@ private enum UniffiInternalError: LocalizedError {
     case unexpectedStaleHandle
     case rustPanic(_ message: String)
 
-    var errorDescription: String? {
+    public var errorDescription: String? {
         switch self {
         case .bufferOverflow: return "Reading the requested value would read past the end of the buffer"
         case .incompleteData: return "The buffer still has data after lifting its containing value"
@@ -241,24 +240,24 @@ private enum UniffiInternalError: LocalizedError {
     }
 }
 
-private extension NSLock {
+fileprivate extension NSLock {
     func withLock<T>(f: () throws -> T) rethrows -> T {
-        lock()
+        self.lock()
         defer { self.unlock() }
         return try f()
     }
 }
 
-private let CALL_SUCCESS: Int8 = 0
-private let CALL_ERROR: Int8 = 1
-private let CALL_UNEXPECTED_ERROR: Int8 = 2
-private let CALL_CANCELLED: Int8 = 3
+fileprivate let CALL_SUCCESS: Int8 = 0
+fileprivate let CALL_ERROR: Int8 = 1
+fileprivate let CALL_UNEXPECTED_ERROR: Int8 = 2
+fileprivate let CALL_CANCELLED: Int8 = 3
 
-private extension RustCallStatus {
+fileprivate extension RustCallStatus {
     init() {
         self.init(
             code: CALL_SUCCESS,
-            errorBuf: RustBuffer(
+            errorBuf: RustBuffer.init(
                 capacity: 0,
                 len: 0,
                 data: nil
@@ -274,8 +273,7 @@ private func rustCall<T>(_ callback: (UnsafeMutablePointer<RustCallStatus>) -> T
 
 private func rustCallWithError<T, E: Swift.Error>(
     _ errorHandler: @escaping (RustBuffer) throws -> E,
-    _ callback: (UnsafeMutablePointer<RustCallStatus>) -> T
-) throws -> T {
+    _ callback: (UnsafeMutablePointer<RustCallStatus>) -> T) throws -> T {
     try makeRustCall(callback, errorHandler: errorHandler)
 }
 
@@ -284,7 +282,7 @@ private func makeRustCall<T, E: Swift.Error>(
     errorHandler: ((RustBuffer) throws -> E)?
 ) throws -> T {
     uniffiEnsureInitialized()
-    var callStatus = RustCallStatus()
+    var callStatus = RustCallStatus.init()
     let returnedVal = callback(&callStatus)
     try uniffiCheckCallStatus(callStatus: callStatus, errorHandler: errorHandler)
     return returnedVal
@@ -295,44 +293,44 @@ private func uniffiCheckCallStatus<E: Swift.Error>(
     errorHandler: ((RustBuffer) throws -> E)?
 ) throws {
     switch callStatus.code {
-    case CALL_SUCCESS:
-        return
+        case CALL_SUCCESS:
+            return
 
-    case CALL_ERROR:
-        if let errorHandler = errorHandler {
-            throw try errorHandler(callStatus.errorBuf)
-        } else {
-            callStatus.errorBuf.deallocate()
-            throw UniffiInternalError.unexpectedRustCallError
-        }
+        case CALL_ERROR:
+            if let errorHandler = errorHandler {
+                throw try errorHandler(callStatus.errorBuf)
+            } else {
+                callStatus.errorBuf.deallocate()
+                throw UniffiInternalError.unexpectedRustCallError
+            }
 
-    case CALL_UNEXPECTED_ERROR:
-        // When the rust code sees a panic, it tries to construct a RustBuffer
-        // with the message.  But if that code panics, then it just sends back
-        // an empty buffer.
-        if callStatus.errorBuf.len > 0 {
-            throw try UniffiInternalError.rustPanic(FfiConverterString.lift(callStatus.errorBuf))
-        } else {
-            callStatus.errorBuf.deallocate()
-            throw UniffiInternalError.rustPanic("Rust panic")
-        }
+        case CALL_UNEXPECTED_ERROR:
+            // When the rust code sees a panic, it tries to construct a RustBuffer
+            // with the message.  But if that code panics, then it just sends back
+            // an empty buffer.
+            if callStatus.errorBuf.len > 0 {
+                throw UniffiInternalError.rustPanic(try FfiConverterString.lift(callStatus.errorBuf))
+            } else {
+                callStatus.errorBuf.deallocate()
+                throw UniffiInternalError.rustPanic("Rust panic")
+            }
 
-    case CALL_CANCELLED:
-        fatalError("Cancellation not supported yet")
+        case CALL_CANCELLED:
+            fatalError("Cancellation not supported yet")
 
-    default:
-        throw UniffiInternalError.unexpectedRustCallStatusCode
+        default:
+            throw UniffiInternalError.unexpectedRustCallStatusCode
     }
 }
 
 private func uniffiTraitInterfaceCall<T>(
     callStatus: UnsafeMutablePointer<RustCallStatus>,
     makeCall: () throws -> T,
-    writeReturn: (T) -> Void
+    writeReturn: (T) -> ()
 ) {
     do {
         try writeReturn(makeCall())
-    } catch {
+    } catch let error {
         callStatus.pointee.code = CALL_UNEXPECTED_ERROR
         callStatus.pointee.errorBuf = FfiConverterString.lower(String(describing: error))
     }
@@ -341,7 +339,7 @@ private func uniffiTraitInterfaceCall<T>(
 private func uniffiTraitInterfaceCallWithError<T, E>(
     callStatus: UnsafeMutablePointer<RustCallStatus>,
     makeCall: () throws -> T,
-    writeReturn: (T) -> Void,
+    writeReturn: (T) -> (),
     lowerError: (E) -> RustBuffer
 ) {
     do {
@@ -354,8 +352,7 @@ private func uniffiTraitInterfaceCallWithError<T, E>(
         callStatus.pointee.errorBuf = FfiConverterString.lower(String(describing: error))
     }
 }
-
-private class UniffiHandleMap<T> {
+fileprivate class UniffiHandleMap<T> {
     private var map: [UInt64: T] = [:]
     private let lock = NSLock()
     private var currentHandle: UInt64 = 1
@@ -369,7 +366,7 @@ private class UniffiHandleMap<T> {
         }
     }
 
-    func get(handle: UInt64) throws -> T {
+     func get(handle: UInt64) throws -> T {
         try lock.withLock {
             guard let obj = map[handle] else {
                 throw UniffiInternalError.unexpectedStaleHandle
@@ -389,76 +386,112 @@ private class UniffiHandleMap<T> {
     }
 
     var count: Int {
-        map.count
+        get {
+            map.count
+        }
     }
 }
 
+
 // Public interface members begin here.
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt16: FfiConverterPrimitive {
+    typealias FfiType = UInt16
+    typealias SwiftType = UInt16
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt16 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterUInt32: FfiConverterPrimitive {
+fileprivate struct FfiConverterUInt32: FfiConverterPrimitive {
     typealias FfiType = UInt32
     typealias SwiftType = UInt32
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt32 {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt32 {
         return try lift(readInt(&buf))
     }
 
-    static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         writeInt(&buf, lower(value))
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt64: FfiConverterPrimitive {
+    typealias FfiType = UInt64
+    typealias SwiftType = UInt64
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt64 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterInt64: FfiConverterPrimitive {
+fileprivate struct FfiConverterInt64: FfiConverterPrimitive {
     typealias FfiType = Int64
     typealias SwiftType = Int64
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Int64 {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Int64 {
         return try lift(readInt(&buf))
     }
 
-    static func write(_ value: Int64, into buf: inout [UInt8]) {
+    public static func write(_ value: Int64, into buf: inout [UInt8]) {
         writeInt(&buf, lower(value))
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterBool: FfiConverter {
+fileprivate struct FfiConverterBool : FfiConverter {
     typealias FfiType = Int8
     typealias SwiftType = Bool
 
-    static func lift(_ value: Int8) throws -> Bool {
+    public static func lift(_ value: Int8) throws -> Bool {
         return value != 0
     }
 
-    static func lower(_ value: Bool) -> Int8 {
+    public static func lower(_ value: Bool) -> Int8 {
         return value ? 1 : 0
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Bool {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Bool {
         return try lift(readInt(&buf))
     }
 
-    static func write(_ value: Bool, into buf: inout [UInt8]) {
+    public static func write(_ value: Bool, into buf: inout [UInt8]) {
         writeInt(&buf, lower(value))
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterString: FfiConverter {
+fileprivate struct FfiConverterString: FfiConverter {
     typealias SwiftType = String
     typealias FfiType = RustBuffer
 
-    static func lift(_ value: RustBuffer) throws -> String {
+    public static func lift(_ value: RustBuffer) throws -> String {
         defer {
             value.deallocate()
         }
@@ -469,7 +502,7 @@ private struct FfiConverterString: FfiConverter {
         return String(bytes: bytes, encoding: String.Encoding.utf8)!
     }
 
-    static func lower(_ value: String) -> RustBuffer {
+    public static func lower(_ value: String) -> RustBuffer {
         return value.utf8CString.withUnsafeBufferPointer { ptr in
             // The swift string gives us int8_t, we want uint8_t.
             ptr.withMemoryRebound(to: UInt8.self) { ptr in
@@ -480,57 +513,119 @@ private struct FfiConverterString: FfiConverter {
         }
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> String {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> String {
         let len: Int32 = try readInt(&buf)
-        return try String(bytes: readBytes(&buf, count: Int(len)), encoding: String.Encoding.utf8)!
+        return String(bytes: try readBytes(&buf, count: Int(len)), encoding: String.Encoding.utf8)!
     }
 
-    static func write(_ value: String, into buf: inout [UInt8]) {
+    public static func write(_ value: String, into buf: inout [UInt8]) {
         let len = Int32(value.utf8.count)
         writeInt(&buf, len)
         writeBytes(&buf, value.utf8)
     }
 }
 
-public protocol CoreSessionProtocol: AnyObject {
-    func attachPlugin(pluginId: String, threadId: String?) throws -> PluginSession
-
-    func baseRevision() throws -> Revision?
-
-    func diffForRange(range: RevisionRange) throws -> Diff
-
-    func diffHead() throws -> Diff
-
-    func diffWorkspace() throws -> Diff
-
-    func headRevision() throws -> Revision?
-
-    func pluginThreads(pluginId: String) throws -> [ThreadRef]
-
-    func plugins() -> [PluginSummary]
-
-    func pollRevision(session: PluginSession) throws -> RevisionProgress
-
-    func postReview(session: PluginSession, payload: ReviewPayload) throws -> SubmissionResult
-
-    func refresh() throws -> RepositorySnapshot
-
-    func repositoryInfo() throws -> RepositoryInfo
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterData: FfiConverterRustBuffer {
+    typealias SwiftType = Data
 
-    func snapshot() throws -> RepositorySnapshot
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Data {
+        let len: Int32 = try readInt(&buf)
+        return Data(try readBytes(&buf, count: Int(len)))
+    }
 
-    func workspaceStatus() throws -> WorkspaceStatus
+    public static func write(_ value: Data, into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        writeBytes(&buf, value)
+    }
+}
+
+
+
+
+public protocol CoreSessionProtocol : AnyObject {
+    
+    func attachPlugin(pluginId: String, threadId: String?) throws  -> PluginSession
+    
+    func baseRevision() throws  -> Revision?
+    
+    func diffCommits(range: RevisionRange, offset: UInt32, limit: UInt32, request: DiffRequest?) throws  -> CommitDiffPage
+    
+    func diffForRange(range: RevisionRange, request: DiffRequest?) throws  -> Diff
+    
+    func diffHead(request: DiffRequest?) throws  -> Diff
+    
+    func diffIndex(request: DiffRequest?) throws  -> Diff
+    
+    func diffMergeBase(target: String?, includeWorkspace: Bool, request: DiffRequest?) throws  -> Diff
+    
+    func diffPatch(patch: Data, checkApplies: Bool, request: DiffRequest?) throws  -> Diff
+    
+    func diffSinceIteration(number: UInt32?, request: DiffRequest?) throws  -> Diff
+    
+    func diffSummaryForRange(range: RevisionRange, request: DiffRequest?) throws  -> DiffSummary
+    
+    func diffSummaryHead(request: DiffRequest?) throws  -> DiffSummary
+    
+    func diffSummaryWorkspace(request: DiffRequest?) throws  -> DiffSummary
+    
+    func diffUnstaged(request: DiffRequest?) throws  -> Diff
+    
+    func diffWorkspace(request: DiffRequest?) throws  -> Diff
+    
+    func expandContext(range: RevisionRange, workspace: Bool, path: String, side: DiffSide, startLine: UInt32, lineCount: UInt32, request: DiffRequest?) throws  -> [DiffLine]
+    
+    func exportPatch(diff: Diff, selection: [PatchSelection]?) throws  -> Data
+    
+    func fileContents(range: RevisionRange, path: String, version: FileVersion) throws  -> FileContents?
+    
+    func headRevision() throws  -> Revision?
+    
+    func loadFileBlobs(range: RevisionRange, workspace: Bool, path: String, request: DiffRequest?) throws  -> FileBlobs
+    
+    func loadFileHunks(range: RevisionRange, workspace: Bool, path: String, maxLines: UInt32?, request: DiffRequest?) throws  -> FileHunks
+    
+    func loadSplitHunks(range: RevisionRange, workspace: Bool, path: String, request: DiffRequest?) throws  -> [SplitHunk]
+    
+    func pluginThreads(pluginId: String) throws  -> [ThreadRef]
+    
+    func plugins()  -> [PluginSummary]
+    
+    func pollRevision(session: PluginSession) throws  -> RevisionProgress
+    
+    func postReview(session: PluginSession, payload: ReviewPayload) throws  -> SubmissionResult
+    
+    func rangeDiff(oldRange: RevisionRange, newRange: RevisionRange, request: DiffRequest?) throws  -> RangeDiff
+    
+    func recordIteration(revision: String?) throws  -> ReviewIteration
+    
+    func refresh() throws  -> RepositorySnapshot
+    
+    func repositoryInfo() throws  -> RepositoryInfo
+    
+    func reviewIterations()  -> [ReviewIteration]
+    
+    func setClassificationRules(rules: [ClassificationRule]) 
+    
+    func snapshot() throws  -> RepositorySnapshot
+    
+    func splitHunks(hunks: [DiffHunk])  -> [SplitHunk]
+    
+    func workspaceStatus() throws  -> WorkspaceStatus
+    
 }
 
 open class CoreSession:
-    CoreSessionProtocol
-{
+    CoreSessionProtocol {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
-    #if swift(>=5.8)
-        @_documentation(visibility: private)
-    #endif
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
     public struct NoPointer {
         public init() {}
     }
@@ -538,7 +633,7 @@ open class CoreSession:
     // TODO: We'd like this to be `private` but for Swifty reasons,
     // we can't implement `FfiConverter` without making this `required` and we can't
     // make it `required` without making it `public`.
-    public required init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
         self.pointer = pointer
     }
 
@@ -547,20 +642,19 @@ open class CoreSession:
     //
     // - Warning:
     //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
-    #if swift(>=5.8)
-        @_documentation(visibility: private)
-    #endif
-    public init(noPointer _: NoPointer) {
-        pointer = nil
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
     }
 
-    #if swift(>=5.8)
-        @_documentation(visibility: private)
-    #endif
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
         return try! rustCall { uniffi_prism_core_fn_clone_coresession(self.pointer, $0) }
     }
-
     // No primary constructor declared for this class.
 
     deinit {
@@ -571,102 +665,313 @@ open class CoreSession:
         try! rustCall { uniffi_prism_core_fn_free_coresession(pointer, $0) }
     }
 
-    open func attachPlugin(pluginId: String, threadId: String?) throws -> PluginSession {
-        return try FfiConverterTypePluginSession.lift(rustCallWithError(FfiConverterTypeCoreError.lift) {
-            uniffi_prism_core_fn_method_coresession_attach_plugin(self.uniffiClonePointer(),
-                                                                  FfiConverterString.lower(pluginId),
-                                                                  FfiConverterOptionString.lower(threadId), $0)
-        })
-    }
-
-    open func baseRevision() throws -> Revision? {
-        return try FfiConverterOptionTypeRevision.lift(rustCallWithError(FfiConverterTypeCoreError.lift) {
-            uniffi_prism_core_fn_method_coresession_base_revision(self.uniffiClonePointer(), $0)
-        })
-    }
-
-    open func diffForRange(range: RevisionRange) throws -> Diff {
-        return try FfiConverterTypeDiff.lift(rustCallWithError(FfiConverterTypeCoreError.lift) {
-            uniffi_prism_core_fn_method_coresession_diff_for_range(self.uniffiClonePointer(),
-                                                                   FfiConverterTypeRevisionRange.lower(range), $0)
-        })
-    }
-
-    open func diffHead() throws -> Diff {
-        return try FfiConverterTypeDiff.lift(rustCallWithError(FfiConverterTypeCoreError.lift) {
-            uniffi_prism_core_fn_method_coresession_diff_head(self.uniffiClonePointer(), $0)
-        })
-    }
-
-    open func diffWorkspace() throws -> Diff {
-        return try FfiConverterTypeDiff.lift(rustCallWithError(FfiConverterTypeCoreError.lift) {
-            uniffi_prism_core_fn_method_coresession_diff_workspace(self.uniffiClonePointer(), $0)
-        })
-    }
-
-    open func headRevision() throws -> Revision? {
-        return try FfiConverterOptionTypeRevision.lift(rustCallWithError(FfiConverterTypeCoreError.lift) {
-            uniffi_prism_core_fn_method_coresession_head_revision(self.uniffiClonePointer(), $0)
-        })
-    }
-
-    open func pluginThreads(pluginId: String) throws -> [ThreadRef] {
-        return try FfiConverterSequenceTypeThreadRef.lift(rustCallWithError(FfiConverterTypeCoreError.lift) {
-            uniffi_prism_core_fn_method_coresession_plugin_threads(self.uniffiClonePointer(),
-                                                                   FfiConverterString.lower(pluginId), $0)
-        })
-    }
-
-    open func plugins() -> [PluginSummary] {
-        return try! FfiConverterSequenceTypePluginSummary.lift(try! rustCall {
-            uniffi_prism_core_fn_method_coresession_plugins(self.uniffiClonePointer(), $0)
-        })
-    }
-
-    open func pollRevision(session: PluginSession) throws -> RevisionProgress {
-        return try FfiConverterTypeRevisionProgress.lift(rustCallWithError(FfiConverterTypeCoreError.lift) {
-            uniffi_prism_core_fn_method_coresession_poll_revision(self.uniffiClonePointer(),
-                                                                  FfiConverterTypePluginSession.lower(session), $0)
-        })
-    }
-
-    open func postReview(session: PluginSession, payload: ReviewPayload) throws -> SubmissionResult {
-        return try FfiConverterTypeSubmissionResult.lift(rustCallWithError(FfiConverterTypeCoreError.lift) {
-            uniffi_prism_core_fn_method_coresession_post_review(self.uniffiClonePointer(),
-                                                                FfiConverterTypePluginSession.lower(session),
-                                                                FfiConverterTypeReviewPayload.lower(payload), $0)
-        })
-    }
-
-    open func refresh() throws -> RepositorySnapshot {
-        return try FfiConverterTypeRepositorySnapshot.lift(rustCallWithError(FfiConverterTypeCoreError.lift) {
-            uniffi_prism_core_fn_method_coresession_refresh(self.uniffiClonePointer(), $0)
-        })
-    }
-
-    open func repositoryInfo() throws -> RepositoryInfo {
-        return try FfiConverterTypeRepositoryInfo.lift(rustCallWithError(FfiConverterTypeCoreError.lift) {
-            uniffi_prism_core_fn_method_coresession_repository_info(self.uniffiClonePointer(), $0)
-        })
-    }
-
-    open func snapshot() throws -> RepositorySnapshot {
-        return try FfiConverterTypeRepositorySnapshot.lift(rustCallWithError(FfiConverterTypeCoreError.lift) {
-            uniffi_prism_core_fn_method_coresession_snapshot(self.uniffiClonePointer(), $0)
-        })
-    }
+    
+
+    
+open func attachPlugin(pluginId: String, threadId: String?)throws  -> PluginSession {
+    return try  FfiConverterTypePluginSession.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_attach_plugin(self.uniffiClonePointer(),
+        FfiConverterString.lower(pluginId),
+        FfiConverterOptionString.lower(threadId),$0
+    )
+})
+}
+    
+open func baseRevision()throws  -> Revision? {
+    return try  FfiConverterOptionTypeRevision.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_base_revision(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func diffCommits(range: RevisionRange, offset: UInt32, limit: UInt32, request: DiffRequest? = nil)throws  -> CommitDiffPage {
+    return try  FfiConverterTypeCommitDiffPage.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_diff_commits(self.uniffiClonePointer(),
+        FfiConverterTypeRevisionRange.lower(range),
+        FfiConverterUInt32.lower(offset),
+        FfiConverterUInt32.lower(limit),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func diffForRange(range: RevisionRange, request: DiffRequest? = nil)throws  -> Diff {
+    return try  FfiConverterTypeDiff.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_diff_for_range(self.uniffiClonePointer(),
+        FfiConverterTypeRevisionRange.lower(range),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func diffHead(request: DiffRequest? = nil)throws  -> Diff {
+    return try  FfiConverterTypeDiff.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_diff_head(self.uniffiClonePointer(),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func diffIndex(request: DiffRequest? = nil)throws  -> Diff {
+    return try  FfiConverterTypeDiff.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_diff_index(self.uniffiClonePointer(),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func diffMergeBase(target: String?, includeWorkspace: Bool, request: DiffRequest? = nil)throws  -> Diff {
+    return try  FfiConverterTypeDiff.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_diff_merge_base(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(target),
+        FfiConverterBool.lower(includeWorkspace),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func diffPatch(patch: Data, checkApplies: Bool, request: DiffRequest? = nil)throws  -> Diff {
+    return try  FfiConverterTypeDiff.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_diff_patch(self.uniffiClonePointer(),
+        FfiConverterData.lower(patch),
+        FfiConverterBool.lower(checkApplies),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func diffSinceIteration(number: UInt32?, request: DiffRequest? = nil)throws  -> Diff {
+    return try  FfiConverterTypeDiff.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_diff_since_iteration(self.uniffiClonePointer(),
+        FfiConverterOptionUInt32.lower(number),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func diffSummaryForRange(range: RevisionRange, request: DiffRequest? = nil)throws  -> DiffSummary {
+    return try  FfiConverterTypeDiffSummary.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_diff_summary_for_range(self.uniffiClonePointer(),
+        FfiConverterTypeRevisionRange.lower(range),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func diffSummaryHead(request: DiffRequest? = nil)throws  -> DiffSummary {
+    return try  FfiConverterTypeDiffSummary.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_diff_summary_head(self.uniffiClonePointer(),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func diffSummaryWorkspace(request: DiffRequest? = nil)throws  -> DiffSummary {
+    return try  FfiConverterTypeDiffSummary.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_diff_summary_workspace(self.uniffiClonePointer(),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func diffUnstaged(request: DiffRequest? = nil)throws  -> Diff {
+    return try  FfiConverterTypeDiff.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_diff_unstaged(self.uniffiClonePointer(),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func diffWorkspace(request: DiffRequest? = nil)throws  -> Diff {
+    return try  FfiConverterTypeDiff.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_diff_workspace(self.uniffiClonePointer(),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func expandContext(range: RevisionRange, workspace: Bool, path: String, side: DiffSide, startLine: UInt32, lineCount: UInt32, request: DiffRequest? = nil)throws  -> [DiffLine] {
+    return try  FfiConverterSequenceTypeDiffLine.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_expand_context(self.uniffiClonePointer(),
+        FfiConverterTypeRevisionRange.lower(range),
+        FfiConverterBool.lower(workspace),
+        FfiConverterString.lower(path),
+        FfiConverterTypeDiffSide.lower(side),
+        FfiConverterUInt32.lower(startLine),
+        FfiConverterUInt32.lower(lineCount),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func exportPatch(diff: Diff, selection: [PatchSelection]? = nil)throws  -> Data {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_export_patch(self.uniffiClonePointer(),
+        FfiConverterTypeDiff.lower(diff),
+        FfiConverterOptionSequenceTypePatchSelection.lower(selection),$0
+    )
+})
+}
+    
+open func fileContents(range: RevisionRange, path: String, version: FileVersion)throws  -> FileContents? {
+    return try  FfiConverterOptionTypeFileContents.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_file_contents(self.uniffiClonePointer(),
+        FfiConverterTypeRevisionRange.lower(range),
+        FfiConverterString.lower(path),
+        FfiConverterTypeFileVersion.lower(version),$0
+    )
+})
+}
+    
+open func headRevision()throws  -> Revision? {
+    return try  FfiConverterOptionTypeRevision.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_head_revision(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func loadFileBlobs(range: RevisionRange, workspace: Bool, path: String, request: DiffRequest? = nil)throws  -> FileBlobs {
+    return try  FfiConverterTypeFileBlobs.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_load_file_blobs(self.uniffiClonePointer(),
+        FfiConverterTypeRevisionRange.lower(range),
+        FfiConverterBool.lower(workspace),
+        FfiConverterString.lower(path),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func loadFileHunks(range: RevisionRange, workspace: Bool, path: String, maxLines: UInt32?, request: DiffRequest? = nil)throws  -> FileHunks {
+    return try  FfiConverterTypeFileHunks.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_load_file_hunks(self.uniffiClonePointer(),
+        FfiConverterTypeRevisionRange.lower(range),
+        FfiConverterBool.lower(workspace),
+        FfiConverterString.lower(path),
+        FfiConverterOptionUInt32.lower(maxLines),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func loadSplitHunks(range: RevisionRange, workspace: Bool, path: String, request: DiffRequest? = nil)throws  -> [SplitHunk] {
+    return try  FfiConverterSequenceTypeSplitHunk.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_load_split_hunks(self.uniffiClonePointer(),
+        FfiConverterTypeRevisionRange.lower(range),
+        FfiConverterBool.lower(workspace),
+        FfiConverterString.lower(path),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func pluginThreads(pluginId: String)throws  -> [ThreadRef] {
+    return try  FfiConverterSequenceTypeThreadRef.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_plugin_threads(self.uniffiClonePointer(),
+        FfiConverterString.lower(pluginId),$0
+    )
+})
+}
+    
+open func plugins() -> [PluginSummary] {
+    return try!  FfiConverterSequenceTypePluginSummary.lift(try! rustCall() {
+    uniffi_prism_core_fn_method_coresession_plugins(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func pollRevision(session: PluginSession)throws  -> RevisionProgress {
+    return try  FfiConverterTypeRevisionProgress.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_poll_revision(self.uniffiClonePointer(),
+        FfiConverterTypePluginSession.lower(session),$0
+    )
+})
+}
+    
+open func postReview(session: PluginSession, payload: ReviewPayload)throws  -> SubmissionResult {
+    return try  FfiConverterTypeSubmissionResult.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_post_review(self.uniffiClonePointer(),
+        FfiConverterTypePluginSession.lower(session),
+        FfiConverterTypeReviewPayload.lower(payload),$0
+    )
+})
+}
+    
+open func rangeDiff(oldRange: RevisionRange, newRange: RevisionRange, request: DiffRequest? = nil)throws  -> RangeDiff {
+    return try  FfiConverterTypeRangeDiff.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_range_diff(self.uniffiClonePointer(),
+        FfiConverterTypeRevisionRange.lower(oldRange),
+        FfiConverterTypeRevisionRange.lower(newRange),
+        FfiConverterOptionTypeDiffRequest.lower(request),$0
+    )
+})
+}
+    
+open func recordIteration(revision: String?)throws  -> ReviewIteration {
+    return try  FfiConverterTypeReviewIteration.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_record_iteration(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(revision),$0
+    )
+})
+}
+    
+open func refresh()throws  -> RepositorySnapshot {
+    return try  FfiConverterTypeRepositorySnapshot.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_refresh(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func repositoryInfo()throws  -> RepositoryInfo {
+    return try  FfiConverterTypeRepositoryInfo.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_repository_info(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func reviewIterations() -> [ReviewIteration] {
+    return try!  FfiConverterSequenceTypeReviewIteration.lift(try! rustCall() {
+    uniffi_prism_core_fn_method_coresession_review_iterations(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func setClassificationRules(rules: [ClassificationRule]) {try! rustCall() {
+    uniffi_prism_core_fn_method_coresession_set_classification_rules(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeClassificationRule.lower(rules),$0
+    )
+}
+}
+    
+open func snapshot()throws  -> RepositorySnapshot {
+    return try  FfiConverterTypeRepositorySnapshot.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_snapshot(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func splitHunks(hunks: [DiffHunk]) -> [SplitHunk] {
+    return try!  FfiConverterSequenceTypeSplitHunk.lift(try! rustCall() {
+    uniffi_prism_core_fn_method_coresession_split_hunks(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeDiffHunk.lower(hunks),$0
+    )
+})
+}
+    
+open func workspaceStatus()throws  -> WorkspaceStatus {
+    return try  FfiConverterTypeWorkspaceStatus.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_method_coresession_workspace_status(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
 
-    open func workspaceStatus() throws -> WorkspaceStatus {
-        return try FfiConverterTypeWorkspaceStatus.lift(rustCallWithError(FfiConverterTypeCoreError.lift) {
-            uniffi_prism_core_fn_method_coresession_workspace_status(self.uniffiClonePointer(), $0)
-        })
-    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
 public struct FfiConverterTypeCoreSession: FfiConverter {
+
     typealias FfiType = UnsafeMutableRawPointer
     typealias SwiftType = CoreSession
 
@@ -683,7 +988,7 @@ public struct FfiConverterTypeCoreSession: FfiConverter {
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
         let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
-        if ptr == nil {
+        if (ptr == nil) {
             throw UniffiInternalError.unexpectedNullPointer
         }
         return try lift(ptr!)
@@ -696,643 +1001,822 @@ public struct FfiConverterTypeCoreSession: FfiConverter {
     }
 }
 
+
+
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
 public func FfiConverterTypeCoreSession_lift(_ pointer: UnsafeMutableRawPointer) throws -> CoreSession {
     return try FfiConverterTypeCoreSession.lift(pointer)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
 public func FfiConverterTypeCoreSession_lower(_ value: CoreSession) -> UnsafeMutableRawPointer {
     return FfiConverterTypeCoreSession.lower(value)
 }
 
-public struct CommentDraft {
-    public var body: String
-    public var location: FileRange
+
+public struct BlobInfo {
+    public var oid: String
+    public var size: UInt64
+    public var image: ImageInfo?
+    public var symlinkTarget: String?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(body: String, location: FileRange) {
-        self.body = body
-        self.location = location
+    public init(oid: String, size: UInt64, image: ImageInfo?, symlinkTarget: String?) {
+        self.oid = oid
+        self.size = size
+        self.image = image
+        self.symlinkTarget = symlinkTarget
     }
 }
 
-extension CommentDraft: Equatable, Hashable {
-    public static func == (lhs: CommentDraft, rhs: CommentDraft) -> Bool {
-        if lhs.body != rhs.body {
+
+
+extension BlobInfo: Equatable, Hashable {
+    public static func ==(lhs: BlobInfo, rhs: BlobInfo) -> Bool {
+        if lhs.oid != rhs.oid {
             return false
         }
-        if lhs.location != rhs.location {
+        if lhs.size != rhs.size {
+            return false
+        }
+        if lhs.image != rhs.image {
+            return false
+        }
+        if lhs.symlinkTarget != rhs.symlinkTarget {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(body)
-        hasher.combine(location)
+        hasher.combine(oid)
+        hasher.combine(size)
+        hasher.combine(image)
+        hasher.combine(symlinkTarget)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCommentDraft: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CommentDraft {
+public struct FfiConverterTypeBlobInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BlobInfo {
         return
-            try CommentDraft(
-                body: FfiConverterString.read(from: &buf),
-                location: FfiConverterTypeFileRange.read(from: &buf)
-            )
+            try BlobInfo(
+                oid: FfiConverterString.read(from: &buf), 
+                size: FfiConverterUInt64.read(from: &buf), 
+                image: FfiConverterOptionTypeImageInfo.read(from: &buf), 
+                symlinkTarget: FfiConverterOptionString.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: CommentDraft, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.body, into: &buf)
-        FfiConverterTypeFileRange.write(value.location, into: &buf)
+    public static func write(_ value: BlobInfo, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.oid, into: &buf)
+        FfiConverterUInt64.write(value.size, into: &buf)
+        FfiConverterOptionTypeImageInfo.write(value.image, into: &buf)
+        FfiConverterOptionString.write(value.symlinkTarget, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCommentDraft_lift(_ buf: RustBuffer) throws -> CommentDraft {
-    return try FfiConverterTypeCommentDraft.lift(buf)
+public func FfiConverterTypeBlobInfo_lift(_ buf: RustBuffer) throws -> BlobInfo {
+    return try FfiConverterTypeBlobInfo.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCommentDraft_lower(_ value: CommentDraft) -> RustBuffer {
-    return FfiConverterTypeCommentDraft.lower(value)
+public func FfiConverterTypeBlobInfo_lower(_ value: BlobInfo) -> RustBuffer {
+    return FfiConverterTypeBlobInfo.lower(value)
 }
 
-public struct Diagnostic {
-    public var title: String
-    public var detail: String?
-    public var severity: Severity
-    public var location: FileRange
-    public var tags: [String]
-    public var suggestions: [Suggestion]
+
+public struct ClassificationRule {
+    public var pattern: String
+    public var classification: FileClassification
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(title: String, detail: String?, severity: Severity, location: FileRange, tags: [String], suggestions: [Suggestion]) {
-        self.title = title
-        self.detail = detail
-        self.severity = severity
-        self.location = location
-        self.tags = tags
-        self.suggestions = suggestions
+    public init(pattern: String, classification: FileClassification) {
+        self.pattern = pattern
+        self.classification = classification
     }
 }
 
-extension Diagnostic: Equatable, Hashable {
-    public static func == (lhs: Diagnostic, rhs: Diagnostic) -> Bool {
-        if lhs.title != rhs.title {
-            return false
-        }
-        if lhs.detail != rhs.detail {
-            return false
-        }
-        if lhs.severity != rhs.severity {
-            return false
-        }
-        if lhs.location != rhs.location {
-            return false
-        }
-        if lhs.tags != rhs.tags {
+
+
+extension ClassificationRule: Equatable, Hashable {
+    public static func ==(lhs: ClassificationRule, rhs: ClassificationRule) -> Bool {
+        if lhs.pattern != rhs.pattern {
             return false
         }
-        if lhs.suggestions != rhs.suggestions {
+        if lhs.classification != rhs.classification {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(title)
-        hasher.combine(detail)
-        hasher.combine(severity)
-        hasher.combine(location)
-        hasher.combine(tags)
-        hasher.combine(suggestions)
+        hasher.combine(pattern)
+        hasher.combine(classification)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDiagnostic: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Diagnostic {
+public struct FfiConverterTypeClassificationRule: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ClassificationRule {
         return
-            try Diagnostic(
-                title: FfiConverterString.read(from: &buf),
-                detail: FfiConverterOptionString.read(from: &buf),
-                severity: FfiConverterTypeSeverity.read(from: &buf),
-                location: FfiConverterTypeFileRange.read(from: &buf),
-                tags: FfiConverterSequenceString.read(from: &buf),
-                suggestions: FfiConverterSequenceTypeSuggestion.read(from: &buf)
-            )
+            try ClassificationRule(
+                pattern: FfiConverterString.read(from: &buf), 
+                classification: FfiConverterTypeFileClassification.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: Diagnostic, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.title, into: &buf)
-        FfiConverterOptionString.write(value.detail, into: &buf)
-        FfiConverterTypeSeverity.write(value.severity, into: &buf)
-        FfiConverterTypeFileRange.write(value.location, into: &buf)
-        FfiConverterSequenceString.write(value.tags, into: &buf)
-        FfiConverterSequenceTypeSuggestion.write(value.suggestions, into: &buf)
+    public static func write(_ value: ClassificationRule, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.pattern, into: &buf)
+        FfiConverterTypeFileClassification.write(value.classification, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiagnostic_lift(_ buf: RustBuffer) throws -> Diagnostic {
-    return try FfiConverterTypeDiagnostic.lift(buf)
+public func FfiConverterTypeClassificationRule_lift(_ buf: RustBuffer) throws -> ClassificationRule {
+    return try FfiConverterTypeClassificationRule.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiagnostic_lower(_ value: Diagnostic) -> RustBuffer {
-    return FfiConverterTypeDiagnostic.lower(value)
+public func FfiConverterTypeClassificationRule_lower(_ value: ClassificationRule) -> RustBuffer {
+    return FfiConverterTypeClassificationRule.lower(value)
 }
 
-public struct Diff {
-    public var range: RevisionRange
-    public var files: [DiffFile]
+
+public struct CommentDraft {
+    public var body: String
+    public var location: FileRange
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(range: RevisionRange, files: [DiffFile]) {
-        self.range = range
-        self.files = files
+    public init(body: String, location: FileRange) {
+        self.body = body
+        self.location = location
     }
 }
 
-extension Diff: Equatable, Hashable {
-    public static func == (lhs: Diff, rhs: Diff) -> Bool {
-        if lhs.range != rhs.range {
+
+
+extension CommentDraft: Equatable, Hashable {
+    public static func ==(lhs: CommentDraft, rhs: CommentDraft) -> Bool {
+        if lhs.body != rhs.body {
             return false
         }
-        if lhs.files != rhs.files {
+        if lhs.location != rhs.location {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(range)
-        hasher.combine(files)
+        hasher.combine(body)
+        hasher.combine(location)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDiff: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Diff {
+public struct FfiConverterTypeCommentDraft: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CommentDraft {
         return
-            try Diff(
-                range: FfiConverterTypeRevisionRange.read(from: &buf),
-                files: FfiConverterSequenceTypeDiffFile.read(from: &buf)
-            )
+            try CommentDraft(
+                body: FfiConverterString.read(from: &buf), 
+                location: FfiConverterTypeFileRange.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: Diff, into buf: inout [UInt8]) {
-        FfiConverterTypeRevisionRange.write(value.range, into: &buf)
-        FfiConverterSequenceTypeDiffFile.write(value.files, into: &buf)
+    public static func write(_ value: CommentDraft, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.body, into: &buf)
+        FfiConverterTypeFileRange.write(value.location, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiff_lift(_ buf: RustBuffer) throws -> Diff {
-    return try FfiConverterTypeDiff.lift(buf)
+public func FfiConverterTypeCommentDraft_lift(_ buf: RustBuffer) throws -> CommentDraft {
+    return try FfiConverterTypeCommentDraft.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiff_lower(_ value: Diff) -> RustBuffer {
-    return FfiConverterTypeDiff.lower(value)
+public func FfiConverterTypeCommentDraft_lower(_ value: CommentDraft) -> RustBuffer {
+    return FfiConverterTypeCommentDraft.lower(value)
 }
 
-public struct DiffFile {
-    public var path: String
-    public var oldPath: String?
-    public var status: FileStatus
-    public var stats: DiffStats
-    public var isBinary: Bool
-    public var hunks: [DiffHunk]
+
+public struct CommitDiffPage {
+    public var diffs: [Diff]
+    public var totalCommits: UInt32
+    public var nextOffset: UInt32?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(path: String, oldPath: String?, status: FileStatus, stats: DiffStats, isBinary: Bool, hunks: [DiffHunk]) {
-        self.path = path
-        self.oldPath = oldPath
-        self.status = status
-        self.stats = stats
-        self.isBinary = isBinary
-        self.hunks = hunks
+    public init(diffs: [Diff], totalCommits: UInt32, nextOffset: UInt32?) {
+        self.diffs = diffs
+        self.totalCommits = totalCommits
+        self.nextOffset = nextOffset
     }
 }
 
-extension DiffFile: Equatable, Hashable {
-    public static func == (lhs: DiffFile, rhs: DiffFile) -> Bool {
-        if lhs.path != rhs.path {
-            return false
-        }
-        if lhs.oldPath != rhs.oldPath {
-            return false
-        }
-        if lhs.status != rhs.status {
-            return false
-        }
-        if lhs.stats != rhs.stats {
+
+
+extension CommitDiffPage: Equatable, Hashable {
+    public static func ==(lhs: CommitDiffPage, rhs: CommitDiffPage) -> Bool {
+        if lhs.diffs != rhs.diffs {
             return false
         }
-        if lhs.isBinary != rhs.isBinary {
+        if lhs.totalCommits != rhs.totalCommits {
             return false
         }
-        if lhs.hunks != rhs.hunks {
+        if lhs.nextOffset != rhs.nextOffset {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(path)
-        hasher.combine(oldPath)
-        hasher.combine(status)
-        hasher.combine(stats)
-        hasher.combine(isBinary)
-        hasher.combine(hunks)
+        hasher.combine(diffs)
+        hasher.combine(totalCommits)
+        hasher.combine(nextOffset)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDiffFile: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffFile {
+public struct FfiConverterTypeCommitDiffPage: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CommitDiffPage {
         return
-            try DiffFile(
-                path: FfiConverterString.read(from: &buf),
-                oldPath: FfiConverterOptionString.read(from: &buf),
-                status: FfiConverterTypeFileStatus.read(from: &buf),
-                stats: FfiConverterTypeDiffStats.read(from: &buf),
-                isBinary: FfiConverterBool.read(from: &buf),
-                hunks: FfiConverterSequenceTypeDiffHunk.read(from: &buf)
-            )
+            try CommitDiffPage(
+                diffs: FfiConverterSequenceTypeDiff.read(from: &buf), 
+                totalCommits: FfiConverterUInt32.read(from: &buf), 
+                nextOffset: FfiConverterOptionUInt32.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: DiffFile, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.path, into: &buf)
-        FfiConverterOptionString.write(value.oldPath, into: &buf)
-        FfiConverterTypeFileStatus.write(value.status, into: &buf)
-        FfiConverterTypeDiffStats.write(value.stats, into: &buf)
-        FfiConverterBool.write(value.isBinary, into: &buf)
-        FfiConverterSequenceTypeDiffHunk.write(value.hunks, into: &buf)
+    public static func write(_ value: CommitDiffPage, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeDiff.write(value.diffs, into: &buf)
+        FfiConverterUInt32.write(value.totalCommits, into: &buf)
+        FfiConverterOptionUInt32.write(value.nextOffset, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiffFile_lift(_ buf: RustBuffer) throws -> DiffFile {
-    return try FfiConverterTypeDiffFile.lift(buf)
+public func FfiConverterTypeCommitDiffPage_lift(_ buf: RustBuffer) throws -> CommitDiffPage {
+    return try FfiConverterTypeCommitDiffPage.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiffFile_lower(_ value: DiffFile) -> RustBuffer {
-    return FfiConverterTypeDiffFile.lower(value)
+public func FfiConverterTypeCommitDiffPage_lower(_ value: CommitDiffPage) -> RustBuffer {
+    return FfiConverterTypeCommitDiffPage.lower(value)
 }
 
-public struct DiffHunk {
-    public var header: DiffRange
-    public var section: String?
-    public var lines: [DiffLine]
+
+public struct CommitPair {
+    public var status: CommitPairStatus
+    public var old: Revision?
+    public var new: Revision?
+    public var interdiff: [DiffHunk]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(header: DiffRange, section: String?, lines: [DiffLine]) {
-        self.header = header
-        self.section = section
-        self.lines = lines
+    public init(status: CommitPairStatus, old: Revision?, new: Revision?, interdiff: [DiffHunk]) {
+        self.status = status
+        self.old = old
+        self.new = new
+        self.interdiff = interdiff
     }
 }
 
-extension DiffHunk: Equatable, Hashable {
-    public static func == (lhs: DiffHunk, rhs: DiffHunk) -> Bool {
-        if lhs.header != rhs.header {
+
+
+extension CommitPair: Equatable, Hashable {
+    public static func ==(lhs: CommitPair, rhs: CommitPair) -> Bool {
+        if lhs.status != rhs.status {
             return false
         }
-        if lhs.section != rhs.section {
+        if lhs.old != rhs.old {
             return false
         }
-        if lhs.lines != rhs.lines {
+        if lhs.new != rhs.new {
+            return false
+        }
+        if lhs.interdiff != rhs.interdiff {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(header)
-        hasher.combine(section)
-        hasher.combine(lines)
+        hasher.combine(status)
+        hasher.combine(old)
+        hasher.combine(new)
+        hasher.combine(interdiff)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDiffHunk: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffHunk {
+public struct FfiConverterTypeCommitPair: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CommitPair {
         return
-            try DiffHunk(
-                header: FfiConverterTypeDiffRange.read(from: &buf),
-                section: FfiConverterOptionString.read(from: &buf),
-                lines: FfiConverterSequenceTypeDiffLine.read(from: &buf)
-            )
+            try CommitPair(
+                status: FfiConverterTypeCommitPairStatus.read(from: &buf), 
+                old: FfiConverterOptionTypeRevision.read(from: &buf), 
+                new: FfiConverterOptionTypeRevision.read(from: &buf), 
+                interdiff: FfiConverterSequenceTypeDiffHunk.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: DiffHunk, into buf: inout [UInt8]) {
-        FfiConverterTypeDiffRange.write(value.header, into: &buf)
-        FfiConverterOptionString.write(value.section, into: &buf)
-        FfiConverterSequenceTypeDiffLine.write(value.lines, into: &buf)
+    public static func write(_ value: CommitPair, into buf: inout [UInt8]) {
+        FfiConverterTypeCommitPairStatus.write(value.status, into: &buf)
+        FfiConverterOptionTypeRevision.write(value.old, into: &buf)
+        FfiConverterOptionTypeRevision.write(value.new, into: &buf)
+        FfiConverterSequenceTypeDiffHunk.write(value.interdiff, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiffHunk_lift(_ buf: RustBuffer) throws -> DiffHunk {
-    return try FfiConverterTypeDiffHunk.lift(buf)
+public func FfiConverterTypeCommitPair_lift(_ buf: RustBuffer) throws -> CommitPair {
+    return try FfiConverterTypeCommitPair.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiffHunk_lower(_ value: DiffHunk) -> RustBuffer {
-    return FfiConverterTypeDiffHunk.lower(value)
+public func FfiConverterTypeCommitPair_lower(_ value: CommitPair) -> RustBuffer {
+    return FfiConverterTypeCommitPair.lower(value)
 }
 
-public struct DiffLine {
-    public var kind: DiffLineKind
-    public var text: String
-    public var baseLine: UInt32?
-    public var headLine: UInt32?
-    public var highlights: [LineHighlight]
+
+public struct Diagnostic {
+    public var title: String
+    public var detail: String?
+    public var severity: Severity
+    public var location: FileRange
+    public var tags: [String]
+    public var suggestions: [Suggestion]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(kind: DiffLineKind, text: String, baseLine: UInt32?, headLine: UInt32?, highlights: [LineHighlight]) {
-        self.kind = kind
-        self.text = text
-        self.baseLine = baseLine
-        self.headLine = headLine
-        self.highlights = highlights
+    public init(title: String, detail: String?, severity: Severity, location: FileRange, tags: [String], suggestions: [Suggestion]) {
+        self.title = title
+        self.detail = detail
+        self.severity = severity
+        self.location = location
+        self.tags = tags
+        self.suggestions = suggestions
     }
 }
 
-extension DiffLine: Equatable, Hashable {
-    public static func == (lhs: DiffLine, rhs: DiffLine) -> Bool {
-        if lhs.kind != rhs.kind {
+
+
+extension Diagnostic: Equatable, Hashable {
+    public static func ==(lhs: Diagnostic, rhs: Diagnostic) -> Bool {
+        if lhs.title != rhs.title {
             return false
         }
-        if lhs.text != rhs.text {
+        if lhs.detail != rhs.detail {
             return false
         }
-        if lhs.baseLine != rhs.baseLine {
+        if lhs.severity != rhs.severity {
             return false
         }
-        if lhs.headLine != rhs.headLine {
+        if lhs.location != rhs.location {
             return false
         }
-        if lhs.highlights != rhs.highlights {
+        if lhs.tags != rhs.tags {
+            return false
+        }
+        if lhs.suggestions != rhs.suggestions {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(kind)
-        hasher.combine(text)
-        hasher.combine(baseLine)
-        hasher.combine(headLine)
-        hasher.combine(highlights)
+        hasher.combine(title)
+        hasher.combine(detail)
+        hasher.combine(severity)
+        hasher.combine(location)
+        hasher.combine(tags)
+        hasher.combine(suggestions)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDiffLine: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffLine {
+public struct FfiConverterTypeDiagnostic: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Diagnostic {
         return
-            try DiffLine(
-                kind: FfiConverterTypeDiffLineKind.read(from: &buf),
-                text: FfiConverterString.read(from: &buf),
-                baseLine: FfiConverterOptionUInt32.read(from: &buf),
-                headLine: FfiConverterOptionUInt32.read(from: &buf),
-                highlights: FfiConverterSequenceTypeLineHighlight.read(from: &buf)
-            )
+            try Diagnostic(
+                title: FfiConverterString.read(from: &buf), 
+                detail: FfiConverterOptionString.read(from: &buf), 
+                severity: FfiConverterTypeSeverity.read(from: &buf), 
+                location: FfiConverterTypeFileRange.read(from: &buf), 
+                tags: FfiConverterSequenceString.read(from: &buf), 
+                suggestions: FfiConverterSequenceTypeSuggestion.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: DiffLine, into buf: inout [UInt8]) {
-        FfiConverterTypeDiffLineKind.write(value.kind, into: &buf)
-        FfiConverterString.write(value.text, into: &buf)
-        FfiConverterOptionUInt32.write(value.baseLine, into: &buf)
-        FfiConverterOptionUInt32.write(value.headLine, into: &buf)
-        FfiConverterSequenceTypeLineHighlight.write(value.highlights, into: &buf)
+    public static func write(_ value: Diagnostic, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.title, into: &buf)
+        FfiConverterOptionString.write(value.detail, into: &buf)
+        FfiConverterTypeSeverity.write(value.severity, into: &buf)
+        FfiConverterTypeFileRange.write(value.location, into: &buf)
+        FfiConverterSequenceString.write(value.tags, into: &buf)
+        FfiConverterSequenceTypeSuggestion.write(value.suggestions, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiffLine_lift(_ buf: RustBuffer) throws -> DiffLine {
-    return try FfiConverterTypeDiffLine.lift(buf)
+public func FfiConverterTypeDiagnostic_lift(_ buf: RustBuffer) throws -> Diagnostic {
+    return try FfiConverterTypeDiagnostic.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiffLine_lower(_ value: DiffLine) -> RustBuffer {
-    return FfiConverterTypeDiffLine.lower(value)
+public func FfiConverterTypeDiagnostic_lower(_ value: Diagnostic) -> RustBuffer {
+    return FfiConverterTypeDiagnostic.lower(value)
 }
 
-public struct DiffRange {
-    public var baseStart: UInt32
-    public var baseLines: UInt32
-    public var headStart: UInt32
-    public var headLines: UInt32
+
+public struct Diff {
+    public var range: RevisionRange
+    public var files: [DiffFile]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(baseStart: UInt32, baseLines: UInt32, headStart: UInt32, headLines: UInt32) {
-        self.baseStart = baseStart
-        self.baseLines = baseLines
-        self.headStart = headStart
-        self.headLines = headLines
+    public init(range: RevisionRange, files: [DiffFile]) {
+        self.range = range
+        self.files = files
     }
 }
 
-extension DiffRange: Equatable, Hashable {
-    public static func == (lhs: DiffRange, rhs: DiffRange) -> Bool {
-        if lhs.baseStart != rhs.baseStart {
-            return false
-        }
-        if lhs.baseLines != rhs.baseLines {
-            return false
-        }
-        if lhs.headStart != rhs.headStart {
+
+
+extension Diff: Equatable, Hashable {
+    public static func ==(lhs: Diff, rhs: Diff) -> Bool {
+        if lhs.range != rhs.range {
             return false
         }
-        if lhs.headLines != rhs.headLines {
+        if lhs.files != rhs.files {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(baseStart)
-        hasher.combine(baseLines)
-        hasher.combine(headStart)
-        hasher.combine(headLines)
+        hasher.combine(range)
+        hasher.combine(files)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDiffRange: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffRange {
+public struct FfiConverterTypeDiff: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Diff {
         return
-            try DiffRange(
-                baseStart: FfiConverterUInt32.read(from: &buf),
-                baseLines: FfiConverterUInt32.read(from: &buf),
-                headStart: FfiConverterUInt32.read(from: &buf),
-                headLines: FfiConverterUInt32.read(from: &buf)
-            )
+            try Diff(
+                range: FfiConverterTypeRevisionRange.read(from: &buf), 
+                files: FfiConverterSequenceTypeDiffFile.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: DiffRange, into buf: inout [UInt8]) {
-        FfiConverterUInt32.write(value.baseStart, into: &buf)
-        FfiConverterUInt32.write(value.baseLines, into: &buf)
-        FfiConverterUInt32.write(value.headStart, into: &buf)
-        FfiConverterUInt32.write(value.headLines, into: &buf)
+    public static func write(_ value: Diff, into buf: inout [UInt8]) {
+        FfiConverterTypeRevisionRange.write(value.range, into: &buf)
+        FfiConverterSequenceTypeDiffFile.write(value.files, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiffRange_lift(_ buf: RustBuffer) throws -> DiffRange {
-    return try FfiConverterTypeDiffRange.lift(buf)
+public func FfiConverterTypeDiff_lift(_ buf: RustBuffer) throws -> Diff {
+    return try FfiConverterTypeDiff.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiffRange_lower(_ value: DiffRange) -> RustBuffer {
-    return FfiConverterTypeDiffRange.lower(value)
+public func FfiConverterTypeDiff_lower(_ value: Diff) -> RustBuffer {
+    return FfiConverterTypeDiff.lower(value)
 }
 
-public struct DiffStats {
-    public var additions: UInt32
-    public var deletions: UInt32
+
+public struct DiffFile {
+    public var path: String
+    public var oldPath: String?
+    public var status: FileStatus
+    public var stats: DiffStats
+    public var isBinary: Bool
+    public var oldMode: FileMode?
+    public var newMode: FileMode?
+    public var oldBlob: BlobInfo?
+    public var newBlob: BlobInfo?
+    public var submodule: SubmoduleChange?
+    public var classification: FileClassification
+    public var stage: StageState?
+    public var truncated: Bool
+    public var encoding: TextEncoding?
+    public var whitespaceOnly: Bool
+    public var lineEndingsOnly: Bool
+    public var structure: StructuralSummary?
+    public var hunks: [DiffHunk]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(additions: UInt32, deletions: UInt32) {
-        self.additions = additions
-        self.deletions = deletions
+    public init(path: String, oldPath: String?, status: FileStatus, stats: DiffStats, isBinary: Bool, oldMode: FileMode?, newMode: FileMode?, oldBlob: BlobInfo?, newBlob: BlobInfo?, submodule: SubmoduleChange?, classification: FileClassification, stage: StageState?, truncated: Bool, encoding: TextEncoding?, whitespaceOnly: Bool, lineEndingsOnly: Bool, structure: StructuralSummary?, hunks: [DiffHunk]) {
+        self.path = path
+        self.oldPath = oldPath
+        self.status = status
+        self.stats = stats
+        self.isBinary = isBinary
+        self.oldMode = oldMode
+        self.newMode = newMode
+        self.oldBlob = oldBlob
+        self.newBlob = newBlob
+        self.submodule = submodule
+        self.classification = classification
+        self.stage = stage
+        self.truncated = truncated
+        self.encoding = encoding
+        self.whitespaceOnly = whitespaceOnly
+        self.lineEndingsOnly = lineEndingsOnly
+        self.structure = structure
+        self.hunks = hunks
     }
 }
 
-extension DiffStats: Equatable, Hashable {
-    public static func == (lhs: DiffStats, rhs: DiffStats) -> Bool {
-        if lhs.additions != rhs.additions {
+
+
+extension DiffFile: Equatable, Hashable {
+    public static func ==(lhs: DiffFile, rhs: DiffFile) -> Bool {
+        if lhs.path != rhs.path {
             return false
         }
-        if lhs.deletions != rhs.deletions {
+        if lhs.oldPath != rhs.oldPath {
+            return false
+        }
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.stats != rhs.stats {
+            return false
+        }
+        if lhs.isBinary != rhs.isBinary {
+            return false
+        }
+        if lhs.oldMode != rhs.oldMode {
+            return false
+        }
+        if lhs.newMode != rhs.newMode {
+            return false
+        }
+        if lhs.oldBlob != rhs.oldBlob {
+            return false
+        }
+        if lhs.newBlob != rhs.newBlob {
+            return false
+        }
+        if lhs.submodule != rhs.submodule {
+            return false
+        }
+        if lhs.classification != rhs.classification {
+            return false
+        }
+        if lhs.stage != rhs.stage {
+            return false
+        }
+        if lhs.truncated != rhs.truncated {
+            return false
+        }
+        if lhs.encoding != rhs.encoding {
+            return false
+        }
+        if lhs.whitespaceOnly != rhs.whitespaceOnly {
+            return false
+        }
+        if lhs.lineEndingsOnly != rhs.lineEndingsOnly {
+            return false
+        }
+        if lhs.structure != rhs.structure {
+            return false
+        }
+        if lhs.hunks != rhs.hunks {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(additions)
-        hasher.combine(deletions)
+        hasher.combine(path)
+        hasher.combine(oldPath)
+        hasher.combine(status)
+        hasher.combine(stats)
+        hasher.combine(isBinary)
+        hasher.combine(oldMode)
+        hasher.combine(newMode)
+        hasher.combine(oldBlob)
+        hasher.combine(newBlob)
+        hasher.combine(submodule)
+        hasher.combine(classification)
+        hasher.combine(stage)
+        hasher.combine(truncated)
+        hasher.combine(encoding)
+        hasher.combine(whitespaceOnly)
+        hasher.combine(lineEndingsOnly)
+        hasher.combine(structure)
+        hasher.combine(hunks)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDiffStats: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffStats {
+public struct FfiConverterTypeDiffFile: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffFile {
         return
-            try DiffStats(
-                additions: FfiConverterUInt32.read(from: &buf),
-                deletions: FfiConverterUInt32.read(from: &buf)
-            )
+            try DiffFile(
+                path: FfiConverterString.read(from: &buf), 
+                oldPath: FfiConverterOptionString.read(from: &buf), 
+                status: FfiConverterTypeFileStatus.read(from: &buf), 
+                stats: FfiConverterTypeDiffStats.read(from: &buf), 
+                isBinary: FfiConverterBool.read(from: &buf), 
+                oldMode: FfiConverterOptionTypeFileMode.read(from: &buf), 
+                newMode: FfiConverterOptionTypeFileMode.read(from: &buf), 
+                oldBlob: FfiConverterOptionTypeBlobInfo.read(from: &buf), 
+                newBlob: FfiConverterOptionTypeBlobInfo.read(from: &buf), 
+                submodule: FfiConverterOptionTypeSubmoduleChange.read(from: &buf), 
+                classification: FfiConverterTypeFileClassification.read(from: &buf), 
+                stage: FfiConverterOptionTypeStageState.read(from: &buf), 
+                truncated: FfiConverterBool.read(from: &buf), 
+                encoding: FfiConverterOptionTypeTextEncoding.read(from: &buf), 
+                whitespaceOnly: FfiConverterBool.read(from: &buf), 
+                lineEndingsOnly: FfiConverterBool.read(from: &buf), 
+                structure: FfiConverterOptionTypeStructuralSummary.read(from: &buf), 
+                hunks: FfiConverterSequenceTypeDiffHunk.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: DiffStats, into buf: inout [UInt8]) {
-        FfiConverterUInt32.write(value.additions, into: &buf)
-        FfiConverterUInt32.write(value.deletions, into: &buf)
+    public static func write(_ value: DiffFile, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterOptionString.write(value.oldPath, into: &buf)
+        FfiConverterTypeFileStatus.write(value.status, into: &buf)
+        FfiConverterTypeDiffStats.write(value.stats, into: &buf)
+        FfiConverterBool.write(value.isBinary, into: &buf)
+        FfiConverterOptionTypeFileMode.write(value.oldMode, into: &buf)
+        FfiConverterOptionTypeFileMode.write(value.newMode, into: &buf)
+        FfiConverterOptionTypeBlobInfo.write(value.oldBlob, into: &buf)
+        FfiConverterOptionTypeBlobInfo.write(value.newBlob, into: &buf)
+        FfiConverterOptionTypeSubmoduleChange.write(value.submodule, into: &buf)
+        FfiConverterTypeFileClassification.write(value.classification, into: &buf)
+        FfiConverterOptionTypeStageState.write(value.stage, into: &buf)
+        FfiConverterBool.write(value.truncated, into: &buf)
+        FfiConverterOptionTypeTextEncoding.write(value.encoding, into: &buf)
+        FfiConverterBool.write(value.whitespaceOnly, into: &buf)
+        FfiConverterBool.write(value.lineEndingsOnly, into: &buf)
+        FfiConverterOptionTypeStructuralSummary.write(value.structure, into: &buf)
+        FfiConverterSequenceTypeDiffHunk.write(value.hunks, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiffStats_lift(_ buf: RustBuffer) throws -> DiffStats {
-    return try FfiConverterTypeDiffStats.lift(buf)
+public func FfiConverterTypeDiffFile_lift(_ buf: RustBuffer) throws -> DiffFile {
+    return try FfiConverterTypeDiffFile.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiffStats_lower(_ value: DiffStats) -> RustBuffer {
-    return FfiConverterTypeDiffStats.lower(value)
+public func FfiConverterTypeDiffFile_lower(_ value: DiffFile) -> RustBuffer {
+    return FfiConverterTypeDiffFile.lower(value)
 }
 
-public struct FileRange {
+
+public struct DiffFileSummary {
     public var path: String
-    public var side: DiffSide
-    public var range: Range
+    public var oldPath: String?
+    public var status: FileStatus
+    public var stats: DiffStats
+    public var isBinary: Bool
+    public var oldMode: FileMode?
+    public var newMode: FileMode?
+    public var oldBlob: BlobInfo?
+    public var newBlob: BlobInfo?
+    public var submodule: SubmoduleChange?
+    public var classification: FileClassification
+    public var stage: StageState?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(path: String, side: DiffSide, range: Range) {
+    public init(path: String, oldPath: String?, status: FileStatus, stats: DiffStats, isBinary: Bool, oldMode: FileMode?, newMode: FileMode?, oldBlob: BlobInfo?, newBlob: BlobInfo?, submodule: SubmoduleChange?, classification: FileClassification, stage: StageState?) {
         self.path = path
-        self.side = side
-        self.range = range
+        self.oldPath = oldPath
+        self.status = status
+        self.stats = stats
+        self.isBinary = isBinary
+        self.oldMode = oldMode
+        self.newMode = newMode
+        self.oldBlob = oldBlob
+        self.newBlob = newBlob
+        self.submodule = submodule
+        self.classification = classification
+        self.stage = stage
     }
 }
 
-extension FileRange: Equatable, Hashable {
-    public static func == (lhs: FileRange, rhs: FileRange) -> Bool {
+
+
+extension DiffFileSummary: Equatable, Hashable {
+    public static func ==(lhs: DiffFileSummary, rhs: DiffFileSummary) -> Bool {
         if lhs.path != rhs.path {
             return false
         }
-        if lhs.side != rhs.side {
+        if lhs.oldPath != rhs.oldPath {
             return false
         }
-        if lhs.range != rhs.range {
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.stats != rhs.stats {
+            return false
+        }
+        if lhs.isBinary != rhs.isBinary {
+            return false
+        }
+        if lhs.oldMode != rhs.oldMode {
+            return false
+        }
+        if lhs.newMode != rhs.newMode {
+            return false
+        }
+        if lhs.oldBlob != rhs.oldBlob {
+            return false
+        }
+        if lhs.newBlob != rhs.newBlob {
+            return false
+        }
+        if lhs.submodule != rhs.submodule {
+            return false
+        }
+        if lhs.classification != rhs.classification {
+            return false
+        }
+        if lhs.stage != rhs.stage {
             return false
         }
         return true
@@ -1340,1127 +1824,1492 @@ extension FileRange: Equatable, Hashable {
 
     public func hash(into hasher: inout Hasher) {
         hasher.combine(path)
-        hasher.combine(side)
-        hasher.combine(range)
+        hasher.combine(oldPath)
+        hasher.combine(status)
+        hasher.combine(stats)
+        hasher.combine(isBinary)
+        hasher.combine(oldMode)
+        hasher.combine(newMode)
+        hasher.combine(oldBlob)
+        hasher.combine(newBlob)
+        hasher.combine(submodule)
+        hasher.combine(classification)
+        hasher.combine(stage)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFileRange: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileRange {
+public struct FfiConverterTypeDiffFileSummary: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffFileSummary {
         return
-            try FileRange(
-                path: FfiConverterString.read(from: &buf),
-                side: FfiConverterTypeDiffSide.read(from: &buf),
-                range: FfiConverterTypeRange.read(from: &buf)
-            )
+            try DiffFileSummary(
+                path: FfiConverterString.read(from: &buf), 
+                oldPath: FfiConverterOptionString.read(from: &buf), 
+                status: FfiConverterTypeFileStatus.read(from: &buf), 
+                stats: FfiConverterTypeDiffStats.read(from: &buf), 
+                isBinary: FfiConverterBool.read(from: &buf), 
+                oldMode: FfiConverterOptionTypeFileMode.read(from: &buf), 
+                newMode: FfiConverterOptionTypeFileMode.read(from: &buf), 
+                oldBlob: FfiConverterOptionTypeBlobInfo.read(from: &buf), 
+                newBlob: FfiConverterOptionTypeBlobInfo.read(from: &buf), 
+                submodule: FfiConverterOptionTypeSubmoduleChange.read(from: &buf), 
+                classification: FfiConverterTypeFileClassification.read(from: &buf), 
+                stage: FfiConverterOptionTypeStageState.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: FileRange, into buf: inout [UInt8]) {
+    public static func write(_ value: DiffFileSummary, into buf: inout [UInt8]) {
         FfiConverterString.write(value.path, into: &buf)
-        FfiConverterTypeDiffSide.write(value.side, into: &buf)
-        FfiConverterTypeRange.write(value.range, into: &buf)
+        FfiConverterOptionString.write(value.oldPath, into: &buf)
+        FfiConverterTypeFileStatus.write(value.status, into: &buf)
+        FfiConverterTypeDiffStats.write(value.stats, into: &buf)
+        FfiConverterBool.write(value.isBinary, into: &buf)
+        FfiConverterOptionTypeFileMode.write(value.oldMode, into: &buf)
+        FfiConverterOptionTypeFileMode.write(value.newMode, into: &buf)
+        FfiConverterOptionTypeBlobInfo.write(value.oldBlob, into: &buf)
+        FfiConverterOptionTypeBlobInfo.write(value.newBlob, into: &buf)
+        FfiConverterOptionTypeSubmoduleChange.write(value.submodule, into: &buf)
+        FfiConverterTypeFileClassification.write(value.classification, into: &buf)
+        FfiConverterOptionTypeStageState.write(value.stage, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFileRange_lift(_ buf: RustBuffer) throws -> FileRange {
-    return try FfiConverterTypeFileRange.lift(buf)
+public func FfiConverterTypeDiffFileSummary_lift(_ buf: RustBuffer) throws -> DiffFileSummary {
+    return try FfiConverterTypeDiffFileSummary.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFileRange_lower(_ value: FileRange) -> RustBuffer {
-    return FfiConverterTypeFileRange.lower(value)
+public func FfiConverterTypeDiffFileSummary_lower(_ value: DiffFileSummary) -> RustBuffer {
+    return FfiConverterTypeDiffFileSummary.lower(value)
 }
 
-public struct LineHighlight {
-    public var startColumn: UInt32
-    public var endColumn: UInt32
+
+public struct DiffHunk {
+    public var header: DiffRange
+    public var section: String?
+    public var lines: [DiffLine]
+    public var whitespaceOnly: Bool
+    public var lineEndingsOnly: Bool
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(startColumn: UInt32, endColumn: UInt32) {
-        self.startColumn = startColumn
-        self.endColumn = endColumn
+    public init(header: DiffRange, section: String?, lines: [DiffLine], whitespaceOnly: Bool, lineEndingsOnly: Bool) {
+        self.header = header
+        self.section = section
+        self.lines = lines
+        self.whitespaceOnly = whitespaceOnly
+        self.lineEndingsOnly = lineEndingsOnly
     }
 }
 
-extension LineHighlight: Equatable, Hashable {
-    public static func == (lhs: LineHighlight, rhs: LineHighlight) -> Bool {
-        if lhs.startColumn != rhs.startColumn {
+
+
+extension DiffHunk: Equatable, Hashable {
+    public static func ==(lhs: DiffHunk, rhs: DiffHunk) -> Bool {
+        if lhs.header != rhs.header {
             return false
         }
-        if lhs.endColumn != rhs.endColumn {
+        if lhs.section != rhs.section {
+            return false
+        }
+        if lhs.lines != rhs.lines {
+            return false
+        }
+        if lhs.whitespaceOnly != rhs.whitespaceOnly {
+            return false
+        }
+        if lhs.lineEndingsOnly != rhs.lineEndingsOnly {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(startColumn)
-        hasher.combine(endColumn)
+        hasher.combine(header)
+        hasher.combine(section)
+        hasher.combine(lines)
+        hasher.combine(whitespaceOnly)
+        hasher.combine(lineEndingsOnly)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeLineHighlight: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LineHighlight {
+public struct FfiConverterTypeDiffHunk: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffHunk {
         return
-            try LineHighlight(
-                startColumn: FfiConverterUInt32.read(from: &buf),
-                endColumn: FfiConverterUInt32.read(from: &buf)
-            )
+            try DiffHunk(
+                header: FfiConverterTypeDiffRange.read(from: &buf), 
+                section: FfiConverterOptionString.read(from: &buf), 
+                lines: FfiConverterSequenceTypeDiffLine.read(from: &buf), 
+                whitespaceOnly: FfiConverterBool.read(from: &buf), 
+                lineEndingsOnly: FfiConverterBool.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: LineHighlight, into buf: inout [UInt8]) {
-        FfiConverterUInt32.write(value.startColumn, into: &buf)
-        FfiConverterUInt32.write(value.endColumn, into: &buf)
+    public static func write(_ value: DiffHunk, into buf: inout [UInt8]) {
+        FfiConverterTypeDiffRange.write(value.header, into: &buf)
+        FfiConverterOptionString.write(value.section, into: &buf)
+        FfiConverterSequenceTypeDiffLine.write(value.lines, into: &buf)
+        FfiConverterBool.write(value.whitespaceOnly, into: &buf)
+        FfiConverterBool.write(value.lineEndingsOnly, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLineHighlight_lift(_ buf: RustBuffer) throws -> LineHighlight {
-    return try FfiConverterTypeLineHighlight.lift(buf)
+public func FfiConverterTypeDiffHunk_lift(_ buf: RustBuffer) throws -> DiffHunk {
+    return try FfiConverterTypeDiffHunk.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLineHighlight_lower(_ value: LineHighlight) -> RustBuffer {
-    return FfiConverterTypeLineHighlight.lower(value)
+public func FfiConverterTypeDiffHunk_lower(_ value: DiffHunk) -> RustBuffer {
+    return FfiConverterTypeDiffHunk.lower(value)
 }
 
-public struct PluginCapabilities {
-    public var supportsListThreads: Bool
-    public var supportsAttachWithoutThread: Bool
-    public var supportsPolling: Bool
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(supportsListThreads: Bool, supportsAttachWithoutThread: Bool, supportsPolling: Bool) {
-        self.supportsListThreads = supportsListThreads
-        self.supportsAttachWithoutThread = supportsAttachWithoutThread
-        self.supportsPolling = supportsPolling
+public struct DiffLine {
+    public var kind: DiffLineKind
+    public var text: String
+    public var baseLine: UInt32?
+    public var headLine: UInt32?
+    public var highlights: [LineHighlight]
+    public var tokens: [SyntaxToken]
+    public var moved: LineMove?
+    public var truncated: Bool
+    public var lineEnding: LineEnding
+    public var enclosingSymbol: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(kind: DiffLineKind, text: String, baseLine: UInt32?, headLine: UInt32?, highlights: [LineHighlight], tokens: [SyntaxToken], moved: LineMove?, truncated: Bool, lineEnding: LineEnding, enclosingSymbol: String?) {
+        self.kind = kind
+        self.text = text
+        self.baseLine = baseLine
+        self.headLine = headLine
+        self.highlights = highlights
+        self.tokens = tokens
+        self.moved = moved
+        self.truncated = truncated
+        self.lineEnding = lineEnding
+        self.enclosingSymbol = enclosingSymbol
     }
 }
 
-extension PluginCapabilities: Equatable, Hashable {
-    public static func == (lhs: PluginCapabilities, rhs: PluginCapabilities) -> Bool {
-        if lhs.supportsListThreads != rhs.supportsListThreads {
+
+
+extension DiffLine: Equatable, Hashable {
+    public static func ==(lhs: DiffLine, rhs: DiffLine) -> Bool {
+        if lhs.kind != rhs.kind {
             return false
         }
-        if lhs.supportsAttachWithoutThread != rhs.supportsAttachWithoutThread {
+        if lhs.text != rhs.text {
             return false
         }
-        if lhs.supportsPolling != rhs.supportsPolling {
+        if lhs.baseLine != rhs.baseLine {
+            return false
+        }
+        if lhs.headLine != rhs.headLine {
+            return false
+        }
+        if lhs.highlights != rhs.highlights {
+            return false
+        }
+        if lhs.tokens != rhs.tokens {
+            return false
+        }
+        if lhs.moved != rhs.moved {
+            return false
+        }
+        if lhs.truncated != rhs.truncated {
+            return false
+        }
+        if lhs.lineEnding != rhs.lineEnding {
+            return false
+        }
+        if lhs.enclosingSymbol != rhs.enclosingSymbol {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(supportsListThreads)
-        hasher.combine(supportsAttachWithoutThread)
-        hasher.combine(supportsPolling)
+        hasher.combine(kind)
+        hasher.combine(text)
+        hasher.combine(baseLine)
+        hasher.combine(headLine)
+        hasher.combine(highlights)
+        hasher.combine(tokens)
+        hasher.combine(moved)
+        hasher.combine(truncated)
+        hasher.combine(lineEnding)
+        hasher.combine(enclosingSymbol)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypePluginCapabilities: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PluginCapabilities {
+public struct FfiConverterTypeDiffLine: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffLine {
         return
-            try PluginCapabilities(
-                supportsListThreads: FfiConverterBool.read(from: &buf),
-                supportsAttachWithoutThread: FfiConverterBool.read(from: &buf),
-                supportsPolling: FfiConverterBool.read(from: &buf)
-            )
+            try DiffLine(
+                kind: FfiConverterTypeDiffLineKind.read(from: &buf), 
+                text: FfiConverterString.read(from: &buf), 
+                baseLine: FfiConverterOptionUInt32.read(from: &buf), 
+                headLine: FfiConverterOptionUInt32.read(from: &buf), 
+                highlights: FfiConverterSequenceTypeLineHighlight.read(from: &buf), 
+                tokens: FfiConverterSequenceTypeSyntaxToken.read(from: &buf), 
+                moved: FfiConverterOptionTypeLineMove.read(from: &buf), 
+                truncated: FfiConverterBool.read(from: &buf), 
+                lineEnding: FfiConverterTypeLineEnding.read(from: &buf), 
+                enclosingSymbol: FfiConverterOptionString.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: PluginCapabilities, into buf: inout [UInt8]) {
-        FfiConverterBool.write(value.supportsListThreads, into: &buf)
-        FfiConverterBool.write(value.supportsAttachWithoutThread, into: &buf)
-        FfiConverterBool.write(value.supportsPolling, into: &buf)
+    public static func write(_ value: DiffLine, into buf: inout [UInt8]) {
+        FfiConverterTypeDiffLineKind.write(value.kind, into: &buf)
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterOptionUInt32.write(value.baseLine, into: &buf)
+        FfiConverterOptionUInt32.write(value.headLine, into: &buf)
+        FfiConverterSequenceTypeLineHighlight.write(value.highlights, into: &buf)
+        FfiConverterSequenceTypeSyntaxToken.write(value.tokens, into: &buf)
+        FfiConverterOptionTypeLineMove.write(value.moved, into: &buf)
+        FfiConverterBool.write(value.truncated, into: &buf)
+        FfiConverterTypeLineEnding.write(value.lineEnding, into: &buf)
+        FfiConverterOptionString.write(value.enclosingSymbol, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypePluginCapabilities_lift(_ buf: RustBuffer) throws -> PluginCapabilities {
-    return try FfiConverterTypePluginCapabilities.lift(buf)
+public func FfiConverterTypeDiffLine_lift(_ buf: RustBuffer) throws -> DiffLine {
+    return try FfiConverterTypeDiffLine.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypePluginCapabilities_lower(_ value: PluginCapabilities) -> RustBuffer {
-    return FfiConverterTypePluginCapabilities.lower(value)
+public func FfiConverterTypeDiffLine_lower(_ value: DiffLine) -> RustBuffer {
+    return FfiConverterTypeDiffLine.lower(value)
 }
 
-public struct PluginSession {
-    public var pluginId: String
-    public var sessionId: String
-    public var thread: ThreadRef?
+
+public struct DiffRange {
+    public var baseStart: UInt32
+    public var baseLines: UInt32
+    public var headStart: UInt32
+    public var headLines: UInt32
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(pluginId: String, sessionId: String, thread: ThreadRef?) {
-        self.pluginId = pluginId
-        self.sessionId = sessionId
-        self.thread = thread
+    public init(baseStart: UInt32, baseLines: UInt32, headStart: UInt32, headLines: UInt32) {
+        self.baseStart = baseStart
+        self.baseLines = baseLines
+        self.headStart = headStart
+        self.headLines = headLines
     }
 }
 
-extension PluginSession: Equatable, Hashable {
-    public static func == (lhs: PluginSession, rhs: PluginSession) -> Bool {
-        if lhs.pluginId != rhs.pluginId {
+
+
+extension DiffRange: Equatable, Hashable {
+    public static func ==(lhs: DiffRange, rhs: DiffRange) -> Bool {
+        if lhs.baseStart != rhs.baseStart {
             return false
         }
-        if lhs.sessionId != rhs.sessionId {
+        if lhs.baseLines != rhs.baseLines {
             return false
         }
-        if lhs.thread != rhs.thread {
+        if lhs.headStart != rhs.headStart {
+            return false
+        }
+        if lhs.headLines != rhs.headLines {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(pluginId)
-        hasher.combine(sessionId)
-        hasher.combine(thread)
+        hasher.combine(baseStart)
+        hasher.combine(baseLines)
+        hasher.combine(headStart)
+        hasher.combine(headLines)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypePluginSession: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PluginSession {
+public struct FfiConverterTypeDiffRange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffRange {
         return
-            try PluginSession(
-                pluginId: FfiConverterString.read(from: &buf),
-                sessionId: FfiConverterString.read(from: &buf),
-                thread: FfiConverterOptionTypeThreadRef.read(from: &buf)
-            )
+            try DiffRange(
+                baseStart: FfiConverterUInt32.read(from: &buf), 
+                baseLines: FfiConverterUInt32.read(from: &buf), 
+                headStart: FfiConverterUInt32.read(from: &buf), 
+                headLines: FfiConverterUInt32.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: PluginSession, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.pluginId, into: &buf)
-        FfiConverterString.write(value.sessionId, into: &buf)
-        FfiConverterOptionTypeThreadRef.write(value.thread, into: &buf)
+    public static func write(_ value: DiffRange, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.baseStart, into: &buf)
+        FfiConverterUInt32.write(value.baseLines, into: &buf)
+        FfiConverterUInt32.write(value.headStart, into: &buf)
+        FfiConverterUInt32.write(value.headLines, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypePluginSession_lift(_ buf: RustBuffer) throws -> PluginSession {
-    return try FfiConverterTypePluginSession.lift(buf)
+public func FfiConverterTypeDiffRange_lift(_ buf: RustBuffer) throws -> DiffRange {
+    return try FfiConverterTypeDiffRange.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypePluginSession_lower(_ value: PluginSession) -> RustBuffer {
-    return FfiConverterTypePluginSession.lower(value)
+public func FfiConverterTypeDiffRange_lower(_ value: DiffRange) -> RustBuffer {
+    return FfiConverterTypeDiffRange.lower(value)
 }
 
-public struct PluginSummary {
-    public var id: String
-    public var label: String
-    public var capabilities: PluginCapabilities
+
+public struct DiffRequest {
+    public var contextLines: UInt32
+    public var whitespace: WhitespaceMode
+    public var renameThreshold: UInt16
+    public var copyThreshold: UInt16
+    public var detectCopies: Bool
+    public var copiesFromUnmodified: Bool
+    public var syntaxHighlighting: Bool
+    public var submoduleLog: Bool
+    public var structuralSummary: Bool
+    public var enclosingSymbols: Bool
+    public var detectMoves: Bool
+    public var skipGeneratedHunks: Bool
+    public var includePaths: [String]
+    public var excludePaths: [String]
+    public var maxTotalBytes: UInt64?
+    public var maxFileLines: UInt32?
+    public var maxLineChars: UInt32?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(id: String, label: String, capabilities: PluginCapabilities) {
-        self.id = id
-        self.label = label
-        self.capabilities = capabilities
-    }
-}
-
-extension PluginSummary: Equatable, Hashable {
-    public static func == (lhs: PluginSummary, rhs: PluginSummary) -> Bool {
-        if lhs.id != rhs.id {
+    public init(contextLines: UInt32 = UInt32(3), whitespace: WhitespaceMode, renameThreshold: UInt16 = UInt16(50), copyThreshold: UInt16 = UInt16(100), detectCopies: Bool = true, copiesFromUnmodified: Bool = true, syntaxHighlighting: Bool = false, submoduleLog: Bool = false, structuralSummary: Bool = false, enclosingSymbols: Bool = false, detectMoves: Bool = false, skipGeneratedHunks: Bool = false, includePaths: [String] = [], excludePaths: [String] = [], maxTotalBytes: UInt64? = nil, maxFileLines: UInt32? = nil, maxLineChars: UInt32? = nil) {
+        self.contextLines = contextLines
+        self.whitespace = whitespace
+        self.renameThreshold = renameThreshold
+        self.copyThreshold = copyThreshold
+        self.detectCopies = detectCopies
+        self.copiesFromUnmodified = copiesFromUnmodified
+        self.syntaxHighlighting = syntaxHighlighting
+        self.submoduleLog = submoduleLog
+        self.structuralSummary = structuralSummary
+        self.enclosingSymbols = enclosingSymbols
+        self.detectMoves = detectMoves
+        self.skipGeneratedHunks = skipGeneratedHunks
+        self.includePaths = includePaths
+        self.excludePaths = excludePaths
+        self.maxTotalBytes = maxTotalBytes
+        self.maxFileLines = maxFileLines
+        self.maxLineChars = maxLineChars
+    }
+}
+
+
+
+extension DiffRequest: Equatable, Hashable {
+    public static func ==(lhs: DiffRequest, rhs: DiffRequest) -> Bool {
+        if lhs.contextLines != rhs.contextLines {
             return false
         }
-        if lhs.label != rhs.label {
+        if lhs.whitespace != rhs.whitespace {
             return false
         }
-        if lhs.capabilities != rhs.capabilities {
+        if lhs.renameThreshold != rhs.renameThreshold {
+            return false
+        }
+        if lhs.copyThreshold != rhs.copyThreshold {
+            return false
+        }
+        if lhs.detectCopies != rhs.detectCopies {
+            return false
+        }
+        if lhs.copiesFromUnmodified != rhs.copiesFromUnmodified {
+            return false
+        }
+        if lhs.syntaxHighlighting != rhs.syntaxHighlighting {
+            return false
+        }
+        if lhs.submoduleLog != rhs.submoduleLog {
+            return false
+        }
+        if lhs.structuralSummary != rhs.structuralSummary {
+            return false
+        }
+        if lhs.enclosingSymbols != rhs.enclosingSymbols {
+            return false
+        }
+        if lhs.detectMoves != rhs.detectMoves {
+            return false
+        }
+        if lhs.skipGeneratedHunks != rhs.skipGeneratedHunks {
+            return false
+        }
+        if lhs.includePaths != rhs.includePaths {
+            return false
+        }
+        if lhs.excludePaths != rhs.excludePaths {
+            return false
+        }
+        if lhs.maxTotalBytes != rhs.maxTotalBytes {
+            return false
+        }
+        if lhs.maxFileLines != rhs.maxFileLines {
+            return false
+        }
+        if lhs.maxLineChars != rhs.maxLineChars {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(id)
-        hasher.combine(label)
-        hasher.combine(capabilities)
+        hasher.combine(contextLines)
+        hasher.combine(whitespace)
+        hasher.combine(renameThreshold)
+        hasher.combine(copyThreshold)
+        hasher.combine(detectCopies)
+        hasher.combine(copiesFromUnmodified)
+        hasher.combine(syntaxHighlighting)
+        hasher.combine(submoduleLog)
+        hasher.combine(structuralSummary)
+        hasher.combine(enclosingSymbols)
+        hasher.combine(detectMoves)
+        hasher.combine(skipGeneratedHunks)
+        hasher.combine(includePaths)
+        hasher.combine(excludePaths)
+        hasher.combine(maxTotalBytes)
+        hasher.combine(maxFileLines)
+        hasher.combine(maxLineChars)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypePluginSummary: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PluginSummary {
+public struct FfiConverterTypeDiffRequest: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffRequest {
         return
-            try PluginSummary(
-                id: FfiConverterString.read(from: &buf),
-                label: FfiConverterString.read(from: &buf),
-                capabilities: FfiConverterTypePluginCapabilities.read(from: &buf)
-            )
+            try DiffRequest(
+                contextLines: FfiConverterUInt32.read(from: &buf), 
+                whitespace: FfiConverterTypeWhitespaceMode.read(from: &buf), 
+                renameThreshold: FfiConverterUInt16.read(from: &buf), 
+                copyThreshold: FfiConverterUInt16.read(from: &buf), 
+                detectCopies: FfiConverterBool.read(from: &buf), 
+                copiesFromUnmodified: FfiConverterBool.read(from: &buf), 
+                syntaxHighlighting: FfiConverterBool.read(from: &buf), 
+                submoduleLog: FfiConverterBool.read(from: &buf), 
+                structuralSummary: FfiConverterBool.read(from: &buf), 
+                enclosingSymbols: FfiConverterBool.read(from: &buf), 
+                detectMoves: FfiConverterBool.read(from: &buf), 
+                skipGeneratedHunks: FfiConverterBool.read(from: &buf), 
+                includePaths: FfiConverterSequenceString.read(from: &buf), 
+                excludePaths: FfiConverterSequenceString.read(from: &buf), 
+                maxTotalBytes: FfiConverterOptionUInt64.read(from: &buf), 
+                maxFileLines: FfiConverterOptionUInt32.read(from: &buf), 
+                maxLineChars: FfiConverterOptionUInt32.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: PluginSummary, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.id, into: &buf)
-        FfiConverterString.write(value.label, into: &buf)
-        FfiConverterTypePluginCapabilities.write(value.capabilities, into: &buf)
+    public static func write(_ value: DiffRequest, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.contextLines, into: &buf)
+        FfiConverterTypeWhitespaceMode.write(value.whitespace, into: &buf)
+        FfiConverterUInt16.write(value.renameThreshold, into: &buf)
+        FfiConverterUInt16.write(value.copyThreshold, into: &buf)
+        FfiConverterBool.write(value.detectCopies, into: &buf)
+        FfiConverterBool.write(value.copiesFromUnmodified, into: &buf)
+        FfiConverterBool.write(value.syntaxHighlighting, into: &buf)
+        FfiConverterBool.write(value.submoduleLog, into: &buf)
+        FfiConverterBool.write(value.structuralSummary, into: &buf)
+        FfiConverterBool.write(value.enclosingSymbols, into: &buf)
+        FfiConverterBool.write(value.detectMoves, into: &buf)
+        FfiConverterBool.write(value.skipGeneratedHunks, into: &buf)
+        FfiConverterSequenceString.write(value.includePaths, into: &buf)
+        FfiConverterSequenceString.write(value.excludePaths, into: &buf)
+        FfiConverterOptionUInt64.write(value.maxTotalBytes, into: &buf)
+        FfiConverterOptionUInt32.write(value.maxFileLines, into: &buf)
+        FfiConverterOptionUInt32.write(value.maxLineChars, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypePluginSummary_lift(_ buf: RustBuffer) throws -> PluginSummary {
-    return try FfiConverterTypePluginSummary.lift(buf)
+public func FfiConverterTypeDiffRequest_lift(_ buf: RustBuffer) throws -> DiffRequest {
+    return try FfiConverterTypeDiffRequest.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypePluginSummary_lower(_ value: PluginSummary) -> RustBuffer {
-    return FfiConverterTypePluginSummary.lower(value)
+public func FfiConverterTypeDiffRequest_lower(_ value: DiffRequest) -> RustBuffer {
+    return FfiConverterTypeDiffRequest.lower(value)
 }
 
-public struct Position {
-    public var line: UInt32
-    public var column: UInt32?
+
+public struct DiffStats {
+    public var additions: UInt32
+    public var deletions: UInt32
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(line: UInt32, column: UInt32?) {
-        self.line = line
-        self.column = column
+    public init(additions: UInt32, deletions: UInt32) {
+        self.additions = additions
+        self.deletions = deletions
     }
 }
 
-extension Position: Equatable, Hashable {
-    public static func == (lhs: Position, rhs: Position) -> Bool {
-        if lhs.line != rhs.line {
+
+
+extension DiffStats: Equatable, Hashable {
+    public static func ==(lhs: DiffStats, rhs: DiffStats) -> Bool {
+        if lhs.additions != rhs.additions {
             return false
         }
-        if lhs.column != rhs.column {
+        if lhs.deletions != rhs.deletions {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(line)
-        hasher.combine(column)
+        hasher.combine(additions)
+        hasher.combine(deletions)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypePosition: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Position {
+public struct FfiConverterTypeDiffStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffStats {
         return
-            try Position(
-                line: FfiConverterUInt32.read(from: &buf),
-                column: FfiConverterOptionUInt32.read(from: &buf)
-            )
+            try DiffStats(
+                additions: FfiConverterUInt32.read(from: &buf), 
+                deletions: FfiConverterUInt32.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: Position, into buf: inout [UInt8]) {
-        FfiConverterUInt32.write(value.line, into: &buf)
-        FfiConverterOptionUInt32.write(value.column, into: &buf)
+    public static func write(_ value: DiffStats, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.additions, into: &buf)
+        FfiConverterUInt32.write(value.deletions, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypePosition_lift(_ buf: RustBuffer) throws -> Position {
-    return try FfiConverterTypePosition.lift(buf)
+public func FfiConverterTypeDiffStats_lift(_ buf: RustBuffer) throws -> DiffStats {
+    return try FfiConverterTypeDiffStats.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypePosition_lower(_ value: Position) -> RustBuffer {
-    return FfiConverterTypePosition.lower(value)
+public func FfiConverterTypeDiffStats_lower(_ value: DiffStats) -> RustBuffer {
+    return FfiConverterTypeDiffStats.lower(value)
 }
 
-public struct Range {
-    public var start: Position
-    public var end: Position
+
+public struct DiffSummary {
+    public var range: RevisionRange
+    public var workspace: Bool
+    public var files: [DiffFileSummary]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(start: Position, end: Position) {
-        self.start = start
-        self.end = end
+    public init(range: RevisionRange, workspace: Bool, files: [DiffFileSummary]) {
+        self.range = range
+        self.workspace = workspace
+        self.files = files
     }
 }
 
-extension Range: Equatable, Hashable {
-    public static func == (lhs: Range, rhs: Range) -> Bool {
-        if lhs.start != rhs.start {
+
+
+extension DiffSummary: Equatable, Hashable {
+    public static func ==(lhs: DiffSummary, rhs: DiffSummary) -> Bool {
+        if lhs.range != rhs.range {
             return false
         }
-        if lhs.end != rhs.end {
+        if lhs.workspace != rhs.workspace {
+            return false
+        }
+        if lhs.files != rhs.files {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(start)
-        hasher.combine(end)
+        hasher.combine(range)
+        hasher.combine(workspace)
+        hasher.combine(files)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRange: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Range {
+public struct FfiConverterTypeDiffSummary: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffSummary {
         return
-            try Range(
-                start: FfiConverterTypePosition.read(from: &buf),
-                end: FfiConverterTypePosition.read(from: &buf)
-            )
+            try DiffSummary(
+                range: FfiConverterTypeRevisionRange.read(from: &buf), 
+                workspace: FfiConverterBool.read(from: &buf), 
+                files: FfiConverterSequenceTypeDiffFileSummary.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: Range, into buf: inout [UInt8]) {
-        FfiConverterTypePosition.write(value.start, into: &buf)
-        FfiConverterTypePosition.write(value.end, into: &buf)
+    public static func write(_ value: DiffSummary, into buf: inout [UInt8]) {
+        FfiConverterTypeRevisionRange.write(value.range, into: &buf)
+        FfiConverterBool.write(value.workspace, into: &buf)
+        FfiConverterSequenceTypeDiffFileSummary.write(value.files, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRange_lift(_ buf: RustBuffer) throws -> Range {
-    return try FfiConverterTypeRange.lift(buf)
+public func FfiConverterTypeDiffSummary_lift(_ buf: RustBuffer) throws -> DiffSummary {
+    return try FfiConverterTypeDiffSummary.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRange_lower(_ value: Range) -> RustBuffer {
-    return FfiConverterTypeRange.lower(value)
+public func FfiConverterTypeDiffSummary_lower(_ value: DiffSummary) -> RustBuffer {
+    return FfiConverterTypeDiffSummary.lower(value)
 }
 
-public struct RepositoryInfo {
-    public var root: String
-    public var defaultBranch: String?
+
+public struct FileBlobs {
+    public var path: String
+    public var old: Data?
+    public var new: Data?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(root: String, defaultBranch: String?) {
-        self.root = root
-        self.defaultBranch = defaultBranch
+    public init(path: String, old: Data?, new: Data?) {
+        self.path = path
+        self.old = old
+        self.new = new
     }
 }
 
-extension RepositoryInfo: Equatable, Hashable {
-    public static func == (lhs: RepositoryInfo, rhs: RepositoryInfo) -> Bool {
-        if lhs.root != rhs.root {
+
+
+extension FileBlobs: Equatable, Hashable {
+    public static func ==(lhs: FileBlobs, rhs: FileBlobs) -> Bool {
+        if lhs.path != rhs.path {
             return false
         }
-        if lhs.defaultBranch != rhs.defaultBranch {
+        if lhs.old != rhs.old {
+            return false
+        }
+        if lhs.new != rhs.new {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(root)
-        hasher.combine(defaultBranch)
+        hasher.combine(path)
+        hasher.combine(old)
+        hasher.combine(new)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRepositoryInfo: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RepositoryInfo {
+public struct FfiConverterTypeFileBlobs: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileBlobs {
         return
-            try RepositoryInfo(
-                root: FfiConverterString.read(from: &buf),
-                defaultBranch: FfiConverterOptionString.read(from: &buf)
-            )
+            try FileBlobs(
+                path: FfiConverterString.read(from: &buf), 
+                old: FfiConverterOptionData.read(from: &buf), 
+                new: FfiConverterOptionData.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: RepositoryInfo, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.root, into: &buf)
-        FfiConverterOptionString.write(value.defaultBranch, into: &buf)
+    public static func write(_ value: FileBlobs, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterOptionData.write(value.old, into: &buf)
+        FfiConverterOptionData.write(value.new, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRepositoryInfo_lift(_ buf: RustBuffer) throws -> RepositoryInfo {
-    return try FfiConverterTypeRepositoryInfo.lift(buf)
+public func FfiConverterTypeFileBlobs_lift(_ buf: RustBuffer) throws -> FileBlobs {
+    return try FfiConverterTypeFileBlobs.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRepositoryInfo_lower(_ value: RepositoryInfo) -> RustBuffer {
-    return FfiConverterTypeRepositoryInfo.lower(value)
+public func FfiConverterTypeFileBlobs_lower(_ value: FileBlobs) -> RustBuffer {
+    return FfiConverterTypeFileBlobs.lower(value)
 }
 
-public struct RepositorySnapshot {
-    public var info: RepositoryInfo
-    public var workspace: WorkspaceStatus
-    public var revisions: RevisionRange?
+
+public struct FileContents {
+    public var path: String
+    public var version: FileVersion
+    public var oid: String
+    public var size: UInt64
+    public var isBinary: Bool
+    public var text: String?
+    public var encoding: TextEncoding?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(info: RepositoryInfo, workspace: WorkspaceStatus, revisions: RevisionRange?) {
-        self.info = info
-        self.workspace = workspace
-        self.revisions = revisions
+    public init(path: String, version: FileVersion, oid: String, size: UInt64, isBinary: Bool, text: String?, encoding: TextEncoding?) {
+        self.path = path
+        self.version = version
+        self.oid = oid
+        self.size = size
+        self.isBinary = isBinary
+        self.text = text
+        self.encoding = encoding
     }
 }
 
-extension RepositorySnapshot: Equatable, Hashable {
-    public static func == (lhs: RepositorySnapshot, rhs: RepositorySnapshot) -> Bool {
-        if lhs.info != rhs.info {
+
+
+extension FileContents: Equatable, Hashable {
+    public static func ==(lhs: FileContents, rhs: FileContents) -> Bool {
+        if lhs.path != rhs.path {
             return false
         }
-        if lhs.workspace != rhs.workspace {
+        if lhs.version != rhs.version {
             return false
         }
-        if lhs.revisions != rhs.revisions {
+        if lhs.oid != rhs.oid {
+            return false
+        }
+        if lhs.size != rhs.size {
+            return false
+        }
+        if lhs.isBinary != rhs.isBinary {
+            return false
+        }
+        if lhs.text != rhs.text {
+            return false
+        }
+        if lhs.encoding != rhs.encoding {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(info)
-        hasher.combine(workspace)
-        hasher.combine(revisions)
+        hasher.combine(path)
+        hasher.combine(version)
+        hasher.combine(oid)
+        hasher.combine(size)
+        hasher.combine(isBinary)
+        hasher.combine(text)
+        hasher.combine(encoding)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRepositorySnapshot: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RepositorySnapshot {
+public struct FfiConverterTypeFileContents: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileContents {
         return
-            try RepositorySnapshot(
-                info: FfiConverterTypeRepositoryInfo.read(from: &buf),
-                workspace: FfiConverterTypeWorkspaceStatus.read(from: &buf),
-                revisions: FfiConverterOptionTypeRevisionRange.read(from: &buf)
-            )
+            try FileContents(
+                path: FfiConverterString.read(from: &buf), 
+                version: FfiConverterTypeFileVersion.read(from: &buf), 
+                oid: FfiConverterString.read(from: &buf), 
+                size: FfiConverterUInt64.read(from: &buf), 
+                isBinary: FfiConverterBool.read(from: &buf), 
+                text: FfiConverterOptionString.read(from: &buf), 
+                encoding: FfiConverterOptionTypeTextEncoding.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: RepositorySnapshot, into buf: inout [UInt8]) {
-        FfiConverterTypeRepositoryInfo.write(value.info, into: &buf)
-        FfiConverterTypeWorkspaceStatus.write(value.workspace, into: &buf)
-        FfiConverterOptionTypeRevisionRange.write(value.revisions, into: &buf)
+    public static func write(_ value: FileContents, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterTypeFileVersion.write(value.version, into: &buf)
+        FfiConverterString.write(value.oid, into: &buf)
+        FfiConverterUInt64.write(value.size, into: &buf)
+        FfiConverterBool.write(value.isBinary, into: &buf)
+        FfiConverterOptionString.write(value.text, into: &buf)
+        FfiConverterOptionTypeTextEncoding.write(value.encoding, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRepositorySnapshot_lift(_ buf: RustBuffer) throws -> RepositorySnapshot {
-    return try FfiConverterTypeRepositorySnapshot.lift(buf)
+public func FfiConverterTypeFileContents_lift(_ buf: RustBuffer) throws -> FileContents {
+    return try FfiConverterTypeFileContents.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRepositorySnapshot_lower(_ value: RepositorySnapshot) -> RustBuffer {
-    return FfiConverterTypeRepositorySnapshot.lower(value)
+public func FfiConverterTypeFileContents_lower(_ value: FileContents) -> RustBuffer {
+    return FfiConverterTypeFileContents.lower(value)
 }
 
-public struct ReviewPayload {
-    public var summary: String?
-    public var actions: [String]
-    public var comments: [CommentDraft]
-    public var diagnostics: [Diagnostic]
+
+public struct FileHunks {
+    public var path: String
+    public var hunks: [DiffHunk]
+    public var truncated: Bool
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(summary: String?, actions: [String], comments: [CommentDraft], diagnostics: [Diagnostic]) {
-        self.summary = summary
-        self.actions = actions
-        self.comments = comments
-        self.diagnostics = diagnostics
+    public init(path: String, hunks: [DiffHunk], truncated: Bool) {
+        self.path = path
+        self.hunks = hunks
+        self.truncated = truncated
     }
 }
 
-extension ReviewPayload: Equatable, Hashable {
-    public static func == (lhs: ReviewPayload, rhs: ReviewPayload) -> Bool {
-        if lhs.summary != rhs.summary {
-            return false
-        }
-        if lhs.actions != rhs.actions {
+
+
+extension FileHunks: Equatable, Hashable {
+    public static func ==(lhs: FileHunks, rhs: FileHunks) -> Bool {
+        if lhs.path != rhs.path {
             return false
         }
-        if lhs.comments != rhs.comments {
+        if lhs.hunks != rhs.hunks {
             return false
         }
-        if lhs.diagnostics != rhs.diagnostics {
+        if lhs.truncated != rhs.truncated {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(summary)
-        hasher.combine(actions)
-        hasher.combine(comments)
-        hasher.combine(diagnostics)
+        hasher.combine(path)
+        hasher.combine(hunks)
+        hasher.combine(truncated)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeReviewPayload: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ReviewPayload {
+public struct FfiConverterTypeFileHunks: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileHunks {
         return
-            try ReviewPayload(
-                summary: FfiConverterOptionString.read(from: &buf),
-                actions: FfiConverterSequenceString.read(from: &buf),
-                comments: FfiConverterSequenceTypeCommentDraft.read(from: &buf),
-                diagnostics: FfiConverterSequenceTypeDiagnostic.read(from: &buf)
-            )
+            try FileHunks(
+                path: FfiConverterString.read(from: &buf), 
+                hunks: FfiConverterSequenceTypeDiffHunk.read(from: &buf), 
+                truncated: FfiConverterBool.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: ReviewPayload, into buf: inout [UInt8]) {
-        FfiConverterOptionString.write(value.summary, into: &buf)
-        FfiConverterSequenceString.write(value.actions, into: &buf)
-        FfiConverterSequenceTypeCommentDraft.write(value.comments, into: &buf)
-        FfiConverterSequenceTypeDiagnostic.write(value.diagnostics, into: &buf)
+    public static func write(_ value: FileHunks, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterSequenceTypeDiffHunk.write(value.hunks, into: &buf)
+        FfiConverterBool.write(value.truncated, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeReviewPayload_lift(_ buf: RustBuffer) throws -> ReviewPayload {
-    return try FfiConverterTypeReviewPayload.lift(buf)
+public func FfiConverterTypeFileHunks_lift(_ buf: RustBuffer) throws -> FileHunks {
+    return try FfiConverterTypeFileHunks.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeReviewPayload_lower(_ value: ReviewPayload) -> RustBuffer {
-    return FfiConverterTypeReviewPayload.lower(value)
+public func FfiConverterTypeFileHunks_lower(_ value: FileHunks) -> RustBuffer {
+    return FfiConverterTypeFileHunks.lower(value)
 }
 
-public struct Revision {
-    public var oid: String
-    public var reference: String?
-    public var summary: String?
-    public var author: Signature?
-    public var committer: Signature?
-    public var timestamp: Int64?
+
+public struct FileRange {
+    public var path: String
+    public var side: DiffSide
+    public var range: Range
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(oid: String, reference: String?, summary: String?, author: Signature?, committer: Signature?, timestamp: Int64?) {
-        self.oid = oid
-        self.reference = reference
-        self.summary = summary
-        self.author = author
-        self.committer = committer
-        self.timestamp = timestamp
+    public init(path: String, side: DiffSide, range: Range) {
+        self.path = path
+        self.side = side
+        self.range = range
     }
 }
 
-extension Revision: Equatable, Hashable {
-    public static func == (lhs: Revision, rhs: Revision) -> Bool {
-        if lhs.oid != rhs.oid {
-            return false
-        }
-        if lhs.reference != rhs.reference {
-            return false
-        }
-        if lhs.summary != rhs.summary {
+
+
+extension FileRange: Equatable, Hashable {
+    public static func ==(lhs: FileRange, rhs: FileRange) -> Bool {
+        if lhs.path != rhs.path {
             return false
         }
-        if lhs.author != rhs.author {
+        if lhs.side != rhs.side {
             return false
         }
-        if lhs.committer != rhs.committer {
-            return false
-        }
-        if lhs.timestamp != rhs.timestamp {
+        if lhs.range != rhs.range {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(oid)
-        hasher.combine(reference)
-        hasher.combine(summary)
-        hasher.combine(author)
-        hasher.combine(committer)
-        hasher.combine(timestamp)
+        hasher.combine(path)
+        hasher.combine(side)
+        hasher.combine(range)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRevision: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Revision {
+public struct FfiConverterTypeFileRange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileRange {
         return
-            try Revision(
-                oid: FfiConverterString.read(from: &buf),
-                reference: FfiConverterOptionString.read(from: &buf),
-                summary: FfiConverterOptionString.read(from: &buf),
-                author: FfiConverterOptionTypeSignature.read(from: &buf),
-                committer: FfiConverterOptionTypeSignature.read(from: &buf),
-                timestamp: FfiConverterOptionInt64.read(from: &buf)
-            )
+            try FileRange(
+                path: FfiConverterString.read(from: &buf), 
+                side: FfiConverterTypeDiffSide.read(from: &buf), 
+                range: FfiConverterTypeRange.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: Revision, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.oid, into: &buf)
-        FfiConverterOptionString.write(value.reference, into: &buf)
-        FfiConverterOptionString.write(value.summary, into: &buf)
-        FfiConverterOptionTypeSignature.write(value.author, into: &buf)
-        FfiConverterOptionTypeSignature.write(value.committer, into: &buf)
-        FfiConverterOptionInt64.write(value.timestamp, into: &buf)
+    public static func write(_ value: FileRange, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterTypeDiffSide.write(value.side, into: &buf)
+        FfiConverterTypeRange.write(value.range, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRevision_lift(_ buf: RustBuffer) throws -> Revision {
-    return try FfiConverterTypeRevision.lift(buf)
+public func FfiConverterTypeFileRange_lift(_ buf: RustBuffer) throws -> FileRange {
+    return try FfiConverterTypeFileRange.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRevision_lower(_ value: Revision) -> RustBuffer {
-    return FfiConverterTypeRevision.lower(value)
+public func FfiConverterTypeFileRange_lower(_ value: FileRange) -> RustBuffer {
+    return FfiConverterTypeFileRange.lower(value)
 }
 
-public struct RevisionProgress {
-    public var state: RevisionState
-    public var detail: String?
+
+public struct ImageInfo {
+    public var format: ImageFormat
+    public var width: UInt32
+    public var height: UInt32
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(state: RevisionState, detail: String?) {
-        self.state = state
-        self.detail = detail
+    public init(format: ImageFormat, width: UInt32, height: UInt32) {
+        self.format = format
+        self.width = width
+        self.height = height
     }
 }
 
-extension RevisionProgress: Equatable, Hashable {
-    public static func == (lhs: RevisionProgress, rhs: RevisionProgress) -> Bool {
-        if lhs.state != rhs.state {
+
+
+extension ImageInfo: Equatable, Hashable {
+    public static func ==(lhs: ImageInfo, rhs: ImageInfo) -> Bool {
+        if lhs.format != rhs.format {
             return false
         }
-        if lhs.detail != rhs.detail {
+        if lhs.width != rhs.width {
+            return false
+        }
+        if lhs.height != rhs.height {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(state)
-        hasher.combine(detail)
+        hasher.combine(format)
+        hasher.combine(width)
+        hasher.combine(height)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRevisionProgress: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RevisionProgress {
+public struct FfiConverterTypeImageInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ImageInfo {
         return
-            try RevisionProgress(
-                state: FfiConverterTypeRevisionState.read(from: &buf),
-                detail: FfiConverterOptionString.read(from: &buf)
-            )
+            try ImageInfo(
+                format: FfiConverterTypeImageFormat.read(from: &buf), 
+                width: FfiConverterUInt32.read(from: &buf), 
+                height: FfiConverterUInt32.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: RevisionProgress, into buf: inout [UInt8]) {
-        FfiConverterTypeRevisionState.write(value.state, into: &buf)
-        FfiConverterOptionString.write(value.detail, into: &buf)
+    public static func write(_ value: ImageInfo, into buf: inout [UInt8]) {
+        FfiConverterTypeImageFormat.write(value.format, into: &buf)
+        FfiConverterUInt32.write(value.width, into: &buf)
+        FfiConverterUInt32.write(value.height, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRevisionProgress_lift(_ buf: RustBuffer) throws -> RevisionProgress {
-    return try FfiConverterTypeRevisionProgress.lift(buf)
+public func FfiConverterTypeImageInfo_lift(_ buf: RustBuffer) throws -> ImageInfo {
+    return try FfiConverterTypeImageInfo.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRevisionProgress_lower(_ value: RevisionProgress) -> RustBuffer {
-    return FfiConverterTypeRevisionProgress.lower(value)
+public func FfiConverterTypeImageInfo_lower(_ value: ImageInfo) -> RustBuffer {
+    return FfiConverterTypeImageInfo.lower(value)
 }
 
-public struct RevisionRange {
-    public var base: Revision?
-    public var head: Revision
+
+public struct LineHighlight {
+    public var startColumn: UInt32
+    public var endColumn: UInt32
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(base: Revision?, head: Revision) {
-        self.base = base
-        self.head = head
+    public init(startColumn: UInt32, endColumn: UInt32) {
+        self.startColumn = startColumn
+        self.endColumn = endColumn
     }
 }
 
-extension RevisionRange: Equatable, Hashable {
-    public static func == (lhs: RevisionRange, rhs: RevisionRange) -> Bool {
-        if lhs.base != rhs.base {
+
+
+extension LineHighlight: Equatable, Hashable {
+    public static func ==(lhs: LineHighlight, rhs: LineHighlight) -> Bool {
+        if lhs.startColumn != rhs.startColumn {
             return false
         }
-        if lhs.head != rhs.head {
+        if lhs.endColumn != rhs.endColumn {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(base)
-        hasher.combine(head)
+        hasher.combine(startColumn)
+        hasher.combine(endColumn)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRevisionRange: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RevisionRange {
+public struct FfiConverterTypeLineHighlight: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LineHighlight {
         return
-            try RevisionRange(
-                base: FfiConverterOptionTypeRevision.read(from: &buf),
-                head: FfiConverterTypeRevision.read(from: &buf)
-            )
+            try LineHighlight(
+                startColumn: FfiConverterUInt32.read(from: &buf), 
+                endColumn: FfiConverterUInt32.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: RevisionRange, into buf: inout [UInt8]) {
-        FfiConverterOptionTypeRevision.write(value.base, into: &buf)
-        FfiConverterTypeRevision.write(value.head, into: &buf)
+    public static func write(_ value: LineHighlight, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.startColumn, into: &buf)
+        FfiConverterUInt32.write(value.endColumn, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRevisionRange_lift(_ buf: RustBuffer) throws -> RevisionRange {
-    return try FfiConverterTypeRevisionRange.lift(buf)
+public func FfiConverterTypeLineHighlight_lift(_ buf: RustBuffer) throws -> LineHighlight {
+    return try FfiConverterTypeLineHighlight.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRevisionRange_lower(_ value: RevisionRange) -> RustBuffer {
-    return FfiConverterTypeRevisionRange.lower(value)
+public func FfiConverterTypeLineHighlight_lower(_ value: LineHighlight) -> RustBuffer {
+    return FfiConverterTypeLineHighlight.lower(value)
 }
 
-public struct Signature {
-    public var name: String
-    public var email: String?
+
+public struct LineMove {
+    public var block: UInt32
+    public var path: String
+    public var line: UInt32
+    public var edited: Bool
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(name: String, email: String?) {
-        self.name = name
-        self.email = email
+    public init(block: UInt32, path: String, line: UInt32, edited: Bool) {
+        self.block = block
+        self.path = path
+        self.line = line
+        self.edited = edited
     }
 }
 
-extension Signature: Equatable, Hashable {
-    public static func == (lhs: Signature, rhs: Signature) -> Bool {
-        if lhs.name != rhs.name {
+
+
+extension LineMove: Equatable, Hashable {
+    public static func ==(lhs: LineMove, rhs: LineMove) -> Bool {
+        if lhs.block != rhs.block {
             return false
         }
-        if lhs.email != rhs.email {
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.line != rhs.line {
+            return false
+        }
+        if lhs.edited != rhs.edited {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(name)
-        hasher.combine(email)
+        hasher.combine(block)
+        hasher.combine(path)
+        hasher.combine(line)
+        hasher.combine(edited)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSignature: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Signature {
+public struct FfiConverterTypeLineMove: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LineMove {
         return
-            try Signature(
-                name: FfiConverterString.read(from: &buf),
-                email: FfiConverterOptionString.read(from: &buf)
-            )
+            try LineMove(
+                block: FfiConverterUInt32.read(from: &buf), 
+                path: FfiConverterString.read(from: &buf), 
+                line: FfiConverterUInt32.read(from: &buf), 
+                edited: FfiConverterBool.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: Signature, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterOptionString.write(value.email, into: &buf)
+    public static func write(_ value: LineMove, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.block, into: &buf)
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterUInt32.write(value.line, into: &buf)
+        FfiConverterBool.write(value.edited, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSignature_lift(_ buf: RustBuffer) throws -> Signature {
-    return try FfiConverterTypeSignature.lift(buf)
+public func FfiConverterTypeLineMove_lift(_ buf: RustBuffer) throws -> LineMove {
+    return try FfiConverterTypeLineMove.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSignature_lower(_ value: Signature) -> RustBuffer {
-    return FfiConverterTypeSignature.lower(value)
+public func FfiConverterTypeLineMove_lower(_ value: LineMove) -> RustBuffer {
+    return FfiConverterTypeLineMove.lower(value)
 }
 
-public struct SubmissionResult {
-    public var revisionStarted: Bool
-    public var reference: String?
-    public var message: String?
+
+public struct PatchSelection {
+    public var path: String
+    public var hunks: [UInt32]?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(revisionStarted: Bool, reference: String?, message: String?) {
-        self.revisionStarted = revisionStarted
-        self.reference = reference
-        self.message = message
+    public init(path: String, hunks: [UInt32]?) {
+        self.path = path
+        self.hunks = hunks
     }
 }
 
-extension SubmissionResult: Equatable, Hashable {
-    public static func == (lhs: SubmissionResult, rhs: SubmissionResult) -> Bool {
-        if lhs.revisionStarted != rhs.revisionStarted {
-            return false
-        }
-        if lhs.reference != rhs.reference {
+
+
+extension PatchSelection: Equatable, Hashable {
+    public static func ==(lhs: PatchSelection, rhs: PatchSelection) -> Bool {
+        if lhs.path != rhs.path {
             return false
         }
-        if lhs.message != rhs.message {
+        if lhs.hunks != rhs.hunks {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(revisionStarted)
-        hasher.combine(reference)
-        hasher.combine(message)
+        hasher.combine(path)
+        hasher.combine(hunks)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSubmissionResult: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SubmissionResult {
+public struct FfiConverterTypePatchSelection: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PatchSelection {
         return
-            try SubmissionResult(
-                revisionStarted: FfiConverterBool.read(from: &buf),
-                reference: FfiConverterOptionString.read(from: &buf),
-                message: FfiConverterOptionString.read(from: &buf)
-            )
+            try PatchSelection(
+                path: FfiConverterString.read(from: &buf), 
+                hunks: FfiConverterOptionSequenceUInt32.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: SubmissionResult, into buf: inout [UInt8]) {
-        FfiConverterBool.write(value.revisionStarted, into: &buf)
-        FfiConverterOptionString.write(value.reference, into: &buf)
-        FfiConverterOptionString.write(value.message, into: &buf)
+    public static func write(_ value: PatchSelection, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterOptionSequenceUInt32.write(value.hunks, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSubmissionResult_lift(_ buf: RustBuffer) throws -> SubmissionResult {
-    return try FfiConverterTypeSubmissionResult.lift(buf)
+public func FfiConverterTypePatchSelection_lift(_ buf: RustBuffer) throws -> PatchSelection {
+    return try FfiConverterTypePatchSelection.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSubmissionResult_lower(_ value: SubmissionResult) -> RustBuffer {
-    return FfiConverterTypeSubmissionResult.lower(value)
+public func FfiConverterTypePatchSelection_lower(_ value: PatchSelection) -> RustBuffer {
+    return FfiConverterTypePatchSelection.lower(value)
 }
 
-public struct Suggestion {
-    public var title: String?
-    public var edits: [TextEdit]
+
+public struct PluginCapabilities {
+    public var supportsListThreads: Bool
+    public var supportsAttachWithoutThread: Bool
+    public var supportsPolling: Bool
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(title: String?, edits: [TextEdit]) {
-        self.title = title
-        self.edits = edits
+    public init(supportsListThreads: Bool, supportsAttachWithoutThread: Bool, supportsPolling: Bool) {
+        self.supportsListThreads = supportsListThreads
+        self.supportsAttachWithoutThread = supportsAttachWithoutThread
+        self.supportsPolling = supportsPolling
     }
 }
 
-extension Suggestion: Equatable, Hashable {
-    public static func == (lhs: Suggestion, rhs: Suggestion) -> Bool {
-        if lhs.title != rhs.title {
+
+
+extension PluginCapabilities: Equatable, Hashable {
+    public static func ==(lhs: PluginCapabilities, rhs: PluginCapabilities) -> Bool {
+        if lhs.supportsListThreads != rhs.supportsListThreads {
             return false
         }
-        if lhs.edits != rhs.edits {
+        if lhs.supportsAttachWithoutThread != rhs.supportsAttachWithoutThread {
+            return false
+        }
+        if lhs.supportsPolling != rhs.supportsPolling {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(title)
-        hasher.combine(edits)
+        hasher.combine(supportsListThreads)
+        hasher.combine(supportsAttachWithoutThread)
+        hasher.combine(supportsPolling)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSuggestion: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Suggestion {
+public struct FfiConverterTypePluginCapabilities: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PluginCapabilities {
         return
-            try Suggestion(
-                title: FfiConverterOptionString.read(from: &buf),
-                edits: FfiConverterSequenceTypeTextEdit.read(from: &buf)
-            )
+            try PluginCapabilities(
+                supportsListThreads: FfiConverterBool.read(from: &buf), 
+                supportsAttachWithoutThread: FfiConverterBool.read(from: &buf), 
+                supportsPolling: FfiConverterBool.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: Suggestion, into buf: inout [UInt8]) {
-        FfiConverterOptionString.write(value.title, into: &buf)
-        FfiConverterSequenceTypeTextEdit.write(value.edits, into: &buf)
+    public static func write(_ value: PluginCapabilities, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.supportsListThreads, into: &buf)
+        FfiConverterBool.write(value.supportsAttachWithoutThread, into: &buf)
+        FfiConverterBool.write(value.supportsPolling, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSuggestion_lift(_ buf: RustBuffer) throws -> Suggestion {
-    return try FfiConverterTypeSuggestion.lift(buf)
+public func FfiConverterTypePluginCapabilities_lift(_ buf: RustBuffer) throws -> PluginCapabilities {
+    return try FfiConverterTypePluginCapabilities.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSuggestion_lower(_ value: Suggestion) -> RustBuffer {
-    return FfiConverterTypeSuggestion.lower(value)
+public func FfiConverterTypePluginCapabilities_lower(_ value: PluginCapabilities) -> RustBuffer {
+    return FfiConverterTypePluginCapabilities.lower(value)
 }
 
-public struct TextEdit {
-    public var location: FileRange
-    public var replacement: String
+
+public struct PluginSession {
+    public var pluginId: String
+    public var sessionId: String
+    public var thread: ThreadRef?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(location: FileRange, replacement: String) {
-        self.location = location
-        self.replacement = replacement
+    public init(pluginId: String, sessionId: String, thread: ThreadRef?) {
+        self.pluginId = pluginId
+        self.sessionId = sessionId
+        self.thread = thread
     }
 }
 
-extension TextEdit: Equatable, Hashable {
-    public static func == (lhs: TextEdit, rhs: TextEdit) -> Bool {
-        if lhs.location != rhs.location {
+
+
+extension PluginSession: Equatable, Hashable {
+    public static func ==(lhs: PluginSession, rhs: PluginSession) -> Bool {
+        if lhs.pluginId != rhs.pluginId {
             return false
         }
-        if lhs.replacement != rhs.replacement {
+        if lhs.sessionId != rhs.sessionId {
+            return false
+        }
+        if lhs.thread != rhs.thread {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(location)
-        hasher.combine(replacement)
+        hasher.combine(pluginId)
+        hasher.combine(sessionId)
+        hasher.combine(thread)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTextEdit: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TextEdit {
+public struct FfiConverterTypePluginSession: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PluginSession {
         return
-            try TextEdit(
-                location: FfiConverterTypeFileRange.read(from: &buf),
-                replacement: FfiConverterString.read(from: &buf)
-            )
+            try PluginSession(
+                pluginId: FfiConverterString.read(from: &buf), 
+                sessionId: FfiConverterString.read(from: &buf), 
+                thread: FfiConverterOptionTypeThreadRef.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: TextEdit, into buf: inout [UInt8]) {
-        FfiConverterTypeFileRange.write(value.location, into: &buf)
-        FfiConverterString.write(value.replacement, into: &buf)
+    public static func write(_ value: PluginSession, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.pluginId, into: &buf)
+        FfiConverterString.write(value.sessionId, into: &buf)
+        FfiConverterOptionTypeThreadRef.write(value.thread, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTextEdit_lift(_ buf: RustBuffer) throws -> TextEdit {
-    return try FfiConverterTypeTextEdit.lift(buf)
+public func FfiConverterTypePluginSession_lift(_ buf: RustBuffer) throws -> PluginSession {
+    return try FfiConverterTypePluginSession.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTextEdit_lower(_ value: TextEdit) -> RustBuffer {
-    return FfiConverterTypeTextEdit.lower(value)
+public func FfiConverterTypePluginSession_lower(_ value: PluginSession) -> RustBuffer {
+    return FfiConverterTypePluginSession.lower(value)
 }
 
-public struct ThreadRef {
+
+public struct PluginSummary {
     public var id: String
-    public var title: String?
+    public var label: String
+    public var capabilities: PluginCapabilities
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(id: String, title: String?) {
+    public init(id: String, label: String, capabilities: PluginCapabilities) {
         self.id = id
-        self.title = title
+        self.label = label
+        self.capabilities = capabilities
     }
 }
 
-extension ThreadRef: Equatable, Hashable {
-    public static func == (lhs: ThreadRef, rhs: ThreadRef) -> Bool {
+
+
+extension PluginSummary: Equatable, Hashable {
+    public static func ==(lhs: PluginSummary, rhs: PluginSummary) -> Bool {
         if lhs.id != rhs.id {
             return false
         }
-        if lhs.title != rhs.title {
+        if lhs.label != rhs.label {
+            return false
+        }
+        if lhs.capabilities != rhs.capabilities {
             return false
         }
         return true
@@ -2468,912 +3317,4380 @@ extension ThreadRef: Equatable, Hashable {
 
     public func hash(into hasher: inout Hasher) {
         hasher.combine(id)
-        hasher.combine(title)
+        hasher.combine(label)
+        hasher.combine(capabilities)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeThreadRef: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ThreadRef {
+public struct FfiConverterTypePluginSummary: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PluginSummary {
         return
-            try ThreadRef(
-                id: FfiConverterString.read(from: &buf),
-                title: FfiConverterOptionString.read(from: &buf)
-            )
+            try PluginSummary(
+                id: FfiConverterString.read(from: &buf), 
+                label: FfiConverterString.read(from: &buf), 
+                capabilities: FfiConverterTypePluginCapabilities.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: ThreadRef, into buf: inout [UInt8]) {
+    public static func write(_ value: PluginSummary, into buf: inout [UInt8]) {
         FfiConverterString.write(value.id, into: &buf)
-        FfiConverterOptionString.write(value.title, into: &buf)
+        FfiConverterString.write(value.label, into: &buf)
+        FfiConverterTypePluginCapabilities.write(value.capabilities, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeThreadRef_lift(_ buf: RustBuffer) throws -> ThreadRef {
-    return try FfiConverterTypeThreadRef.lift(buf)
+public func FfiConverterTypePluginSummary_lift(_ buf: RustBuffer) throws -> PluginSummary {
+    return try FfiConverterTypePluginSummary.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeThreadRef_lower(_ value: ThreadRef) -> RustBuffer {
-    return FfiConverterTypeThreadRef.lower(value)
+public func FfiConverterTypePluginSummary_lower(_ value: PluginSummary) -> RustBuffer {
+    return FfiConverterTypePluginSummary.lower(value)
 }
 
-public struct WorkspaceStatus {
-    public var currentBranch: String?
-    public var dirty: Bool
+
+public struct Position {
+    public var line: UInt32
+    public var column: UInt32?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(currentBranch: String?, dirty: Bool) {
-        self.currentBranch = currentBranch
-        self.dirty = dirty
+    public init(line: UInt32, column: UInt32?) {
+        self.line = line
+        self.column = column
     }
 }
 
-extension WorkspaceStatus: Equatable, Hashable {
-    public static func == (lhs: WorkspaceStatus, rhs: WorkspaceStatus) -> Bool {
-        if lhs.currentBranch != rhs.currentBranch {
+
+
+extension Position: Equatable, Hashable {
+    public static func ==(lhs: Position, rhs: Position) -> Bool {
+        if lhs.line != rhs.line {
             return false
         }
-        if lhs.dirty != rhs.dirty {
+        if lhs.column != rhs.column {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(currentBranch)
-        hasher.combine(dirty)
+        hasher.combine(line)
+        hasher.combine(column)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeWorkspaceStatus: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> WorkspaceStatus {
+public struct FfiConverterTypePosition: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Position {
         return
-            try WorkspaceStatus(
-                currentBranch: FfiConverterOptionString.read(from: &buf),
-                dirty: FfiConverterBool.read(from: &buf)
-            )
+            try Position(
+                line: FfiConverterUInt32.read(from: &buf), 
+                column: FfiConverterOptionUInt32.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: WorkspaceStatus, into buf: inout [UInt8]) {
-        FfiConverterOptionString.write(value.currentBranch, into: &buf)
-        FfiConverterBool.write(value.dirty, into: &buf)
+    public static func write(_ value: Position, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.line, into: &buf)
+        FfiConverterOptionUInt32.write(value.column, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeWorkspaceStatus_lift(_ buf: RustBuffer) throws -> WorkspaceStatus {
-    return try FfiConverterTypeWorkspaceStatus.lift(buf)
+public func FfiConverterTypePosition_lift(_ buf: RustBuffer) throws -> Position {
+    return try FfiConverterTypePosition.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeWorkspaceStatus_lower(_ value: WorkspaceStatus) -> RustBuffer {
-    return FfiConverterTypeWorkspaceStatus.lower(value)
+public func FfiConverterTypePosition_lower(_ value: Position) -> RustBuffer {
+    return FfiConverterTypePosition.lower(value)
 }
 
-public enum CoreError {
-    case NotARepository(message: String)
-
-    case BareRepository(message: String)
-
-    case MissingHeadRevision(message: String)
 
-    case Git(message: String)
+public struct Range {
+    public var start: Position
+    public var end: Position
 
-    case Io(message: String)
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(start: Position, end: Position) {
+        self.start = start
+        self.end = end
+    }
+}
 
-    case Unimplemented(message: String)
 
-    case Internal(message: String)
 
-    case PluginNotRegistered(message: String)
+extension Range: Equatable, Hashable {
+    public static func ==(lhs: Range, rhs: Range) -> Bool {
+        if lhs.start != rhs.start {
+            return false
+        }
+        if lhs.end != rhs.end {
+            return false
+        }
+        return true
+    }
 
-    case Plugin(message: String)
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(start)
+        hasher.combine(end)
+    }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCoreError: FfiConverterRustBuffer {
-    typealias SwiftType = CoreError
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CoreError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        case 1: return try .NotARepository(
-                message: FfiConverterString.read(from: &buf)
-            )
-
-        case 2: return try .BareRepository(
-                message: FfiConverterString.read(from: &buf)
-            )
+public struct FfiConverterTypeRange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Range {
+        return
+            try Range(
+                start: FfiConverterTypePosition.read(from: &buf), 
+                end: FfiConverterTypePosition.read(from: &buf)
+        )
+    }
 
-        case 3: return try .MissingHeadRevision(
-                message: FfiConverterString.read(from: &buf)
-            )
+    public static func write(_ value: Range, into buf: inout [UInt8]) {
+        FfiConverterTypePosition.write(value.start, into: &buf)
+        FfiConverterTypePosition.write(value.end, into: &buf)
+    }
+}
 
-        case 4: return try .Git(
-                message: FfiConverterString.read(from: &buf)
-            )
 
-        case 5: return try .Io(
-                message: FfiConverterString.read(from: &buf)
-            )
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRange_lift(_ buf: RustBuffer) throws -> Range {
+    return try FfiConverterTypeRange.lift(buf)
+}
 
-        case 6: return try .Unimplemented(
-                message: FfiConverterString.read(from: &buf)
-            )
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRange_lower(_ value: Range) -> RustBuffer {
+    return FfiConverterTypeRange.lower(value)
+}
 
-        case 7: return try .Internal(
-                message: FfiConverterString.read(from: &buf)
-            )
 
-        case 8: return try .PluginNotRegistered(
-                message: FfiConverterString.read(from: &buf)
-            )
+public struct RangeDiff {
+    public var oldRange: RevisionRange
+    public var newRange: RevisionRange
+    public var pairs: [CommitPair]
 
-        case 9: return try .Plugin(
-                message: FfiConverterString.read(from: &buf)
-            )
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(oldRange: RevisionRange, newRange: RevisionRange, pairs: [CommitPair]) {
+        self.oldRange = oldRange
+        self.newRange = newRange
+        self.pairs = pairs
+    }
+}
 
-        default: throw UniffiInternalError.unexpectedEnumCase
+
+
+extension RangeDiff: Equatable, Hashable {
+    public static func ==(lhs: RangeDiff, rhs: RangeDiff) -> Bool {
+        if lhs.oldRange != rhs.oldRange {
+            return false
+        }
+        if lhs.newRange != rhs.newRange {
+            return false
+        }
+        if lhs.pairs != rhs.pairs {
+            return false
         }
+        return true
     }
 
-    public static func write(_ value: CoreError, into buf: inout [UInt8]) {
-        switch value {
-        case .NotARepository(_ /* message is ignored*/ ):
-            writeInt(&buf, Int32(1))
-        case .BareRepository(_ /* message is ignored*/ ):
-            writeInt(&buf, Int32(2))
-        case .MissingHeadRevision(_ /* message is ignored*/ ):
-            writeInt(&buf, Int32(3))
-        case .Git(_ /* message is ignored*/ ):
-            writeInt(&buf, Int32(4))
-        case .Io(_ /* message is ignored*/ ):
-            writeInt(&buf, Int32(5))
-        case .Unimplemented(_ /* message is ignored*/ ):
-            writeInt(&buf, Int32(6))
-        case .Internal(_ /* message is ignored*/ ):
-            writeInt(&buf, Int32(7))
-        case .PluginNotRegistered(_ /* message is ignored*/ ):
-            writeInt(&buf, Int32(8))
-        case .Plugin(_ /* message is ignored*/ ):
-            writeInt(&buf, Int32(9))
-        }
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(oldRange)
+        hasher.combine(newRange)
+        hasher.combine(pairs)
     }
 }
 
-extension CoreError: Equatable, Hashable {}
 
-extension CoreError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRangeDiff: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RangeDiff {
+        return
+            try RangeDiff(
+                oldRange: FfiConverterTypeRevisionRange.read(from: &buf), 
+                newRange: FfiConverterTypeRevisionRange.read(from: &buf), 
+                pairs: FfiConverterSequenceTypeCommitPair.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RangeDiff, into buf: inout [UInt8]) {
+        FfiConverterTypeRevisionRange.write(value.oldRange, into: &buf)
+        FfiConverterTypeRevisionRange.write(value.newRange, into: &buf)
+        FfiConverterSequenceTypeCommitPair.write(value.pairs, into: &buf)
     }
 }
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
-public enum DiffLineKind {
-    case context
-    case addition
-    case deletion
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRangeDiff_lift(_ buf: RustBuffer) throws -> RangeDiff {
+    return try FfiConverterTypeRangeDiff.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDiffLineKind: FfiConverterRustBuffer {
-    typealias SwiftType = DiffLineKind
+public func FfiConverterTypeRangeDiff_lower(_ value: RangeDiff) -> RustBuffer {
+    return FfiConverterTypeRangeDiff.lower(value)
+}
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffLineKind {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        case 1: return .context
 
-        case 2: return .addition
+public struct RepositoryInfo {
+    public var root: String
+    public var defaultBranch: String?
 
-        case 3: return .deletion
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(root: String, defaultBranch: String?) {
+        self.root = root
+        self.defaultBranch = defaultBranch
+    }
+}
 
-        default: throw UniffiInternalError.unexpectedEnumCase
+
+
+extension RepositoryInfo: Equatable, Hashable {
+    public static func ==(lhs: RepositoryInfo, rhs: RepositoryInfo) -> Bool {
+        if lhs.root != rhs.root {
+            return false
+        }
+        if lhs.defaultBranch != rhs.defaultBranch {
+            return false
         }
+        return true
     }
 
-    public static func write(_ value: DiffLineKind, into buf: inout [UInt8]) {
-        switch value {
-        case .context:
-            writeInt(&buf, Int32(1))
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(root)
+        hasher.combine(defaultBranch)
+    }
+}
 
-        case .addition:
-            writeInt(&buf, Int32(2))
 
-        case .deletion:
-            writeInt(&buf, Int32(3))
-        }
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRepositoryInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RepositoryInfo {
+        return
+            try RepositoryInfo(
+                root: FfiConverterString.read(from: &buf), 
+                defaultBranch: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RepositoryInfo, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.root, into: &buf)
+        FfiConverterOptionString.write(value.defaultBranch, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiffLineKind_lift(_ buf: RustBuffer) throws -> DiffLineKind {
-    return try FfiConverterTypeDiffLineKind.lift(buf)
+public func FfiConverterTypeRepositoryInfo_lift(_ buf: RustBuffer) throws -> RepositoryInfo {
+    return try FfiConverterTypeRepositoryInfo.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiffLineKind_lower(_ value: DiffLineKind) -> RustBuffer {
-    return FfiConverterTypeDiffLineKind.lower(value)
+public func FfiConverterTypeRepositoryInfo_lower(_ value: RepositoryInfo) -> RustBuffer {
+    return FfiConverterTypeRepositoryInfo.lower(value)
 }
 
-extension DiffLineKind: Equatable, Hashable {}
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+public struct RepositorySnapshot {
+    public var info: RepositoryInfo
+    public var workspace: WorkspaceStatus
+    public var revisions: RevisionRange?
 
-public enum DiffSide {
-    case base
-    case head
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(info: RepositoryInfo, workspace: WorkspaceStatus, revisions: RevisionRange?) {
+        self.info = info
+        self.workspace = workspace
+        self.revisions = revisions
+    }
 }
 
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeDiffSide: FfiConverterRustBuffer {
-    typealias SwiftType = DiffSide
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffSide {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        case 1: return .base
 
-        case 2: return .head
 
-        default: throw UniffiInternalError.unexpectedEnumCase
+extension RepositorySnapshot: Equatable, Hashable {
+    public static func ==(lhs: RepositorySnapshot, rhs: RepositorySnapshot) -> Bool {
+        if lhs.info != rhs.info {
+            return false
+        }
+        if lhs.workspace != rhs.workspace {
+            return false
+        }
+        if lhs.revisions != rhs.revisions {
+            return false
         }
+        return true
     }
 
-    public static func write(_ value: DiffSide, into buf: inout [UInt8]) {
-        switch value {
-        case .base:
-            writeInt(&buf, Int32(1))
-
-        case .head:
-            writeInt(&buf, Int32(2))
-        }
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(info)
+        hasher.combine(workspace)
+        hasher.combine(revisions)
     }
 }
 
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-public func FfiConverterTypeDiffSide_lift(_ buf: RustBuffer) throws -> DiffSide {
-    return try FfiConverterTypeDiffSide.lift(buf)
-}
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDiffSide_lower(_ value: DiffSide) -> RustBuffer {
-    return FfiConverterTypeDiffSide.lower(value)
-}
+public struct FfiConverterTypeRepositorySnapshot: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RepositorySnapshot {
+        return
+            try RepositorySnapshot(
+                info: FfiConverterTypeRepositoryInfo.read(from: &buf), 
+                workspace: FfiConverterTypeWorkspaceStatus.read(from: &buf), 
+                revisions: FfiConverterOptionTypeRevisionRange.read(from: &buf)
+        )
+    }
 
-extension DiffSide: Equatable, Hashable {}
+    public static func write(_ value: RepositorySnapshot, into buf: inout [UInt8]) {
+        FfiConverterTypeRepositoryInfo.write(value.info, into: &buf)
+        FfiConverterTypeWorkspaceStatus.write(value.workspace, into: &buf)
+        FfiConverterOptionTypeRevisionRange.write(value.revisions, into: &buf)
+    }
+}
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
-public enum FileStatus {
-    case added
-    case deleted
-    case modified
-    case renamed
-    case copied
-    case typeChange
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRepositorySnapshot_lift(_ buf: RustBuffer) throws -> RepositorySnapshot {
+    return try FfiConverterTypeRepositorySnapshot.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFileStatus: FfiConverterRustBuffer {
-    typealias SwiftType = FileStatus
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileStatus {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        case 1: return .added
+public func FfiConverterTypeRepositorySnapshot_lower(_ value: RepositorySnapshot) -> RustBuffer {
+    return FfiConverterTypeRepositorySnapshot.lower(value)
+}
 
-        case 2: return .deleted
 
-        case 3: return .modified
+public struct ReviewIteration {
+    public var number: UInt32
+    public var treeOid: String
 
-        case 4: return .renamed
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(number: UInt32, treeOid: String) {
+        self.number = number
+        self.treeOid = treeOid
+    }
+}
 
-        case 5: return .copied
 
-        case 6: return .typeChange
 
-        default: throw UniffiInternalError.unexpectedEnumCase
+extension ReviewIteration: Equatable, Hashable {
+    public static func ==(lhs: ReviewIteration, rhs: ReviewIteration) -> Bool {
+        if lhs.number != rhs.number {
+            return false
+        }
+        if lhs.treeOid != rhs.treeOid {
+            return false
         }
+        return true
     }
 
-    public static func write(_ value: FileStatus, into buf: inout [UInt8]) {
-        switch value {
-        case .added:
-            writeInt(&buf, Int32(1))
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(number)
+        hasher.combine(treeOid)
+    }
+}
 
-        case .deleted:
-            writeInt(&buf, Int32(2))
 
-        case .modified:
-            writeInt(&buf, Int32(3))
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeReviewIteration: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ReviewIteration {
+        return
+            try ReviewIteration(
+                number: FfiConverterUInt32.read(from: &buf), 
+                treeOid: FfiConverterString.read(from: &buf)
+        )
+    }
 
+    public static func write(_ value: ReviewIteration, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.number, into: &buf)
+        FfiConverterString.write(value.treeOid, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeReviewIteration_lift(_ buf: RustBuffer) throws -> ReviewIteration {
+    return try FfiConverterTypeReviewIteration.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeReviewIteration_lower(_ value: ReviewIteration) -> RustBuffer {
+    return FfiConverterTypeReviewIteration.lower(value)
+}
+
+
+public struct ReviewPayload {
+    public var summary: String?
+    public var actions: [String]
+    public var comments: [CommentDraft]
+    public var diagnostics: [Diagnostic]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(summary: String?, actions: [String], comments: [CommentDraft], diagnostics: [Diagnostic]) {
+        self.summary = summary
+        self.actions = actions
+        self.comments = comments
+        self.diagnostics = diagnostics
+    }
+}
+
+
+
+extension ReviewPayload: Equatable, Hashable {
+    public static func ==(lhs: ReviewPayload, rhs: ReviewPayload) -> Bool {
+        if lhs.summary != rhs.summary {
+            return false
+        }
+        if lhs.actions != rhs.actions {
+            return false
+        }
+        if lhs.comments != rhs.comments {
+            return false
+        }
+        if lhs.diagnostics != rhs.diagnostics {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(summary)
+        hasher.combine(actions)
+        hasher.combine(comments)
+        hasher.combine(diagnostics)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeReviewPayload: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ReviewPayload {
+        return
+            try ReviewPayload(
+                summary: FfiConverterOptionString.read(from: &buf), 
+                actions: FfiConverterSequenceString.read(from: &buf), 
+                comments: FfiConverterSequenceTypeCommentDraft.read(from: &buf), 
+                diagnostics: FfiConverterSequenceTypeDiagnostic.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ReviewPayload, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.summary, into: &buf)
+        FfiConverterSequenceString.write(value.actions, into: &buf)
+        FfiConverterSequenceTypeCommentDraft.write(value.comments, into: &buf)
+        FfiConverterSequenceTypeDiagnostic.write(value.diagnostics, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeReviewPayload_lift(_ buf: RustBuffer) throws -> ReviewPayload {
+    return try FfiConverterTypeReviewPayload.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeReviewPayload_lower(_ value: ReviewPayload) -> RustBuffer {
+    return FfiConverterTypeReviewPayload.lower(value)
+}
+
+
+public struct Revision {
+    public var oid: String
+    public var reference: String?
+    public var summary: String?
+    public var author: Signature?
+    public var committer: Signature?
+    public var timestamp: Int64?
+    public var kind: RevisionKind
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(oid: String, reference: String?, summary: String?, author: Signature?, committer: Signature?, timestamp: Int64?, kind: RevisionKind) {
+        self.oid = oid
+        self.reference = reference
+        self.summary = summary
+        self.author = author
+        self.committer = committer
+        self.timestamp = timestamp
+        self.kind = kind
+    }
+}
+
+
+
+extension Revision: Equatable, Hashable {
+    public static func ==(lhs: Revision, rhs: Revision) -> Bool {
+        if lhs.oid != rhs.oid {
+            return false
+        }
+        if lhs.reference != rhs.reference {
+            return false
+        }
+        if lhs.summary != rhs.summary {
+            return false
+        }
+        if lhs.author != rhs.author {
+            return false
+        }
+        if lhs.committer != rhs.committer {
+            return false
+        }
+        if lhs.timestamp != rhs.timestamp {
+            return false
+        }
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(oid)
+        hasher.combine(reference)
+        hasher.combine(summary)
+        hasher.combine(author)
+        hasher.combine(committer)
+        hasher.combine(timestamp)
+        hasher.combine(kind)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRevision: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Revision {
+        return
+            try Revision(
+                oid: FfiConverterString.read(from: &buf), 
+                reference: FfiConverterOptionString.read(from: &buf), 
+                summary: FfiConverterOptionString.read(from: &buf), 
+                author: FfiConverterOptionTypeSignature.read(from: &buf), 
+                committer: FfiConverterOptionTypeSignature.read(from: &buf), 
+                timestamp: FfiConverterOptionInt64.read(from: &buf), 
+                kind: FfiConverterTypeRevisionKind.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Revision, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.oid, into: &buf)
+        FfiConverterOptionString.write(value.reference, into: &buf)
+        FfiConverterOptionString.write(value.summary, into: &buf)
+        FfiConverterOptionTypeSignature.write(value.author, into: &buf)
+        FfiConverterOptionTypeSignature.write(value.committer, into: &buf)
+        FfiConverterOptionInt64.write(value.timestamp, into: &buf)
+        FfiConverterTypeRevisionKind.write(value.kind, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRevision_lift(_ buf: RustBuffer) throws -> Revision {
+    return try FfiConverterTypeRevision.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRevision_lower(_ value: Revision) -> RustBuffer {
+    return FfiConverterTypeRevision.lower(value)
+}
+
+
+public struct RevisionProgress {
+    public var state: RevisionState
+    public var detail: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(state: RevisionState, detail: String?) {
+        self.state = state
+        self.detail = detail
+    }
+}
+
+
+
+extension RevisionProgress: Equatable, Hashable {
+    public static func ==(lhs: RevisionProgress, rhs: RevisionProgress) -> Bool {
+        if lhs.state != rhs.state {
+            return false
+        }
+        if lhs.detail != rhs.detail {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(state)
+        hasher.combine(detail)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRevisionProgress: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RevisionProgress {
+        return
+            try RevisionProgress(
+                state: FfiConverterTypeRevisionState.read(from: &buf), 
+                detail: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RevisionProgress, into buf: inout [UInt8]) {
+        FfiConverterTypeRevisionState.write(value.state, into: &buf)
+        FfiConverterOptionString.write(value.detail, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRevisionProgress_lift(_ buf: RustBuffer) throws -> RevisionProgress {
+    return try FfiConverterTypeRevisionProgress.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRevisionProgress_lower(_ value: RevisionProgress) -> RustBuffer {
+    return FfiConverterTypeRevisionProgress.lower(value)
+}
+
+
+public struct RevisionRange {
+    public var base: Revision?
+    public var head: Revision
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(base: Revision?, head: Revision) {
+        self.base = base
+        self.head = head
+    }
+}
+
+
+
+extension RevisionRange: Equatable, Hashable {
+    public static func ==(lhs: RevisionRange, rhs: RevisionRange) -> Bool {
+        if lhs.base != rhs.base {
+            return false
+        }
+        if lhs.head != rhs.head {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(base)
+        hasher.combine(head)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRevisionRange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RevisionRange {
+        return
+            try RevisionRange(
+                base: FfiConverterOptionTypeRevision.read(from: &buf), 
+                head: FfiConverterTypeRevision.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RevisionRange, into buf: inout [UInt8]) {
+        FfiConverterOptionTypeRevision.write(value.base, into: &buf)
+        FfiConverterTypeRevision.write(value.head, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRevisionRange_lift(_ buf: RustBuffer) throws -> RevisionRange {
+    return try FfiConverterTypeRevisionRange.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRevisionRange_lower(_ value: RevisionRange) -> RustBuffer {
+    return FfiConverterTypeRevisionRange.lower(value)
+}
+
+
+public struct Signature {
+    public var name: String
+    public var email: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, email: String?) {
+        self.name = name
+        self.email = email
+    }
+}
+
+
+
+extension Signature: Equatable, Hashable {
+    public static func ==(lhs: Signature, rhs: Signature) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.email != rhs.email {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(email)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSignature: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Signature {
+        return
+            try Signature(
+                name: FfiConverterString.read(from: &buf), 
+                email: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Signature, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterOptionString.write(value.email, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSignature_lift(_ buf: RustBuffer) throws -> Signature {
+    return try FfiConverterTypeSignature.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSignature_lower(_ value: Signature) -> RustBuffer {
+    return FfiConverterTypeSignature.lower(value)
+}
+
+
+public struct SplitHunk {
+    public var header: DiffRange
+    public var section: String?
+    public var rows: [SplitRow]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(header: DiffRange, section: String?, rows: [SplitRow]) {
+        self.header = header
+        self.section = section
+        self.rows = rows
+    }
+}
+
+
+
+extension SplitHunk: Equatable, Hashable {
+    public static func ==(lhs: SplitHunk, rhs: SplitHunk) -> Bool {
+        if lhs.header != rhs.header {
+            return false
+        }
+        if lhs.section != rhs.section {
+            return false
+        }
+        if lhs.rows != rhs.rows {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(header)
+        hasher.combine(section)
+        hasher.combine(rows)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSplitHunk: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SplitHunk {
+        return
+            try SplitHunk(
+                header: FfiConverterTypeDiffRange.read(from: &buf), 
+                section: FfiConverterOptionString.read(from: &buf), 
+                rows: FfiConverterSequenceTypeSplitRow.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SplitHunk, into buf: inout [UInt8]) {
+        FfiConverterTypeDiffRange.write(value.header, into: &buf)
+        FfiConverterOptionString.write(value.section, into: &buf)
+        FfiConverterSequenceTypeSplitRow.write(value.rows, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSplitHunk_lift(_ buf: RustBuffer) throws -> SplitHunk {
+    return try FfiConverterTypeSplitHunk.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSplitHunk_lower(_ value: SplitHunk) -> RustBuffer {
+    return FfiConverterTypeSplitHunk.lower(value)
+}
+
+
+public struct SplitRow {
+    public var base: DiffLine?
+    public var head: DiffLine?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(base: DiffLine?, head: DiffLine?) {
+        self.base = base
+        self.head = head
+    }
+}
+
+
+
+extension SplitRow: Equatable, Hashable {
+    public static func ==(lhs: SplitRow, rhs: SplitRow) -> Bool {
+        if lhs.base != rhs.base {
+            return false
+        }
+        if lhs.head != rhs.head {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(base)
+        hasher.combine(head)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSplitRow: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SplitRow {
+        return
+            try SplitRow(
+                base: FfiConverterOptionTypeDiffLine.read(from: &buf), 
+                head: FfiConverterOptionTypeDiffLine.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SplitRow, into buf: inout [UInt8]) {
+        FfiConverterOptionTypeDiffLine.write(value.base, into: &buf)
+        FfiConverterOptionTypeDiffLine.write(value.head, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSplitRow_lift(_ buf: RustBuffer) throws -> SplitRow {
+    return try FfiConverterTypeSplitRow.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSplitRow_lower(_ value: SplitRow) -> RustBuffer {
+    return FfiConverterTypeSplitRow.lower(value)
+}
+
+
+public struct StructuralSummary {
+    public var changes: [SymbolChange]
+    public var functionsChanged: UInt32
+    public var typesChanged: UInt32
+    public var publicSignaturesChanged: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(changes: [SymbolChange], functionsChanged: UInt32, typesChanged: UInt32, publicSignaturesChanged: UInt32) {
+        self.changes = changes
+        self.functionsChanged = functionsChanged
+        self.typesChanged = typesChanged
+        self.publicSignaturesChanged = publicSignaturesChanged
+    }
+}
+
+
+
+extension StructuralSummary: Equatable, Hashable {
+    public static func ==(lhs: StructuralSummary, rhs: StructuralSummary) -> Bool {
+        if lhs.changes != rhs.changes {
+            return false
+        }
+        if lhs.functionsChanged != rhs.functionsChanged {
+            return false
+        }
+        if lhs.typesChanged != rhs.typesChanged {
+            return false
+        }
+        if lhs.publicSignaturesChanged != rhs.publicSignaturesChanged {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(changes)
+        hasher.combine(functionsChanged)
+        hasher.combine(typesChanged)
+        hasher.combine(publicSignaturesChanged)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStructuralSummary: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StructuralSummary {
+        return
+            try StructuralSummary(
+                changes: FfiConverterSequenceTypeSymbolChange.read(from: &buf), 
+                functionsChanged: FfiConverterUInt32.read(from: &buf), 
+                typesChanged: FfiConverterUInt32.read(from: &buf), 
+                publicSignaturesChanged: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: StructuralSummary, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeSymbolChange.write(value.changes, into: &buf)
+        FfiConverterUInt32.write(value.functionsChanged, into: &buf)
+        FfiConverterUInt32.write(value.typesChanged, into: &buf)
+        FfiConverterUInt32.write(value.publicSignaturesChanged, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStructuralSummary_lift(_ buf: RustBuffer) throws -> StructuralSummary {
+    return try FfiConverterTypeStructuralSummary.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStructuralSummary_lower(_ value: StructuralSummary) -> RustBuffer {
+    return FfiConverterTypeStructuralSummary.lower(value)
+}
+
+
+public struct SubmissionResult {
+    public var revisionStarted: Bool
+    public var reference: String?
+    public var message: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(revisionStarted: Bool, reference: String?, message: String?) {
+        self.revisionStarted = revisionStarted
+        self.reference = reference
+        self.message = message
+    }
+}
+
+
+
+extension SubmissionResult: Equatable, Hashable {
+    public static func ==(lhs: SubmissionResult, rhs: SubmissionResult) -> Bool {
+        if lhs.revisionStarted != rhs.revisionStarted {
+            return false
+        }
+        if lhs.reference != rhs.reference {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(revisionStarted)
+        hasher.combine(reference)
+        hasher.combine(message)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSubmissionResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SubmissionResult {
+        return
+            try SubmissionResult(
+                revisionStarted: FfiConverterBool.read(from: &buf), 
+                reference: FfiConverterOptionString.read(from: &buf), 
+                message: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SubmissionResult, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.revisionStarted, into: &buf)
+        FfiConverterOptionString.write(value.reference, into: &buf)
+        FfiConverterOptionString.write(value.message, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSubmissionResult_lift(_ buf: RustBuffer) throws -> SubmissionResult {
+    return try FfiConverterTypeSubmissionResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSubmissionResult_lower(_ value: SubmissionResult) -> RustBuffer {
+    return FfiConverterTypeSubmissionResult.lower(value)
+}
+
+
+public struct SubmoduleChange {
+    public var oldOid: String?
+    public var newOid: String?
+    public var dirty: Bool
+    public var commits: [Revision]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(oldOid: String?, newOid: String?, dirty: Bool, commits: [Revision]) {
+        self.oldOid = oldOid
+        self.newOid = newOid
+        self.dirty = dirty
+        self.commits = commits
+    }
+}
+
+
+
+extension SubmoduleChange: Equatable, Hashable {
+    public static func ==(lhs: SubmoduleChange, rhs: SubmoduleChange) -> Bool {
+        if lhs.oldOid != rhs.oldOid {
+            return false
+        }
+        if lhs.newOid != rhs.newOid {
+            return false
+        }
+        if lhs.dirty != rhs.dirty {
+            return false
+        }
+        if lhs.commits != rhs.commits {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(oldOid)
+        hasher.combine(newOid)
+        hasher.combine(dirty)
+        hasher.combine(commits)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSubmoduleChange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SubmoduleChange {
+        return
+            try SubmoduleChange(
+                oldOid: FfiConverterOptionString.read(from: &buf), 
+                newOid: FfiConverterOptionString.read(from: &buf), 
+                dirty: FfiConverterBool.read(from: &buf), 
+                commits: FfiConverterSequenceTypeRevision.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SubmoduleChange, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.oldOid, into: &buf)
+        FfiConverterOptionString.write(value.newOid, into: &buf)
+        FfiConverterBool.write(value.dirty, into: &buf)
+        FfiConverterSequenceTypeRevision.write(value.commits, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSubmoduleChange_lift(_ buf: RustBuffer) throws -> SubmoduleChange {
+    return try FfiConverterTypeSubmoduleChange.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSubmoduleChange_lower(_ value: SubmoduleChange) -> RustBuffer {
+    return FfiConverterTypeSubmoduleChange.lower(value)
+}
+
+
+public struct Suggestion {
+    public var title: String?
+    public var edits: [TextEdit]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(title: String?, edits: [TextEdit]) {
+        self.title = title
+        self.edits = edits
+    }
+}
+
+
+
+extension Suggestion: Equatable, Hashable {
+    public static func ==(lhs: Suggestion, rhs: Suggestion) -> Bool {
+        if lhs.title != rhs.title {
+            return false
+        }
+        if lhs.edits != rhs.edits {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(title)
+        hasher.combine(edits)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSuggestion: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Suggestion {
+        return
+            try Suggestion(
+                title: FfiConverterOptionString.read(from: &buf), 
+                edits: FfiConverterSequenceTypeTextEdit.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Suggestion, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.title, into: &buf)
+        FfiConverterSequenceTypeTextEdit.write(value.edits, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSuggestion_lift(_ buf: RustBuffer) throws -> Suggestion {
+    return try FfiConverterTypeSuggestion.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSuggestion_lower(_ value: Suggestion) -> RustBuffer {
+    return FfiConverterTypeSuggestion.lower(value)
+}
+
+
+public struct SymbolChange {
+    public var kind: SymbolKind
+    public var name: String
+    public var oldName: String?
+    public var change: SymbolChangeKind
+    public var isPublic: Bool
+    public var baseLine: UInt32?
+    public var headLine: UInt32?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(kind: SymbolKind, name: String, oldName: String?, change: SymbolChangeKind, isPublic: Bool, baseLine: UInt32?, headLine: UInt32?) {
+        self.kind = kind
+        self.name = name
+        self.oldName = oldName
+        self.change = change
+        self.isPublic = isPublic
+        self.baseLine = baseLine
+        self.headLine = headLine
+    }
+}
+
+
+
+extension SymbolChange: Equatable, Hashable {
+    public static func ==(lhs: SymbolChange, rhs: SymbolChange) -> Bool {
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.oldName != rhs.oldName {
+            return false
+        }
+        if lhs.change != rhs.change {
+            return false
+        }
+        if lhs.isPublic != rhs.isPublic {
+            return false
+        }
+        if lhs.baseLine != rhs.baseLine {
+            return false
+        }
+        if lhs.headLine != rhs.headLine {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(kind)
+        hasher.combine(name)
+        hasher.combine(oldName)
+        hasher.combine(change)
+        hasher.combine(isPublic)
+        hasher.combine(baseLine)
+        hasher.combine(headLine)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSymbolChange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SymbolChange {
+        return
+            try SymbolChange(
+                kind: FfiConverterTypeSymbolKind.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                oldName: FfiConverterOptionString.read(from: &buf), 
+                change: FfiConverterTypeSymbolChangeKind.read(from: &buf), 
+                isPublic: FfiConverterBool.read(from: &buf), 
+                baseLine: FfiConverterOptionUInt32.read(from: &buf), 
+                headLine: FfiConverterOptionUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SymbolChange, into buf: inout [UInt8]) {
+        FfiConverterTypeSymbolKind.write(value.kind, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterOptionString.write(value.oldName, into: &buf)
+        FfiConverterTypeSymbolChangeKind.write(value.change, into: &buf)
+        FfiConverterBool.write(value.isPublic, into: &buf)
+        FfiConverterOptionUInt32.write(value.baseLine, into: &buf)
+        FfiConverterOptionUInt32.write(value.headLine, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSymbolChange_lift(_ buf: RustBuffer) throws -> SymbolChange {
+    return try FfiConverterTypeSymbolChange.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSymbolChange_lower(_ value: SymbolChange) -> RustBuffer {
+    return FfiConverterTypeSymbolChange.lower(value)
+}
+
+
+public struct SyntaxToken {
+    public var startColumn: UInt32
+    public var endColumn: UInt32
+    public var kind: SyntaxKind
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(startColumn: UInt32, endColumn: UInt32, kind: SyntaxKind) {
+        self.startColumn = startColumn
+        self.endColumn = endColumn
+        self.kind = kind
+    }
+}
+
+
+
+extension SyntaxToken: Equatable, Hashable {
+    public static func ==(lhs: SyntaxToken, rhs: SyntaxToken) -> Bool {
+        if lhs.startColumn != rhs.startColumn {
+            return false
+        }
+        if lhs.endColumn != rhs.endColumn {
+            return false
+        }
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(startColumn)
+        hasher.combine(endColumn)
+        hasher.combine(kind)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSyntaxToken: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SyntaxToken {
+        return
+            try SyntaxToken(
+                startColumn: FfiConverterUInt32.read(from: &buf), 
+                endColumn: FfiConverterUInt32.read(from: &buf), 
+                kind: FfiConverterTypeSyntaxKind.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SyntaxToken, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.startColumn, into: &buf)
+        FfiConverterUInt32.write(value.endColumn, into: &buf)
+        FfiConverterTypeSyntaxKind.write(value.kind, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSyntaxToken_lift(_ buf: RustBuffer) throws -> SyntaxToken {
+    return try FfiConverterTypeSyntaxToken.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSyntaxToken_lower(_ value: SyntaxToken) -> RustBuffer {
+    return FfiConverterTypeSyntaxToken.lower(value)
+}
+
+
+public struct TextEdit {
+    public var location: FileRange
+    public var replacement: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(location: FileRange, replacement: String) {
+        self.location = location
+        self.replacement = replacement
+    }
+}
+
+
+
+extension TextEdit: Equatable, Hashable {
+    public static func ==(lhs: TextEdit, rhs: TextEdit) -> Bool {
+        if lhs.location != rhs.location {
+            return false
+        }
+        if lhs.replacement != rhs.replacement {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(location)
+        hasher.combine(replacement)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTextEdit: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TextEdit {
+        return
+            try TextEdit(
+                location: FfiConverterTypeFileRange.read(from: &buf), 
+                replacement: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TextEdit, into buf: inout [UInt8]) {
+        FfiConverterTypeFileRange.write(value.location, into: &buf)
+        FfiConverterString.write(value.replacement, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTextEdit_lift(_ buf: RustBuffer) throws -> TextEdit {
+    return try FfiConverterTypeTextEdit.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTextEdit_lower(_ value: TextEdit) -> RustBuffer {
+    return FfiConverterTypeTextEdit.lower(value)
+}
+
+
+public struct ThreadRef {
+    public var id: String
+    public var title: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: String, title: String?) {
+        self.id = id
+        self.title = title
+    }
+}
+
+
+
+extension ThreadRef: Equatable, Hashable {
+    public static func ==(lhs: ThreadRef, rhs: ThreadRef) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.title != rhs.title {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(title)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeThreadRef: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ThreadRef {
+        return
+            try ThreadRef(
+                id: FfiConverterString.read(from: &buf), 
+                title: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ThreadRef, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterOptionString.write(value.title, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeThreadRef_lift(_ buf: RustBuffer) throws -> ThreadRef {
+    return try FfiConverterTypeThreadRef.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeThreadRef_lower(_ value: ThreadRef) -> RustBuffer {
+    return FfiConverterTypeThreadRef.lower(value)
+}
+
+
+public struct WorkspaceStatus {
+    public var currentBranch: String?
+    public var dirty: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(currentBranch: String?, dirty: Bool) {
+        self.currentBranch = currentBranch
+        self.dirty = dirty
+    }
+}
+
+
+
+extension WorkspaceStatus: Equatable, Hashable {
+    public static func ==(lhs: WorkspaceStatus, rhs: WorkspaceStatus) -> Bool {
+        if lhs.currentBranch != rhs.currentBranch {
+            return false
+        }
+        if lhs.dirty != rhs.dirty {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(currentBranch)
+        hasher.combine(dirty)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeWorkspaceStatus: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> WorkspaceStatus {
+        return
+            try WorkspaceStatus(
+                currentBranch: FfiConverterOptionString.read(from: &buf), 
+                dirty: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: WorkspaceStatus, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.currentBranch, into: &buf)
+        FfiConverterBool.write(value.dirty, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWorkspaceStatus_lift(_ buf: RustBuffer) throws -> WorkspaceStatus {
+    return try FfiConverterTypeWorkspaceStatus.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWorkspaceStatus_lower(_ value: WorkspaceStatus) -> RustBuffer {
+    return FfiConverterTypeWorkspaceStatus.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum CommitPairStatus {
+    
+    case unchanged
+    case modified
+    case added
+    case dropped
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCommitPairStatus: FfiConverterRustBuffer {
+    typealias SwiftType = CommitPairStatus
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CommitPairStatus {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .unchanged
+        
+        case 2: return .modified
+        
+        case 3: return .added
+        
+        case 4: return .dropped
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: CommitPairStatus, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .unchanged:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .modified:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .added:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .dropped:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCommitPairStatus_lift(_ buf: RustBuffer) throws -> CommitPairStatus {
+    return try FfiConverterTypeCommitPairStatus.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCommitPairStatus_lower(_ value: CommitPairStatus) -> RustBuffer {
+    return FfiConverterTypeCommitPairStatus.lower(value)
+}
+
+
+
+extension CommitPairStatus: Equatable, Hashable {}
+
+
+
+
+public enum CoreError {
+
+    
+    
+    case NotARepository(message: String)
+    
+    case BareRepository(message: String)
+    
+    case MissingHeadRevision(message: String)
+    
+    case MissingDefaultBranch(message: String)
+    
+    case FileNotInDiff(message: String)
+    
+    case InvalidPath(message: String)
+    
+    case IterationNotFound(message: String)
+    
+    case IncompletePatch(message: String)
+    
+    case HunkNotInDiff(message: String)
+    
+    case PatchDoesNotApply(message: String)
+    
+    case Git(message: String)
+    
+    case Io(message: String)
+    
+    case Unimplemented(message: String)
+    
+    case Internal(message: String)
+    
+    case PluginNotRegistered(message: String)
+    
+    case Plugin(message: String)
+    
+    case Suggestion(message: String)
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCoreError: FfiConverterRustBuffer {
+    typealias SwiftType = CoreError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CoreError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .NotARepository(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .BareRepository(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 3: return .MissingHeadRevision(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 4: return .MissingDefaultBranch(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 5: return .FileNotInDiff(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 6: return .InvalidPath(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 7: return .IterationNotFound(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 8: return .IncompletePatch(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 9: return .HunkNotInDiff(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 10: return .PatchDoesNotApply(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 11: return .Git(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 12: return .Io(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 13: return .Unimplemented(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 14: return .Internal(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 15: return .PluginNotRegistered(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 16: return .Plugin(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 17: return .Suggestion(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: CoreError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        case .NotARepository(_ /* message is ignored*/):
+            writeInt(&buf, Int32(1))
+        case .BareRepository(_ /* message is ignored*/):
+            writeInt(&buf, Int32(2))
+        case .MissingHeadRevision(_ /* message is ignored*/):
+            writeInt(&buf, Int32(3))
+        case .MissingDefaultBranch(_ /* message is ignored*/):
+            writeInt(&buf, Int32(4))
+        case .FileNotInDiff(_ /* message is ignored*/):
+            writeInt(&buf, Int32(5))
+        case .InvalidPath(_ /* message is ignored*/):
+            writeInt(&buf, Int32(6))
+        case .IterationNotFound(_ /* message is ignored*/):
+            writeInt(&buf, Int32(7))
+        case .IncompletePatch(_ /* message is ignored*/):
+            writeInt(&buf, Int32(8))
+        case .HunkNotInDiff(_ /* message is ignored*/):
+            writeInt(&buf, Int32(9))
+        case .PatchDoesNotApply(_ /* message is ignored*/):
+            writeInt(&buf, Int32(10))
+        case .Git(_ /* message is ignored*/):
+            writeInt(&buf, Int32(11))
+        case .Io(_ /* message is ignored*/):
+            writeInt(&buf, Int32(12))
+        case .Unimplemented(_ /* message is ignored*/):
+            writeInt(&buf, Int32(13))
+        case .Internal(_ /* message is ignored*/):
+            writeInt(&buf, Int32(14))
+        case .PluginNotRegistered(_ /* message is ignored*/):
+            writeInt(&buf, Int32(15))
+        case .Plugin(_ /* message is ignored*/):
+            writeInt(&buf, Int32(16))
+        case .Suggestion(_ /* message is ignored*/):
+            writeInt(&buf, Int32(17))
+
+        
+        }
+    }
+}
+
+
+extension CoreError: Equatable, Hashable {}
+
+extension CoreError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum DiffLineKind {
+    
+    case context
+    case addition
+    case deletion
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDiffLineKind: FfiConverterRustBuffer {
+    typealias SwiftType = DiffLineKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffLineKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .context
+        
+        case 2: return .addition
+        
+        case 3: return .deletion
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DiffLineKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .context:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .addition:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .deletion:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDiffLineKind_lift(_ buf: RustBuffer) throws -> DiffLineKind {
+    return try FfiConverterTypeDiffLineKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDiffLineKind_lower(_ value: DiffLineKind) -> RustBuffer {
+    return FfiConverterTypeDiffLineKind.lower(value)
+}
+
+
+
+extension DiffLineKind: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum DiffSide {
+    
+    case base
+    case head
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDiffSide: FfiConverterRustBuffer {
+    typealias SwiftType = DiffSide
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffSide {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .base
+        
+        case 2: return .head
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DiffSide, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .base:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .head:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDiffSide_lift(_ buf: RustBuffer) throws -> DiffSide {
+    return try FfiConverterTypeDiffSide.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDiffSide_lower(_ value: DiffSide) -> RustBuffer {
+    return FfiConverterTypeDiffSide.lower(value)
+}
+
+
+
+extension DiffSide: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum FileClassification {
+    
+    case source
+    case test
+    case documentation
+    case lockfile
+    case generated
+    case vendored
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFileClassification: FfiConverterRustBuffer {
+    typealias SwiftType = FileClassification
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileClassification {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .source
+        
+        case 2: return .test
+        
+        case 3: return .documentation
+        
+        case 4: return .lockfile
+        
+        case 5: return .generated
+        
+        case 6: return .vendored
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FileClassification, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .source:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .test:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .documentation:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .lockfile:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .generated:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .vendored:
+            writeInt(&buf, Int32(6))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileClassification_lift(_ buf: RustBuffer) throws -> FileClassification {
+    return try FfiConverterTypeFileClassification.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileClassification_lower(_ value: FileClassification) -> RustBuffer {
+    return FfiConverterTypeFileClassification.lower(value)
+}
+
+
+
+extension FileClassification: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum FileMode {
+    
+    case regular
+    case executable
+    case symlink
+    case gitlink
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFileMode: FfiConverterRustBuffer {
+    typealias SwiftType = FileMode
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileMode {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .regular
+        
+        case 2: return .executable
+        
+        case 3: return .symlink
+        
+        case 4: return .gitlink
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FileMode, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .regular:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .executable:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .symlink:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .gitlink:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileMode_lift(_ buf: RustBuffer) throws -> FileMode {
+    return try FfiConverterTypeFileMode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileMode_lower(_ value: FileMode) -> RustBuffer {
+    return FfiConverterTypeFileMode.lower(value)
+}
+
+
+
+extension FileMode: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum FileStatus {
+    
+    case added
+    case deleted
+    case modified
+    case renamed
+    case copied
+    case typeChange
+    case submodule
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFileStatus: FfiConverterRustBuffer {
+    typealias SwiftType = FileStatus
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileStatus {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .added
+        
+        case 2: return .deleted
+        
+        case 3: return .modified
+        
+        case 4: return .renamed
+        
+        case 5: return .copied
+        
+        case 6: return .typeChange
+        
+        case 7: return .submodule
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FileStatus, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .added:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .deleted:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .modified:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .renamed:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .copied:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .typeChange:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .submodule:
+            writeInt(&buf, Int32(7))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileStatus_lift(_ buf: RustBuffer) throws -> FileStatus {
+    return try FfiConverterTypeFileStatus.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileStatus_lower(_ value: FileStatus) -> RustBuffer {
+    return FfiConverterTypeFileStatus.lower(value)
+}
+
+
+
+extension FileStatus: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum FileVersion {
+    
+    case base
+    case head
+    case workspace
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFileVersion: FfiConverterRustBuffer {
+    typealias SwiftType = FileVersion
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileVersion {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .base
+        
+        case 2: return .head
+        
+        case 3: return .workspace
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FileVersion, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .base:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .head:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .workspace:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileVersion_lift(_ buf: RustBuffer) throws -> FileVersion {
+    return try FfiConverterTypeFileVersion.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileVersion_lower(_ value: FileVersion) -> RustBuffer {
+    return FfiConverterTypeFileVersion.lower(value)
+}
+
+
+
+extension FileVersion: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ImageFormat {
+    
+    case png
+    case jpeg
+    case gif
+    case webp
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeImageFormat: FfiConverterRustBuffer {
+    typealias SwiftType = ImageFormat
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ImageFormat {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .png
+        
+        case 2: return .jpeg
+        
+        case 3: return .gif
+        
+        case 4: return .webp
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ImageFormat, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .png:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .jpeg:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .gif:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .webp:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeImageFormat_lift(_ buf: RustBuffer) throws -> ImageFormat {
+    return try FfiConverterTypeImageFormat.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeImageFormat_lower(_ value: ImageFormat) -> RustBuffer {
+    return FfiConverterTypeImageFormat.lower(value)
+}
+
+
+
+extension ImageFormat: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum LineEnding {
+    
+    case lf
+    case crLf
+    case missing
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLineEnding: FfiConverterRustBuffer {
+    typealias SwiftType = LineEnding
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LineEnding {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .lf
+        
+        case 2: return .crLf
+        
+        case 3: return .missing
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: LineEnding, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .lf:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .crLf:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .missing:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLineEnding_lift(_ buf: RustBuffer) throws -> LineEnding {
+    return try FfiConverterTypeLineEnding.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLineEnding_lower(_ value: LineEnding) -> RustBuffer {
+    return FfiConverterTypeLineEnding.lower(value)
+}
+
+
+
+extension LineEnding: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum RevisionKind {
+    
+    case commit
+    case tree
+    case index
+    case workdir
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRevisionKind: FfiConverterRustBuffer {
+    typealias SwiftType = RevisionKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RevisionKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .commit
+        
+        case 2: return .tree
+        
+        case 3: return .index
+        
+        case 4: return .workdir
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: RevisionKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .commit:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .tree:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .index:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .workdir:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRevisionKind_lift(_ buf: RustBuffer) throws -> RevisionKind {
+    return try FfiConverterTypeRevisionKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRevisionKind_lower(_ value: RevisionKind) -> RustBuffer {
+    return FfiConverterTypeRevisionKind.lower(value)
+}
+
+
+
+extension RevisionKind: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum RevisionState {
+    
+    case pending
+    case inProgress
+    case completed
+    case failed
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRevisionState: FfiConverterRustBuffer {
+    typealias SwiftType = RevisionState
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RevisionState {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .pending
+        
+        case 2: return .inProgress
+        
+        case 3: return .completed
+        
+        case 4: return .failed
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: RevisionState, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .pending:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .inProgress:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .completed:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .failed:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRevisionState_lift(_ buf: RustBuffer) throws -> RevisionState {
+    return try FfiConverterTypeRevisionState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRevisionState_lower(_ value: RevisionState) -> RustBuffer {
+    return FfiConverterTypeRevisionState.lower(value)
+}
+
+
+
+extension RevisionState: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum Severity {
+    
+    case info
+    case warning
+    case error
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSeverity: FfiConverterRustBuffer {
+    typealias SwiftType = Severity
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Severity {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .info
+        
+        case 2: return .warning
+        
+        case 3: return .error
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: Severity, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .info:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .warning:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .error:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSeverity_lift(_ buf: RustBuffer) throws -> Severity {
+    return try FfiConverterTypeSeverity.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSeverity_lower(_ value: Severity) -> RustBuffer {
+    return FfiConverterTypeSeverity.lower(value)
+}
+
+
+
+extension Severity: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum StageState {
+    
+    case staged
+    case unstaged
+    case partiallyStaged
+    case untracked
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStageState: FfiConverterRustBuffer {
+    typealias SwiftType = StageState
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StageState {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .staged
+        
+        case 2: return .unstaged
+        
+        case 3: return .partiallyStaged
+        
+        case 4: return .untracked
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: StageState, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .staged:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .unstaged:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .partiallyStaged:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .untracked:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStageState_lift(_ buf: RustBuffer) throws -> StageState {
+    return try FfiConverterTypeStageState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStageState_lower(_ value: StageState) -> RustBuffer {
+    return FfiConverterTypeStageState.lower(value)
+}
+
+
+
+extension StageState: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum SymbolChangeKind {
+    
+    case added
+    case removed
+    case renamed
+    case signatureChanged
+    case bodyChanged
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSymbolChangeKind: FfiConverterRustBuffer {
+    typealias SwiftType = SymbolChangeKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SymbolChangeKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .added
+        
+        case 2: return .removed
+        
+        case 3: return .renamed
+        
+        case 4: return .signatureChanged
+        
+        case 5: return .bodyChanged
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: SymbolChangeKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .added:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .removed:
+            writeInt(&buf, Int32(2))
+        
+        
         case .renamed:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .signatureChanged:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .bodyChanged:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSymbolChangeKind_lift(_ buf: RustBuffer) throws -> SymbolChangeKind {
+    return try FfiConverterTypeSymbolChangeKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSymbolChangeKind_lower(_ value: SymbolChangeKind) -> RustBuffer {
+    return FfiConverterTypeSymbolChangeKind.lower(value)
+}
+
+
+
+extension SymbolChangeKind: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum SymbolKind {
+    
+    case function
+    case type
+    case impl
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSymbolKind: FfiConverterRustBuffer {
+    typealias SwiftType = SymbolKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SymbolKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .function
+        
+        case 2: return .type
+        
+        case 3: return .impl
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: SymbolKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .function:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .type:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .impl:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSymbolKind_lift(_ buf: RustBuffer) throws -> SymbolKind {
+    return try FfiConverterTypeSymbolKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSymbolKind_lower(_ value: SymbolKind) -> RustBuffer {
+    return FfiConverterTypeSymbolKind.lower(value)
+}
+
+
+
+extension SymbolKind: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum SyntaxKind {
+    
+    case keyword
+    case type
+    case function
+    case string
+    case number
+    case constant
+    case comment
+    case attribute
+    case `operator`
+    case punctuation
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSyntaxKind: FfiConverterRustBuffer {
+    typealias SwiftType = SyntaxKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SyntaxKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .keyword
+        
+        case 2: return .type
+        
+        case 3: return .function
+        
+        case 4: return .string
+        
+        case 5: return .number
+        
+        case 6: return .constant
+        
+        case 7: return .comment
+        
+        case 8: return .attribute
+        
+        case 9: return .`operator`
+        
+        case 10: return .punctuation
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: SyntaxKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .keyword:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .type:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .function:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .string:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .number:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .constant:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .comment:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .attribute:
+            writeInt(&buf, Int32(8))
+        
+        
+        case .`operator`:
+            writeInt(&buf, Int32(9))
+        
+        
+        case .punctuation:
+            writeInt(&buf, Int32(10))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSyntaxKind_lift(_ buf: RustBuffer) throws -> SyntaxKind {
+    return try FfiConverterTypeSyntaxKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSyntaxKind_lower(_ value: SyntaxKind) -> RustBuffer {
+    return FfiConverterTypeSyntaxKind.lower(value)
+}
+
+
+
+extension SyntaxKind: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TextEncoding {
+    
+    case utf8
+    case utf8Lossy
+    case utf8Bom
+    case utf16Le
+    case utf16LeBom
+    case utf16Be
+    case utf16BeBom
+    case legacy(name: String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTextEncoding: FfiConverterRustBuffer {
+    typealias SwiftType = TextEncoding
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TextEncoding {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .utf8
+        
+        case 2: return .utf8Lossy
+        
+        case 3: return .utf8Bom
+        
+        case 4: return .utf16Le
+        
+        case 5: return .utf16LeBom
+        
+        case 6: return .utf16Be
+        
+        case 7: return .utf16BeBom
+        
+        case 8: return .legacy(name: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TextEncoding, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .utf8:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .utf8Lossy:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .utf8Bom:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .utf16Le:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .utf16LeBom:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .utf16Be:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .utf16BeBom:
+            writeInt(&buf, Int32(7))
+        
+        
+        case let .legacy(name):
+            writeInt(&buf, Int32(8))
+            FfiConverterString.write(name, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTextEncoding_lift(_ buf: RustBuffer) throws -> TextEncoding {
+    return try FfiConverterTypeTextEncoding.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTextEncoding_lower(_ value: TextEncoding) -> RustBuffer {
+    return FfiConverterTypeTextEncoding.lower(value)
+}
+
+
+
+extension TextEncoding: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum WhitespaceMode {
+    
+    case include
+    case ignoreAll
+    case ignoreAtEol
+    case ignoreChange
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeWhitespaceMode: FfiConverterRustBuffer {
+    typealias SwiftType = WhitespaceMode
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> WhitespaceMode {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .include
+        
+        case 2: return .ignoreAll
+        
+        case 3: return .ignoreAtEol
+        
+        case 4: return .ignoreChange
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: WhitespaceMode, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .include:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .ignoreAll:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .ignoreAtEol:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .ignoreChange:
             writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWhitespaceMode_lift(_ buf: RustBuffer) throws -> WhitespaceMode {
+    return try FfiConverterTypeWhitespaceMode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWhitespaceMode_lower(_ value: WhitespaceMode) -> RustBuffer {
+    return FfiConverterTypeWhitespaceMode.lower(value)
+}
+
+
+
+extension WhitespaceMode: Equatable, Hashable {}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = UInt32?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt32.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionInt64: FfiConverterRustBuffer {
+    typealias SwiftType = Int64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionData: FfiConverterRustBuffer {
+    typealias SwiftType = Data?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterData.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterData.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeBlobInfo: FfiConverterRustBuffer {
+    typealias SwiftType = BlobInfo?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeBlobInfo.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeBlobInfo.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeDiffLine: FfiConverterRustBuffer {
+    typealias SwiftType = DiffLine?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeDiffLine.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeDiffLine.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeDiffRequest: FfiConverterRustBuffer {
+    typealias SwiftType = DiffRequest?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeDiffRequest.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeDiffRequest.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeFileContents: FfiConverterRustBuffer {
+    typealias SwiftType = FileContents?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFileContents.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFileContents.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeImageInfo: FfiConverterRustBuffer {
+    typealias SwiftType = ImageInfo?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeImageInfo.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeImageInfo.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeLineMove: FfiConverterRustBuffer {
+    typealias SwiftType = LineMove?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeLineMove.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeLineMove.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeRevision: FfiConverterRustBuffer {
+    typealias SwiftType = Revision?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeRevision.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeRevision.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeRevisionRange: FfiConverterRustBuffer {
+    typealias SwiftType = RevisionRange?
 
-        case .copied:
-            writeInt(&buf, Int32(5))
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeRevisionRange.write(value, into: &buf)
+    }
 
-        case .typeChange:
-            writeInt(&buf, Int32(6))
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeRevisionRange.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFileStatus_lift(_ buf: RustBuffer) throws -> FileStatus {
-    return try FfiConverterTypeFileStatus.lift(buf)
+fileprivate struct FfiConverterOptionTypeSignature: FfiConverterRustBuffer {
+    typealias SwiftType = Signature?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeSignature.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeSignature.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFileStatus_lower(_ value: FileStatus) -> RustBuffer {
-    return FfiConverterTypeFileStatus.lower(value)
+fileprivate struct FfiConverterOptionTypeStructuralSummary: FfiConverterRustBuffer {
+    typealias SwiftType = StructuralSummary?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeStructuralSummary.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeStructuralSummary.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-extension FileStatus: Equatable, Hashable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeSubmoduleChange: FfiConverterRustBuffer {
+    typealias SwiftType = SubmoduleChange?
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeSubmoduleChange.write(value, into: &buf)
+    }
 
-public enum RevisionState {
-    case pending
-    case inProgress
-    case completed
-    case failed
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeSubmoduleChange.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRevisionState: FfiConverterRustBuffer {
-    typealias SwiftType = RevisionState
+fileprivate struct FfiConverterOptionTypeThreadRef: FfiConverterRustBuffer {
+    typealias SwiftType = ThreadRef?
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RevisionState {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        case 1: return .pending
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeThreadRef.write(value, into: &buf)
+    }
 
-        case 2: return .inProgress
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeThreadRef.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
-        case 3: return .completed
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeFileMode: FfiConverterRustBuffer {
+    typealias SwiftType = FileMode?
 
-        case 4: return .failed
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFileMode.write(value, into: &buf)
+    }
 
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFileMode.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
+}
 
-    public static func write(_ value: RevisionState, into buf: inout [UInt8]) {
-        switch value {
-        case .pending:
-            writeInt(&buf, Int32(1))
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeStageState: FfiConverterRustBuffer {
+    typealias SwiftType = StageState?
 
-        case .inProgress:
-            writeInt(&buf, Int32(2))
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeStageState.write(value, into: &buf)
+    }
 
-        case .completed:
-            writeInt(&buf, Int32(3))
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeStageState.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
-        case .failed:
-            writeInt(&buf, Int32(4))
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeTextEncoding: FfiConverterRustBuffer {
+    typealias SwiftType = TextEncoding?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeTextEncoding.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeTextEncoding.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRevisionState_lift(_ buf: RustBuffer) throws -> RevisionState {
-    return try FfiConverterTypeRevisionState.lift(buf)
+fileprivate struct FfiConverterOptionSequenceUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt32]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceUInt32.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceUInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRevisionState_lower(_ value: RevisionState) -> RustBuffer {
-    return FfiConverterTypeRevisionState.lower(value)
+fileprivate struct FfiConverterOptionSequenceTypePatchSelection: FfiConverterRustBuffer {
+    typealias SwiftType = [PatchSelection]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceTypePatchSelection.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceTypePatchSelection.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-extension RevisionState: Equatable, Hashable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt32]
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+    public static func write(_ value: [UInt32], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterUInt32.write(item, into: &buf)
+        }
+    }
 
-public enum Severity {
-    case info
-    case warning
-    case error
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt32] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UInt32]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterUInt32.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSeverity: FfiConverterRustBuffer {
-    typealias SwiftType = Severity
+fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Severity {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        case 1: return .info
+    public static func write(_ value: [String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterString.write(item, into: &buf)
+        }
+    }
 
-        case 2: return .warning
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [String]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterString.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        case 3: return .error
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeClassificationRule: FfiConverterRustBuffer {
+    typealias SwiftType = [ClassificationRule]
 
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: [ClassificationRule], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeClassificationRule.write(item, into: &buf)
         }
     }
 
-    public static func write(_ value: Severity, into buf: inout [UInt8]) {
-        switch value {
-        case .info:
-            writeInt(&buf, Int32(1))
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ClassificationRule] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ClassificationRule]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeClassificationRule.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        case .warning:
-            writeInt(&buf, Int32(2))
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeCommentDraft: FfiConverterRustBuffer {
+    typealias SwiftType = [CommentDraft]
 
-        case .error:
-            writeInt(&buf, Int32(3))
+    public static func write(_ value: [CommentDraft], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeCommentDraft.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [CommentDraft] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [CommentDraft]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeCommentDraft.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSeverity_lift(_ buf: RustBuffer) throws -> Severity {
-    return try FfiConverterTypeSeverity.lift(buf)
-}
+fileprivate struct FfiConverterSequenceTypeCommitPair: FfiConverterRustBuffer {
+    typealias SwiftType = [CommitPair]
 
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-public func FfiConverterTypeSeverity_lower(_ value: Severity) -> RustBuffer {
-    return FfiConverterTypeSeverity.lower(value)
-}
+    public static func write(_ value: [CommitPair], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeCommitPair.write(item, into: &buf)
+        }
+    }
 
-extension Severity: Equatable, Hashable {}
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [CommitPair] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [CommitPair]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeCommitPair.read(from: &buf))
+        }
+        return seq
+    }
+}
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
-    typealias SwiftType = UInt32?
+fileprivate struct FfiConverterSequenceTypeDiagnostic: FfiConverterRustBuffer {
+    typealias SwiftType = [Diagnostic]
 
-    static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [Diagnostic], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeDiagnostic.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterUInt32.write(value, into: &buf)
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterUInt32.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Diagnostic] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Diagnostic]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeDiagnostic.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionInt64: FfiConverterRustBuffer {
-    typealias SwiftType = Int64?
+fileprivate struct FfiConverterSequenceTypeDiff: FfiConverterRustBuffer {
+    typealias SwiftType = [Diff]
 
-    static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [Diff], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeDiff.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterInt64.write(value, into: &buf)
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterInt64.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Diff] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Diff]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeDiff.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionString: FfiConverterRustBuffer {
-    typealias SwiftType = String?
+fileprivate struct FfiConverterSequenceTypeDiffFile: FfiConverterRustBuffer {
+    typealias SwiftType = [DiffFile]
 
-    static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [DiffFile], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeDiffFile.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterString.write(value, into: &buf)
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterString.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DiffFile] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [DiffFile]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeDiffFile.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionTypeRevision: FfiConverterRustBuffer {
-    typealias SwiftType = Revision?
+fileprivate struct FfiConverterSequenceTypeDiffFileSummary: FfiConverterRustBuffer {
+    typealias SwiftType = [DiffFileSummary]
 
-    static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [DiffFileSummary], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeDiffFileSummary.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeRevision.write(value, into: &buf)
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeRevision.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DiffFileSummary] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [DiffFileSummary]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeDiffFileSummary.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionTypeRevisionRange: FfiConverterRustBuffer {
-    typealias SwiftType = RevisionRange?
+fileprivate struct FfiConverterSequenceTypeDiffHunk: FfiConverterRustBuffer {
+    typealias SwiftType = [DiffHunk]
 
-    static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [DiffHunk], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeDiffHunk.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeRevisionRange.write(value, into: &buf)
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeRevisionRange.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DiffHunk] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [DiffHunk]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeDiffHunk.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionTypeSignature: FfiConverterRustBuffer {
-    typealias SwiftType = Signature?
+fileprivate struct FfiConverterSequenceTypeDiffLine: FfiConverterRustBuffer {
+    typealias SwiftType = [DiffLine]
 
-    static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [DiffLine], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeDiffLine.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeSignature.write(value, into: &buf)
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeSignature.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DiffLine] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [DiffLine]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeDiffLine.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionTypeThreadRef: FfiConverterRustBuffer {
-    typealias SwiftType = ThreadRef?
+fileprivate struct FfiConverterSequenceTypeLineHighlight: FfiConverterRustBuffer {
+    typealias SwiftType = [LineHighlight]
 
-    static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [LineHighlight], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeLineHighlight.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeThreadRef.write(value, into: &buf)
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeThreadRef.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [LineHighlight] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [LineHighlight]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeLineHighlight.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceString: FfiConverterRustBuffer {
-    typealias SwiftType = [String]
+fileprivate struct FfiConverterSequenceTypePatchSelection: FfiConverterRustBuffer {
+    typealias SwiftType = [PatchSelection]
 
-    static func write(_ value: [String], into buf: inout [UInt8]) {
+    public static func write(_ value: [PatchSelection], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterString.write(item, into: &buf)
+            FfiConverterTypePatchSelection.write(item, into: &buf)
         }
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [PatchSelection] {
         let len: Int32 = try readInt(&buf)
-        var seq = [String]()
+        var seq = [PatchSelection]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterString.read(from: &buf))
+            seq.append(try FfiConverterTypePatchSelection.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypeCommentDraft: FfiConverterRustBuffer {
-    typealias SwiftType = [CommentDraft]
+fileprivate struct FfiConverterSequenceTypePluginSummary: FfiConverterRustBuffer {
+    typealias SwiftType = [PluginSummary]
 
-    static func write(_ value: [CommentDraft], into buf: inout [UInt8]) {
+    public static func write(_ value: [PluginSummary], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeCommentDraft.write(item, into: &buf)
+            FfiConverterTypePluginSummary.write(item, into: &buf)
         }
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [CommentDraft] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [PluginSummary] {
         let len: Int32 = try readInt(&buf)
-        var seq = [CommentDraft]()
+        var seq = [PluginSummary]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterTypeCommentDraft.read(from: &buf))
+            seq.append(try FfiConverterTypePluginSummary.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypeDiagnostic: FfiConverterRustBuffer {
-    typealias SwiftType = [Diagnostic]
+fileprivate struct FfiConverterSequenceTypeReviewIteration: FfiConverterRustBuffer {
+    typealias SwiftType = [ReviewIteration]
 
-    static func write(_ value: [Diagnostic], into buf: inout [UInt8]) {
+    public static func write(_ value: [ReviewIteration], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeDiagnostic.write(item, into: &buf)
+            FfiConverterTypeReviewIteration.write(item, into: &buf)
         }
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Diagnostic] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ReviewIteration] {
         let len: Int32 = try readInt(&buf)
-        var seq = [Diagnostic]()
+        var seq = [ReviewIteration]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterTypeDiagnostic.read(from: &buf))
+            seq.append(try FfiConverterTypeReviewIteration.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypeDiffFile: FfiConverterRustBuffer {
-    typealias SwiftType = [DiffFile]
+fileprivate struct FfiConverterSequenceTypeRevision: FfiConverterRustBuffer {
+    typealias SwiftType = [Revision]
 
-    static func write(_ value: [DiffFile], into buf: inout [UInt8]) {
+    public static func write(_ value: [Revision], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeDiffFile.write(item, into: &buf)
+            FfiConverterTypeRevision.write(item, into: &buf)
         }
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DiffFile] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Revision] {
         let len: Int32 = try readInt(&buf)
-        var seq = [DiffFile]()
+        var seq = [Revision]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterTypeDiffFile.read(from: &buf))
+            seq.append(try FfiConverterTypeRevision.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypeDiffHunk: FfiConverterRustBuffer {
-    typealias SwiftType = [DiffHunk]
+fileprivate struct FfiConverterSequenceTypeSplitHunk: FfiConverterRustBuffer {
+    typealias SwiftType = [SplitHunk]
 
-    static func write(_ value: [DiffHunk], into buf: inout [UInt8]) {
+    public static func write(_ value: [SplitHunk], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeDiffHunk.write(item, into: &buf)
+            FfiConverterTypeSplitHunk.write(item, into: &buf)
         }
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DiffHunk] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SplitHunk] {
         let len: Int32 = try readInt(&buf)
-        var seq = [DiffHunk]()
+        var seq = [SplitHunk]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterTypeDiffHunk.read(from: &buf))
+            seq.append(try FfiConverterTypeSplitHunk.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypeDiffLine: FfiConverterRustBuffer {
-    typealias SwiftType = [DiffLine]
+fileprivate struct FfiConverterSequenceTypeSplitRow: FfiConverterRustBuffer {
+    typealias SwiftType = [SplitRow]
 
-    static func write(_ value: [DiffLine], into buf: inout [UInt8]) {
+    public static func write(_ value: [SplitRow], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeDiffLine.write(item, into: &buf)
+            FfiConverterTypeSplitRow.write(item, into: &buf)
         }
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DiffLine] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SplitRow] {
         let len: Int32 = try readInt(&buf)
-        var seq = [DiffLine]()
+        var seq = [SplitRow]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterTypeDiffLine.read(from: &buf))
+            seq.append(try FfiConverterTypeSplitRow.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypeLineHighlight: FfiConverterRustBuffer {
-    typealias SwiftType = [LineHighlight]
+fileprivate struct FfiConverterSequenceTypeSuggestion: FfiConverterRustBuffer {
+    typealias SwiftType = [Suggestion]
 
-    static func write(_ value: [LineHighlight], into buf: inout [UInt8]) {
+    public static func write(_ value: [Suggestion], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeLineHighlight.write(item, into: &buf)
+            FfiConverterTypeSuggestion.write(item, into: &buf)
         }
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [LineHighlight] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Suggestion] {
         let len: Int32 = try readInt(&buf)
-        var seq = [LineHighlight]()
+        var seq = [Suggestion]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterTypeLineHighlight.read(from: &buf))
+            seq.append(try FfiConverterTypeSuggestion.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypePluginSummary: FfiConverterRustBuffer {
-    typealias SwiftType = [PluginSummary]
+fileprivate struct FfiConverterSequenceTypeSymbolChange: FfiConverterRustBuffer {
+    typealias SwiftType = [SymbolChange]
 
-    static func write(_ value: [PluginSummary], into buf: inout [UInt8]) {
+    public static func write(_ value: [SymbolChange], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypePluginSummary.write(item, into: &buf)
+            FfiConverterTypeSymbolChange.write(item, into: &buf)
         }
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [PluginSummary] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SymbolChange] {
         let len: Int32 = try readInt(&buf)
-        var seq = [PluginSummary]()
+        var seq = [SymbolChange]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterTypePluginSummary.read(from: &buf))
+            seq.append(try FfiConverterTypeSymbolChange.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypeSuggestion: FfiConverterRustBuffer {
-    typealias SwiftType = [Suggestion]
+fileprivate struct FfiConverterSequenceTypeSyntaxToken: FfiConverterRustBuffer {
+    typealias SwiftType = [SyntaxToken]
 
-    static func write(_ value: [Suggestion], into buf: inout [UInt8]) {
+    public static func write(_ value: [SyntaxToken], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeSuggestion.write(item, into: &buf)
+            FfiConverterTypeSyntaxToken.write(item, into: &buf)
         }
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Suggestion] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SyntaxToken] {
         let len: Int32 = try readInt(&buf)
-        var seq = [Suggestion]()
+        var seq = [SyntaxToken]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterTypeSuggestion.read(from: &buf))
+            seq.append(try FfiConverterTypeSyntaxToken.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypeTextEdit: FfiConverterRustBuffer {
+fileprivate struct FfiConverterSequenceTypeTextEdit: FfiConverterRustBuffer {
     typealias SwiftType = [TextEdit]
 
-    static func write(_ value: [TextEdit], into buf: inout [UInt8]) {
+    public static func write(_ value: [TextEdit], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
@@ -3381,24 +7698,24 @@ private struct FfiConverterSequenceTypeTextEdit: FfiConverterRustBuffer {
         }
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TextEdit] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TextEdit] {
         let len: Int32 = try readInt(&buf)
         var seq = [TextEdit]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterTypeTextEdit.read(from: &buf))
+            seq.append(try FfiConverterTypeTextEdit.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypeThreadRef: FfiConverterRustBuffer {
+fileprivate struct FfiConverterSequenceTypeThreadRef: FfiConverterRustBuffer {
     typealias SwiftType = [ThreadRef]
 
-    static func write(_ value: [ThreadRef], into buf: inout [UInt8]) {
+    public static func write(_ value: [ThreadRef], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
@@ -3406,23 +7723,22 @@ private struct FfiConverterSequenceTypeThreadRef: FfiConverterRustBuffer {
         }
     }
 
-    static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ThreadRef] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ThreadRef] {
         let len: Int32 = try readInt(&buf)
         var seq = [ThreadRef]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterTypeThreadRef.read(from: &buf))
+            seq.append(try FfiConverterTypeThreadRef.read(from: &buf))
         }
         return seq
     }
 }
-
-public func open(path: String) throws -> CoreSession {
-    return try FfiConverterTypeCoreSession.lift(rustCallWithError(FfiConverterTypeCoreError.lift) {
-        uniffi_prism_core_fn_func_open(
-            FfiConverterString.lower(path), $0
-        )
-    })
+public func `open`(path: String)throws  -> CoreSession {
+    return try  FfiConverterTypeCoreSession.lift(try rustCallWithError(FfiConverterTypeCoreError.lift) {
+    uniffi_prism_core_fn_func_open(
+        FfiConverterString.lower(path),$0
+    )
+})
 }
 
 private enum InitializationResult {
@@ -3430,7 +7746,6 @@ private enum InitializationResult {
     case contractVersionMismatch
     case apiChecksumMismatch
 }
-
 // Use a global variable to perform the versioning checks. Swift ensures that
 // the code inside is only computed once.
 private var initializationResult: InitializationResult = {
@@ -3441,49 +7756,109 @@ private var initializationResult: InitializationResult = {
     if bindings_contract_version != scaffolding_contract_version {
         return InitializationResult.contractVersionMismatch
     }
-    if uniffi_prism_core_checksum_func_open() != 3223 {
+    if (uniffi_prism_core_checksum_func_open() != 3223) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_attach_plugin() != 9985) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_base_revision() != 990) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_diff_commits() != 12766) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_diff_for_range() != 21375) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_diff_head() != 23747) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_diff_index() != 30246) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_diff_merge_base() != 4351) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_diff_patch() != 38878) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_diff_since_iteration() != 40498) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_diff_summary_for_range() != 59595) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_diff_summary_head() != 42052) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_diff_summary_workspace() != 40160) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_diff_unstaged() != 23325) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_diff_workspace() != 63083) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_expand_context() != 31477) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_export_patch() != 4720) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_file_contents() != 57210) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_head_revision() != 42265) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_load_file_blobs() != 58833) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_prism_core_checksum_method_coresession_load_file_hunks() != 40048) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_prism_core_checksum_method_coresession_attach_plugin() != 9985 {
+    if (uniffi_prism_core_checksum_method_coresession_load_split_hunks() != 56809) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_prism_core_checksum_method_coresession_base_revision() != 990 {
+    if (uniffi_prism_core_checksum_method_coresession_plugin_threads() != 2796) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_prism_core_checksum_method_coresession_diff_for_range() != 39197 {
+    if (uniffi_prism_core_checksum_method_coresession_plugins() != 35256) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_prism_core_checksum_method_coresession_diff_head() != 50676 {
+    if (uniffi_prism_core_checksum_method_coresession_poll_revision() != 45306) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_prism_core_checksum_method_coresession_diff_workspace() != 2039 {
+    if (uniffi_prism_core_checksum_method_coresession_post_review() != 32878) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_prism_core_checksum_method_coresession_head_revision() != 42265 {
+    if (uniffi_prism_core_checksum_method_coresession_range_diff() != 15730) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_prism_core_checksum_method_coresession_plugin_threads() != 2796 {
+    if (uniffi_prism_core_checksum_method_coresession_record_iteration() != 22647) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_prism_core_checksum_method_coresession_plugins() != 35256 {
+    if (uniffi_prism_core_checksum_method_coresession_refresh() != 20035) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_prism_core_checksum_method_coresession_poll_revision() != 45306 {
+    if (uniffi_prism_core_checksum_method_coresession_repository_info() != 62133) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_prism_core_checksum_method_coresession_post_review() != 32878 {
+    if (uniffi_prism_core_checksum_method_coresession_review_iterations() != 5811) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_prism_core_checksum_method_coresession_refresh() != 20035 {
+    if (uniffi_prism_core_checksum_method_coresession_set_classification_rules() != 46404) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_prism_core_checksum_method_coresession_repository_info() != 62133 {
+    if (uniffi_prism_core_checksum_method_coresession_snapshot() != 2927) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_prism_core_checksum_method_coresession_snapshot() != 2927 {
+    if (uniffi_prism_core_checksum_method_coresession_split_hunks() != 19536) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_prism_core_checksum_method_coresession_workspace_status() != 61824 {
+    if (uniffi_prism_core_checksum_method_coresession_workspace_status() != 61824) {
         return InitializationResult.apiChecksumMismatch
     }
 
@@ -3501,4 +7876,4 @@ private func uniffiEnsureInitialized() {
     }
 }
 
-// swiftlint:enable all
+// swiftlint:enable all
\ No newline at end of file
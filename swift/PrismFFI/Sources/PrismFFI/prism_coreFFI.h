@@ -271,19 +271,79 @@ RustBuffer uniffi_prism_core_fn_method_coresession_attach_plugin(void*_Nonnull p
 RustBuffer uniffi_prism_core_fn_method_coresession_base_revision(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_COMMITS
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_COMMITS
+RustBuffer uniffi_prism_core_fn_method_coresession_diff_commits(void*_Nonnull ptr, RustBuffer range, uint32_t offset, uint32_t limit, RustBuffer request, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_FOR_RANGE
 #define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_FOR_RANGE
-RustBuffer uniffi_prism_core_fn_method_coresession_diff_for_range(void*_Nonnull ptr, RustBuffer range, RustCallStatus *_Nonnull out_status
+RustBuffer uniffi_prism_core_fn_method_coresession_diff_for_range(void*_Nonnull ptr, RustBuffer range, RustBuffer request, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_HEAD
 #define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_HEAD
-RustBuffer uniffi_prism_core_fn_method_coresession_diff_head(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+RustBuffer uniffi_prism_core_fn_method_coresession_diff_head(void*_Nonnull ptr, RustBuffer request, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_INDEX
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_INDEX
+RustBuffer uniffi_prism_core_fn_method_coresession_diff_index(void*_Nonnull ptr, RustBuffer request, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_MERGE_BASE
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_MERGE_BASE
+RustBuffer uniffi_prism_core_fn_method_coresession_diff_merge_base(void*_Nonnull ptr, RustBuffer target, int8_t include_workspace, RustBuffer request, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_PATCH
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_PATCH
+RustBuffer uniffi_prism_core_fn_method_coresession_diff_patch(void*_Nonnull ptr, RustBuffer patch, int8_t check_applies, RustBuffer request, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_SINCE_ITERATION
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_SINCE_ITERATION
+RustBuffer uniffi_prism_core_fn_method_coresession_diff_since_iteration(void*_Nonnull ptr, RustBuffer number, RustBuffer request, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_SUMMARY_FOR_RANGE
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_SUMMARY_FOR_RANGE
+RustBuffer uniffi_prism_core_fn_method_coresession_diff_summary_for_range(void*_Nonnull ptr, RustBuffer range, RustBuffer request, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_SUMMARY_HEAD
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_SUMMARY_HEAD
+RustBuffer uniffi_prism_core_fn_method_coresession_diff_summary_head(void*_Nonnull ptr, RustBuffer request, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_SUMMARY_WORKSPACE
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_SUMMARY_WORKSPACE
+RustBuffer uniffi_prism_core_fn_method_coresession_diff_summary_workspace(void*_Nonnull ptr, RustBuffer request, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_UNSTAGED
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_UNSTAGED
+RustBuffer uniffi_prism_core_fn_method_coresession_diff_unstaged(void*_Nonnull ptr, RustBuffer request, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_WORKSPACE
 #define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_DIFF_WORKSPACE
-RustBuffer uniffi_prism_core_fn_method_coresession_diff_workspace(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+RustBuffer uniffi_prism_core_fn_method_coresession_diff_workspace(void*_Nonnull ptr, RustBuffer request, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_EXPAND_CONTEXT
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_EXPAND_CONTEXT
+RustBuffer uniffi_prism_core_fn_method_coresession_expand_context(void*_Nonnull ptr, RustBuffer range, int8_t workspace, RustBuffer path, RustBuffer side, uint32_t start_line, uint32_t line_count, RustBuffer request, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_EXPORT_PATCH
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_EXPORT_PATCH
+RustBuffer uniffi_prism_core_fn_method_coresession_export_patch(void*_Nonnull ptr, RustBuffer diff, RustBuffer selection, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_FILE_CONTENTS
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_FILE_CONTENTS
+RustBuffer uniffi_prism_core_fn_method_coresession_file_contents(void*_Nonnull ptr, RustBuffer range, RustBuffer path, RustBuffer version, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_HEAD_REVISION
@@ -291,6 +351,21 @@ RustBuffer uniffi_prism_core_fn_method_coresession_diff_workspace(void*_Nonnull
 RustBuffer uniffi_prism_core_fn_method_coresession_head_revision(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_LOAD_FILE_BLOBS
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_LOAD_FILE_BLOBS
+RustBuffer uniffi_prism_core_fn_method_coresession_load_file_blobs(void*_Nonnull ptr, RustBuffer range, int8_t workspace, RustBuffer path, RustBuffer request, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_LOAD_FILE_HUNKS
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_LOAD_FILE_HUNKS
+RustBuffer uniffi_prism_core_fn_method_coresession_load_file_hunks(void*_Nonnull ptr, RustBuffer range, int8_t workspace, RustBuffer path, RustBuffer max_lines, RustBuffer request, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_LOAD_SPLIT_HUNKS
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_LOAD_SPLIT_HUNKS
+RustBuffer uniffi_prism_core_fn_method_coresession_load_split_hunks(void*_Nonnull ptr, RustBuffer range, int8_t workspace, RustBuffer path, RustBuffer request, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_PLUGIN_THREADS
 #define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_PLUGIN_THREADS
 RustBuffer uniffi_prism_core_fn_method_coresession_plugin_threads(void*_Nonnull ptr, RustBuffer plugin_id, RustCallStatus *_Nonnull out_status
@@ -311,6 +386,16 @@ RustBuffer uniffi_prism_core_fn_method_coresession_poll_revision(void*_Nonnull p
 RustBuffer uniffi_prism_core_fn_method_coresession_post_review(void*_Nonnull ptr, RustBuffer session, RustBuffer payload, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_RANGE_DIFF
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_RANGE_DIFF
+RustBuffer uniffi_prism_core_fn_method_coresession_range_diff(void*_Nonnull ptr, RustBuffer old_range, RustBuffer new_range, RustBuffer request, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_RECORD_ITERATION
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_RECORD_ITERATION
+RustBuffer uniffi_prism_core_fn_method_coresession_record_iteration(void*_Nonnull ptr, RustBuffer revision, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_REFRESH
 #define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_REFRESH
 RustBuffer uniffi_prism_core_fn_method_coresession_refresh(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -321,11 +406,26 @@ RustBuffer uniffi_prism_core_fn_method_coresession_refresh(void*_Nonnull ptr, Ru
 RustBuffer uniffi_prism_core_fn_method_coresession_repository_info(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_REVIEW_ITERATIONS
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_REVIEW_ITERATIONS
+RustBuffer uniffi_prism_core_fn_method_coresession_review_iterations(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_SET_CLASSIFICATION_RULES
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_SET_CLASSIFICATION_RULES
+void uniffi_prism_core_fn_method_coresession_set_classification_rules(void*_Nonnull ptr, RustBuffer rules, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_SNAPSHOT
 #define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_SNAPSHOT
 RustBuffer uniffi_prism_core_fn_method_coresession_snapshot(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_SPLIT_HUNKS
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_SPLIT_HUNKS
+RustBuffer uniffi_prism_core_fn_method_coresession_split_hunks(void*_Nonnull ptr, RustBuffer hunks, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_WORKSPACE_STATUS
 #define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_FN_METHOD_CORESESSION_WORKSPACE_STATUS
 RustBuffer uniffi_prism_core_fn_method_coresession_workspace_status(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -632,6 +732,12 @@ uint16_t uniffi_prism_core_checksum_method_coresession_attach_plugin(void
 #define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_BASE_REVISION
 uint16_t uniffi_prism_core_checksum_method_coresession_base_revision(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_COMMITS
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_COMMITS
+uint16_t uniffi_prism_core_checksum_method_coresession_diff_commits(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_FOR_RANGE
@@ -644,18 +750,102 @@ uint16_t uniffi_prism_core_checksum_method_coresession_diff_for_range(void
 #define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_HEAD
 uint16_t uniffi_prism_core_checksum_method_coresession_diff_head(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_INDEX
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_INDEX
+uint16_t uniffi_prism_core_checksum_method_coresession_diff_index(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_MERGE_BASE
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_MERGE_BASE
+uint16_t uniffi_prism_core_checksum_method_coresession_diff_merge_base(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_PATCH
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_PATCH
+uint16_t uniffi_prism_core_checksum_method_coresession_diff_patch(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_SINCE_ITERATION
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_SINCE_ITERATION
+uint16_t uniffi_prism_core_checksum_method_coresession_diff_since_iteration(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_SUMMARY_FOR_RANGE
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_SUMMARY_FOR_RANGE
+uint16_t uniffi_prism_core_checksum_method_coresession_diff_summary_for_range(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_SUMMARY_HEAD
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_SUMMARY_HEAD
+uint16_t uniffi_prism_core_checksum_method_coresession_diff_summary_head(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_SUMMARY_WORKSPACE
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_SUMMARY_WORKSPACE
+uint16_t uniffi_prism_core_checksum_method_coresession_diff_summary_workspace(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_UNSTAGED
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_UNSTAGED
+uint16_t uniffi_prism_core_checksum_method_coresession_diff_unstaged(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_WORKSPACE
 #define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_DIFF_WORKSPACE
 uint16_t uniffi_prism_core_checksum_method_coresession_diff_workspace(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_EXPAND_CONTEXT
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_EXPAND_CONTEXT
+uint16_t uniffi_prism_core_checksum_method_coresession_expand_context(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_EXPORT_PATCH
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_EXPORT_PATCH
+uint16_t uniffi_prism_core_checksum_method_coresession_export_patch(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_FILE_CONTENTS
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_FILE_CONTENTS
+uint16_t uniffi_prism_core_checksum_method_coresession_file_contents(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_HEAD_REVISION
 #define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_HEAD_REVISION
 uint16_t uniffi_prism_core_checksum_method_coresession_head_revision(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_LOAD_FILE_BLOBS
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_LOAD_FILE_BLOBS
+uint16_t uniffi_prism_core_checksum_method_coresession_load_file_blobs(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_LOAD_FILE_HUNKS
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_LOAD_FILE_HUNKS
+uint16_t uniffi_prism_core_checksum_method_coresession_load_file_hunks(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_LOAD_SPLIT_HUNKS
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_LOAD_SPLIT_HUNKS
+uint16_t uniffi_prism_core_checksum_method_coresession_load_split_hunks(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_PLUGIN_THREADS
@@ -680,6 +870,18 @@ uint16_t uniffi_prism_core_checksum_method_coresession_poll_revision(void
 #define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_POST_REVIEW
 uint16_t uniffi_prism_core_checksum_method_coresession_post_review(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_RANGE_DIFF
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_RANGE_DIFF
+uint16_t uniffi_prism_core_checksum_method_coresession_range_diff(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_RECORD_ITERATION
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_RECORD_ITERATION
+uint16_t uniffi_prism_core_checksum_method_coresession_record_iteration(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_REFRESH
@@ -692,12 +894,30 @@ uint16_t uniffi_prism_core_checksum_method_coresession_refresh(void
 #define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_REPOSITORY_INFO
 uint16_t uniffi_prism_core_checksum_method_coresession_repository_info(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_REVIEW_ITERATIONS
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_REVIEW_ITERATIONS
+uint16_t uniffi_prism_core_checksum_method_coresession_review_iterations(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_SET_CLASSIFICATION_RULES
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_SET_CLASSIFICATION_RULES
+uint16_t uniffi_prism_core_checksum_method_coresession_set_classification_rules(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_SNAPSHOT
 #define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_SNAPSHOT
 uint16_t uniffi_prism_core_checksum_method_coresession_snapshot(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_SPLIT_HUNKS
+#define UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_SPLIT_HUNKS
+uint16_t uniffi_prism_core_checksum_method_coresession_split_hunks(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_PRISM_CORE_CHECKSUM_METHOD_CORESESSION_WORKSPACE_STATUS